@@ -5,6 +5,7 @@ pub(crate) struct Meta {
     pub(super) json: bool,
     pub(super) context: bool,
     pub(super) this: bool,
+    pub(super) strict: bool,
 }
 
 #[derive(Default)]
@@ -41,6 +42,12 @@ impl Meta {
         Ok(())
     }
 
+    fn force_strict(&mut self, _meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        self.strict = true;
+
+        Ok(())
+    }
+
     fn make_async(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
         if matches!(self.kind, Kind::AsyncFn) {
             return Err(meta.error("`async` attribute should not be used with an `async fn`"));
@@ -94,6 +101,10 @@ impl syn::parse::Parser for Parser {
                 return attr.force_this(meta);
             }
 
+            if meta.path.is_ident("strict") {
+                return attr.force_strict(meta);
+            }
+
             if meta.path.is_ident("async") {
                 return attr.make_async(meta);
             }
@@ -41,6 +41,17 @@ pub(super) fn export(meta: meta::Meta, input: syn::ItemFn) -> proc_macro::TokenS
             .unwrap_or_else(|| quote::quote!(#name))
     });
 
+    // Extract arguments, optionally checking the argument count
+    // N.B.: The count is checked directly because `()` extracts a single argument
+    let arity_check = meta
+        .strict
+        .then(|| quote::quote!(neon::macro_internal::check_arity(&mut cx, #num_args)?;));
+
+    let args_extract = quote::quote!(
+        #arity_check
+        let (#(#tuple_fields,)*) = cx.args()?;
+    );
+
     // Tag whether we should JSON wrap results
     let return_tag = if meta.json {
         quote::format_ident!("NeonJsonTag")
@@ -61,7 +72,7 @@ pub(super) fn export(meta: meta::Meta, input: syn::ItemFn) -> proc_macro::TokenS
         Kind::Async => quote::quote!(
             #context_extract
             #this_extract
-            let (#(#tuple_fields,)*) = cx.args()?;
+            #args_extract
             let fut = #name(#context_arg #this_arg #(#args),*);
             let fut = {
                 use neon::macro_internal::{ToNeonMarker, NeonValueTag};
@@ -74,7 +85,7 @@ pub(super) fn export(meta: meta::Meta, input: syn::ItemFn) -> proc_macro::TokenS
         Kind::AsyncFn => quote::quote!(
             #context_extract
             #this_extract
-            let (#(#tuple_fields,)*) = cx.args()?;
+            #args_extract
             let fut = #name(#context_arg #this_arg #(#args),*);
 
             neon::macro_internal::spawn(&mut cx, fut, |mut cx, res| #result_extract)
@@ -82,7 +93,7 @@ pub(super) fn export(meta: meta::Meta, input: syn::ItemFn) -> proc_macro::TokenS
         Kind::Normal => quote::quote!(
             #context_extract
             #this_extract
-            let (#(#tuple_fields,)*) = cx.args()?;
+            #args_extract
             let res = #name(#context_arg #this_arg #(#args),*);
 
             #result_extract
@@ -90,7 +101,7 @@ pub(super) fn export(meta: meta::Meta, input: syn::ItemFn) -> proc_macro::TokenS
         Kind::Task => quote::quote!(
             #context_extract
             #this_extract
-            let (#(#tuple_fields,)*) = cx.args()?;
+            #args_extract
             let promise = neon::context::Context::task(&mut cx, move || #name(#context_arg #this_arg #(#args),*))
                 .promise(|mut cx, res| #result_extract);
 
@@ -41,3 +41,57 @@ pub fn export(
 ) -> proc_macro::TokenStream {
     export::export(attr, item)
 }
+
+#[proc_macro_attribute]
+pub fn test(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+
+        return syn::Error::new_spanned(attr, "unexpected arguments to `neon::testing::test`")
+            .into_compile_error()
+            .into();
+    }
+
+    let syn::ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = syn::parse_macro_input!(item as syn::ItemFn);
+
+    let name = &sig.ident;
+    let test_name = name.to_string();
+    let register_name = quote::format_ident!("__NEON_TEST__{name}");
+    let register_fn = quote::quote!({
+        #[neon::macro_internal::linkme::distributed_slice(neon::macro_internal::TESTS)]
+        #[linkme(crate = neon::macro_internal::linkme)]
+        fn #register_name<'cx>(
+            cx: &mut neon::context::ModuleContext<'cx>,
+        ) -> neon::result::NeonResult<(&'static str, neon::handle::Handle<'cx, neon::types::JsValue>)> {
+            use neon::types::{JsFunction, JsUndefined, JsValue};
+
+            let f = JsFunction::with_name(
+                cx,
+                #test_name,
+                |mut cx: neon::context::FunctionContext| -> neon::result::JsResult<JsUndefined> {
+                    #name(&mut cx)?;
+                    Ok(neon::context::Context::undefined(&mut cx))
+                },
+            )?;
+
+            Ok((#test_name, f.upcast::<JsValue>()))
+        }
+    });
+
+    quote::quote!(
+        #(#attrs) *
+        #vis #sig {
+            #register_fn
+            #block
+        }
+    )
+    .into()
+}
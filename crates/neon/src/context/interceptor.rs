@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use crate::{
+    context::{internal::ContextInternal, Absent, Cx, FunctionContext},
+    lifecycle::InstanceData,
+    result::JsResult,
+    types::JsValue,
+};
+
+pub(crate) type Interceptor = Arc<
+    dyn for<'a, 'cx> Fn(
+            &mut FunctionContext<'cx>,
+            &CallInfo,
+            Next<'a, 'cx>,
+        ) -> JsResult<'cx, JsValue>
+        + Send
+        + Sync,
+>;
+
+// Type erased body of a Neon function
+pub(crate) type Body<'a, 'cx> = dyn Fn(FunctionContext<'cx>) -> JsResult<'cx, JsValue> + 'a;
+
+/// Information about a call to a function, passed to each interceptor registered with
+/// [`ModuleContext::add_interceptor`](super::ModuleContext::add_interceptor).
+#[derive(Debug)]
+pub struct CallInfo {
+    name: Box<str>,
+}
+
+impl CallInfo {
+    pub(crate) fn new(name: &str) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Returns the name the function was created with.
+    ///
+    /// This is the export name for functions exported with
+    /// [`ModuleContext::export_function`](super::ModuleContext::export_function) or
+    /// [`neon::export`](crate::export), and the Rust type name of the implementation
+    /// for functions created with [`JsFunction::new`](crate::types::JsFunction::new).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// The remainder of an interceptor chain, ending with the intercepted function.
+///
+/// An interceptor must call [`Next::call`] to continue the call, or it may return
+/// without calling the function.
+pub struct Next<'a, 'cx> {
+    interceptors: &'a [Interceptor],
+    info: &'a CallInfo,
+    body: &'a Body<'a, 'cx>,
+}
+
+impl<'a, 'cx> Next<'a, 'cx> {
+    /// Calls the next interceptor or, at the end of the chain, the function itself.
+    pub fn call(self, cx: &mut FunctionContext<'cx>) -> JsResult<'cx, JsValue> {
+        match self.interceptors.split_first() {
+            Some((interceptor, interceptors)) => interceptor(
+                cx,
+                self.info,
+                Next {
+                    interceptors,
+                    ..self
+                },
+            ),
+            None => {
+                // The new context shares the scope of `cx`, which is exclusively
+                // borrowed for the duration of the call
+                let inner = FunctionContext {
+                    cx: Cx::new(cx.env()),
+                    info: cx.info,
+                    arguments: None,
+                    absent: Absent::default(),
+                };
+
+                (self.body)(inner)
+            }
+        }
+    }
+}
+
+// Calls `body` through each interceptor registered in this instance of the module
+pub(crate) fn dispatch<'cx>(
+    mut cx: FunctionContext<'cx>,
+    info: &CallInfo,
+    body: &Body<'_, 'cx>,
+) -> JsResult<'cx, JsValue> {
+    let Some(interceptors) = InstanceData::interceptors(&mut cx) else {
+        return body(cx);
+    };
+
+    Next {
+        interceptors: &interceptors,
+        info,
+        body,
+    }
+    .call(&mut cx)
+}
@@ -1,4 +1,11 @@
-use std::{cell::RefCell, ffi::c_void, mem::MaybeUninit};
+use std::{
+    cell::{Cell, RefCell},
+    ffi::c_void,
+    mem::MaybeUninit,
+};
+
+#[cfg(feature = "napi-4")]
+use std::sync::{Arc, Weak};
 
 use crate::{
     context::{Cx, ModuleContext},
@@ -8,6 +15,9 @@ use crate::{
     types::{private::ValueInternal, JsObject},
 };
 
+#[cfg(feature = "napi-4")]
+use crate::sys::async_context::AsyncContext;
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Env(raw::Env);
@@ -21,6 +31,80 @@ impl From<raw::Env> for Env {
 thread_local! {
     #[allow(unused)]
     pub(crate) static IS_RUNNING: RefCell<bool> = const { RefCell::new(false) };
+
+    // Environment of the innermost Neon frame executing on this thread
+    static CURRENT_ENV: Cell<Option<Env>> = const { Cell::new(None) };
+
+    // Async context shared by the channels created in the innermost Neon frame. The
+    // current async context cannot change while a frame is executing.
+    #[cfg(feature = "napi-4")]
+    static CHANNEL_CONTEXT: RefCell<Weak<AsyncContext>> = const { RefCell::new(Weak::new()) };
+
+    // Whether the module has been initialized on this thread. Unlike `IS_RUNNING`, it
+    // is not cleared when the environment shuts down, since finalizers still execute.
+    static IS_JS_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+pub(crate) fn is_js_thread() -> bool {
+    IS_JS_THREAD.try_with(|v| v.get()).unwrap_or(false)
+}
+
+#[track_caller]
+pub(crate) fn assert_js_thread() {
+    if !is_js_thread() {
+        panic!(
+            "Neon context or handle used off of the JavaScript thread. Use `Root` and \
+             `Channel` to send values to other threads and back."
+        );
+    }
+}
+
+/// Marks an environment as current on this thread until dropped, then restores the
+/// previously current environment
+pub(crate) struct CurrentEnv {
+    env: Option<Env>,
+    #[cfg(feature = "napi-4")]
+    channel_context: Weak<AsyncContext>,
+}
+
+impl CurrentEnv {
+    pub(crate) fn enter(env: Env) -> Self {
+        Self {
+            env: CURRENT_ENV.with(|current| current.replace(Some(env))),
+            #[cfg(feature = "napi-4")]
+            channel_context: CHANNEL_CONTEXT.with(|current| current.take()),
+        }
+    }
+
+    pub(crate) fn get() -> Option<Env> {
+        CURRENT_ENV.with(|current| current.get())
+    }
+
+    #[cfg(feature = "napi-4")]
+    /// Returns the async context shared by channels in the current frame, calling
+    /// `capture` if there is none or it was destroyed with the last of them
+    pub(crate) fn channel_context(capture: impl FnOnce() -> AsyncContext) -> Arc<AsyncContext> {
+        CHANNEL_CONTEXT.with(|current| {
+            if let Some(async_context) = current.borrow().upgrade() {
+                return async_context;
+            }
+
+            let async_context = Arc::new(capture());
+
+            *current.borrow_mut() = Arc::downgrade(&async_context);
+            async_context
+        })
+    }
+}
+
+impl Drop for CurrentEnv {
+    fn drop(&mut self) {
+        CURRENT_ENV.with(|current| current.set(self.env));
+
+        #[cfg(feature = "napi-4")]
+        CHANNEL_CONTEXT
+            .with(|current| *current.borrow_mut() = std::mem::take(&mut self.channel_context));
+    }
 }
 
 impl Env {
@@ -50,6 +134,9 @@ pub trait ContextInternal<'cx>: Sized {
     fn cx(&self) -> &Cx<'cx>;
     fn cx_mut(&mut self) -> &mut Cx<'cx>;
     fn env(&self) -> Env {
+        #[cfg(debug_assertions)]
+        assert_js_thread();
+
         self.cx().env
     }
 }
@@ -57,6 +144,12 @@ pub trait ContextInternal<'cx>: Sized {
 fn default_main(mut cx: ModuleContext) -> NeonResult<()> {
     #[cfg(feature = "tokio-rt-multi-thread")]
     crate::executor::tokio::init(&mut cx)?;
+
+    #[cfg(feature = "testing")]
+    if !crate::macro_internal::TESTS.is_empty() {
+        crate::testing::export(&mut cx)?;
+    }
+
     crate::registered().export(&mut cx)
 }
 
@@ -65,6 +158,13 @@ fn init(cx: ModuleContext) -> NeonResult<()> {
         panic!("The `neon::main` macro must only be used once");
     }
 
+    #[cfg(feature = "napi-6")]
+    let cx = {
+        let mut cx = cx;
+        crate::intrinsics::init(&mut cx);
+        cx
+    };
+
     if let Some(main) = crate::macro_internal::MAIN.first() {
         main(cx)
     } else {
@@ -72,6 +172,13 @@ fn init(cx: ModuleContext) -> NeonResult<()> {
     }
 }
 
+// Declares the Node-API version selected with the `napi-*` features. Node uses it to
+// select version specific behavior and assumes version 8 if it is missing.
+#[no_mangle]
+extern "C" fn node_api_module_get_api_version_v1() -> i32 {
+    sys::bindings::NAPI_VERSION as i32
+}
+
 #[no_mangle]
 unsafe extern "C" fn napi_register_module_v1(env: *mut c_void, m: *mut c_void) -> *mut c_void {
     let env = env.cast();
@@ -82,6 +189,8 @@ unsafe extern "C" fn napi_register_module_v1(env: *mut c_void, m: *mut c_void) -
         *v.borrow_mut() = true;
     });
 
+    IS_JS_THREAD.with(|v| v.set(true));
+
     let env = Env(env);
     let exports = Handle::new_internal(JsObject::from_local(env, m.cast()));
     let _ = ModuleContext::with(env, exports, init);
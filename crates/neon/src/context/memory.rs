@@ -0,0 +1,46 @@
+use crate::{
+    context::{Context, Cx},
+    handle::Handle,
+    object::Object,
+    result::NeonResult,
+    types::JsObject,
+};
+
+/// Memory usage of the JavaScript environment, returned by
+/// [`Context::heap_statistics`].
+///
+/// All values are in bytes and are reported by
+/// [`process.memoryUsage()`](https://nodejs.org/api/process.html#processmemoryusage).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HeapStatistics {
+    /// Resident set size of the process.
+    pub rss: u64,
+    /// Total size of the JavaScript heap.
+    pub heap_total: u64,
+    /// Used size of the JavaScript heap.
+    pub heap_used: u64,
+    /// Memory outside of the JavaScript heap that is kept alive by JavaScript objects
+    /// and tracked by the engine.
+    pub external: u64,
+    /// Memory allocated for `ArrayBuffer`s, including all Node `Buffer`s. This is
+    /// included in `external`.
+    pub array_buffers: u64,
+}
+
+pub(super) fn heap_statistics(cx: &mut Cx) -> NeonResult<HeapStatistics> {
+    let usage: Handle<JsObject> = cx
+        .global::<JsObject>("process")?
+        .method(cx, "memoryUsage")?
+        .call()?;
+
+    let mut get = |key: &str| -> NeonResult<u64> { Ok(usage.prop(cx, key).get::<f64>()? as u64) };
+
+    Ok(HeapStatistics {
+        rss: get("rss")?,
+        heap_total: get("heapTotal")?,
+        heap_used: get("heapUsed")?,
+        external: get("external")?,
+        array_buffers: get("arrayBuffers")?,
+    })
+}
@@ -149,6 +149,32 @@
 //! [`Context::try_catch`] method, which catches any thrown
 //! exception and restores the context to non-throwing state.
 //!
+//! ## Reentrancy
+//!
+//! Calling into JavaScript, for example with [`JsFunction::call`](crate::types::JsFunction::call),
+//! may synchronously call back into the same or another Neon function. Each call into
+//! Rust receives a new context with its own scope, nested inside of the caller's. There
+//! is no limit on the depth of nesting, other than the size of the stack.
+//!
+//! Nesting follows a few rules, which the borrow checker enforces for contexts passed
+//! to Neon functions. A context obtained with the `unsafe` [`Cx::with_current`] is not
+//! borrowed from its caller, so code that uses it must uphold the rules itself:
+//!
+//! * Calling into JavaScript requires a mutable borrow of the context, so a nested
+//!   call can only happen while the outer Neon function is not otherwise using its
+//!   context. This includes [`Context::lock`]: buffers cannot be borrowed across a call
+//!   into JavaScript, because the guard borrows the context.
+//! * Handles created by a nested call belong to its scope. They may only be passed to
+//!   the caller by returning them to JavaScript.
+//! * An exception thrown by a nested call is returned to the caller as an [`Err`] from
+//!   the call into JavaScript, and can be propagated with `?` or caught with
+//!   [`Context::try_catch`].
+//!
+//! Rust state shared between nested calls, such as a [`RefCell`](std::cell::RefCell)
+//! stored with [`JsBox`](crate::types::JsBox) or [`wrap`](crate::object::wrap), may
+//! already be borrowed by a caller further up the stack. Prefer fallible borrows, like
+//! [`RefCell::try_borrow_mut`](std::cell::RefCell::try_borrow_mut), over ones that panic.
+//!
 //! ## See also
 //!
 //! 1. Ecma International. [Execution contexts](https://tc39.es/ecma262/#sec-execution-contexts), _ECMAScript Language Specification_.
@@ -159,9 +185,14 @@
 //! [iterator]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Iterators_and_Generators
 //! [question-mark]: https://doc.rust-lang.org/edition-guide/rust-2018/error-handling-and-panics/the-question-mark-operator-for-easier-error-handling.html
 
+#[cfg(feature = "napi-6")]
+pub(crate) mod interceptor;
 pub(crate) mod internal;
+mod memory;
+mod permissions;
 
 use std::{
+    cell::Cell,
     convert::Into,
     marker::PhantomData,
     ops::{Deref, DerefMut},
@@ -170,35 +201,50 @@ use std::{
 
 pub use crate::types::buffer::lock::Lock;
 
+pub use self::{memory::HeapStatistics, permissions::Permissions};
+
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::interceptor::{CallInfo, Next};
+
 use crate::{
     event::TaskBuilder,
     handle::Handle,
-    object::Object,
-    result::{JsResult, NeonResult, Throw},
+    meta::ModuleMetadata,
+    object::{Object, ObjectBuilder},
+    result::{JsResult, NeonResult, ResultExt, Throw, ThrowError},
     sys::{
         self, raw,
         scope::{EscapableHandleScope, HandleScope},
     },
     types::{
         boxed::{Finalize, JsBox},
-        error::JsError,
-        extract::FromArgs,
+        error::{self, ErrorBuilder, JsError, StackFrame},
+        extract::{FromArgs, TryIntoJs},
+        number::RangeError,
         private::ValueInternal,
         Deferred, JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsFunction, JsNull, JsNumber,
         JsObject, JsPromise, JsString, JsUndefined, JsValue, StringResult, Value,
     },
+    version::NodeVersion,
 };
 
-use self::internal::{ContextInternal, Env};
+use self::internal::{ContextInternal, CurrentEnv, Env};
 
 #[cfg(feature = "napi-4")]
-use crate::event::Channel;
+use crate::{event::Channel, types::Responder};
 
 #[cfg(feature = "napi-5")]
 use crate::types::date::{DateError, JsDate};
 
 #[cfg(feature = "napi-6")]
-use crate::lifecycle::InstanceData;
+use crate::{event::ThreadPool, lifecycle::InstanceData, thread::InstanceStore};
+
+#[cfg(feature = "napi-6")]
+use std::{
+    any,
+    cell::{RefCell, RefMut},
+};
 
 #[doc(hidden)]
 /// An execution context of a task completion callback.
@@ -253,11 +299,87 @@ impl<'cx> Cx<'cx> {
     }
 
     pub(crate) fn with_context<T, F: for<'b> FnOnce(Cx<'b>) -> T>(env: Env, f: F) -> T {
+        let _current = CurrentEnv::enter(env);
+
         f(Self {
             env,
             _phantom_inner: PhantomData,
         })
     }
+
+    /// Calls `f` with a context for the Neon function, callback, or module
+    /// initialization that is currently executing on this thread.
+    ///
+    /// This allows code that is called synchronously from a Neon frame, without being
+    /// passed a context, to use JavaScript. For example, a C library may invoke a
+    /// callback while a Neon function is calling into it. The context has its own
+    /// [temporary scope](Context::execute_scoped), so handles cannot outlive `f`.
+    ///
+    /// Returns `None` if no Neon frame is executing on this thread, such as on a
+    /// background thread, or if a JavaScript exception is pending.
+    ///
+    /// If `f` throws, the exception remains pending after `with_current` returns. The
+    /// enclosing Neon frame can catch it with [`Context::try_catch`].
+    ///
+    /// # Safety
+    ///
+    /// The new context is not borrowed from the enclosing frame's context, so the borrow
+    /// checker cannot prevent `f` from running JavaScript while the frame is using it.
+    /// The caller must ensure that the enclosing frame only reaches `with_current` at a
+    /// point where it could have called into JavaScript itself. In particular, the frame
+    /// must not hold a [`Lock`](Context::lock), a borrow from it, or a slice from
+    /// [`TypedArray::as_slice`](crate::types::buffer::TypedArray::as_slice) or
+    /// [`TypedArray::as_mut_slice`](crate::types::buffer::TypedArray::as_mut_slice),
+    /// since JavaScript called by `f` may write to or detach the underlying buffer.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use std::os::raw::c_void;
+    ///
+    /// // A C library that calls `cb` synchronously for each item
+    /// extern "C" fn for_each(len: u32, cb: extern "C" fn(u32, *mut c_void), data: *mut c_void) {
+    ///     (0..len).for_each(|i| cb(i, data));
+    /// }
+    ///
+    /// extern "C" fn log_item(i: u32, _data: *mut c_void) {
+    ///     // Safety: `log_items` holds no borrows while calling `for_each`
+    ///     unsafe {
+    ///         Cx::with_current(|cx| {
+    ///             cx.global::<JsObject>("console")?
+    ///                 .method(cx, "log")?
+    ///                 .arg(i)?
+    ///                 .exec()
+    ///         })
+    ///     };
+    /// }
+    ///
+    /// fn log_items(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     cx.try_catch(|_| {
+    ///         for_each(3, log_item, std::ptr::null_mut());
+    ///         Ok(())
+    ///     })
+    ///     .or_else(|err| cx.throw(err))?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub unsafe fn with_current<T, F>(f: F) -> Option<T>
+    where
+        F: for<'b> FnOnce(&mut Cx<'b>) -> T,
+    {
+        let env = CurrentEnv::get()?;
+
+        if sys::error::is_throwing(env.to_raw()) {
+            return None;
+        }
+
+        let scope = HandleScope::new(env.to_raw());
+        let result = f(&mut Cx::new(env));
+
+        drop(scope);
+
+        Some(result)
+    }
 }
 
 impl<'cx> ContextInternal<'cx> for Cx<'cx> {
@@ -287,6 +409,9 @@ impl<'cx> From<ModuleContext<'cx>> for Cx<'cx> {
 #[repr(C)]
 pub(crate) struct CallbackInfo<'cx> {
     info: raw::FunctionCallbackInfo,
+    // Promise declared with `FunctionContext::deferred_response`, returned in place
+    // of the function's own return value
+    response: Cell<Option<raw::Local>>,
     _lifetime: PhantomData<&'cx raw::FunctionCallbackInfo>,
 }
 
@@ -294,10 +419,15 @@ impl CallbackInfo<'_> {
     pub unsafe fn new(info: raw::FunctionCallbackInfo) -> Self {
         Self {
             info,
+            response: Cell::new(None),
             _lifetime: PhantomData,
         }
     }
 
+    pub(crate) fn take_response(&self) -> Option<raw::Local> {
+        self.response.take()
+    }
+
     fn kind<'b, C: Context<'b>>(&self, cx: &C) -> CallKind {
         if unsafe { sys::call::is_construct(cx.env().to_raw(), self.info) } {
             CallKind::Construct
@@ -306,6 +436,12 @@ impl CallbackInfo<'_> {
         }
     }
 
+    fn new_target<'b, C: Context<'b>>(&self, cx: &C) -> Option<raw::Local> {
+        let target = unsafe { sys::call::new_target(cx.env().to_raw(), self.info) };
+
+        (!target.is_null()).then_some(target)
+    }
+
     pub fn len<'b, C: Context<'b>>(&self, cx: &C) -> usize {
         unsafe { sys::call::len(cx.env().to_raw(), self.info) }
     }
@@ -359,6 +495,19 @@ pub enum CallKind {
     Call,
 }
 
+/// Arguments that [`FunctionContext::argument_opt_typed`] and
+/// [`FunctionContext::argument_or`] treat as absent. See
+/// [`FunctionContext::treat_as_absent`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Absent {
+    /// Arguments that were not passed. An explicit `undefined` is a value.
+    Missing,
+    /// Arguments that were not passed or are `undefined`, the same as a JavaScript
+    /// [default parameter](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Default_parameters).
+    #[default]
+    MissingOrUndefined,
+}
+
 /// An _execution context_, which represents the current state of a thread of execution in the JavaScript engine.
 ///
 /// All interaction with the JavaScript engine in Neon code is mediated through instances of this trait.
@@ -367,7 +516,8 @@ pub enum CallKind {
 pub trait Context<'a>: ContextInternal<'a> {
     /// Lock the JavaScript engine, returning an RAII guard that keeps the lock active as long as the guard is alive.
     ///
-    /// If this is not the currently active context (for example, if it was used to spawn a scoped context with `execute_scoped` or `compute_scoped`), this method will panic.
+    /// The guard borrows the context, so JavaScript cannot be called, and cannot call back
+    /// into Rust, while buffers are borrowed.
     fn lock<'b>(&'b mut self) -> Lock<'b, Self>
     where
         'a: 'b,
@@ -437,6 +587,48 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsNumber::new(self, x.into())
     }
 
+    /// Creates a `JsNumber` from an [`i64`], failing if the integer cannot be
+    /// represented exactly by a JavaScript number.
+    ///
+    /// Unlike [`number`](Context::number), which rounds large integers, this
+    /// checks that `n` is a [safe integer](crate::types::number).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn next_sequence() -> i64 { 42 }
+    /// fn sequence(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let n = next_sequence();
+    ///
+    ///     // Throws a `RangeError` if `n` would be rounded
+    ///     cx.number_from_i64(n).or_throw(&mut cx)
+    /// }
+    /// ```
+    fn number_from_i64(&mut self, n: i64) -> Result<Handle<'a, JsNumber>, RangeError<i64>> {
+        JsNumber::from_i64(self, n)
+    }
+
+    /// Creates a `JsNumber` from a [`u64`], failing if the integer cannot be
+    /// represented exactly by a JavaScript number.
+    fn number_from_u64(&mut self, n: u64) -> Result<Handle<'a, JsNumber>, RangeError<u64>> {
+        JsNumber::from_u64(self, n)
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Creates a `JsNumber` from an [`i64`] if it is a safe integer, or else a
+    /// [`JsBigInt`](crate::types::JsBigInt), so that the value is never rounded.
+    fn number_or_bigint_from_i64(&mut self, n: i64) -> Handle<'a, JsValue> {
+        crate::types::number::number_or_bigint_from_i64(self, n)
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Creates a `JsNumber` from a [`u64`] if it is a safe integer, or else a
+    /// [`JsBigInt`](crate::types::JsBigInt), so that the value is never rounded.
+    fn number_or_bigint_from_u64(&mut self, n: u64) -> Handle<'a, JsValue> {
+        crate::types::number::number_or_bigint_from_u64(self, n)
+    }
+
     /// Convenience method for creating a `JsString` value.
     ///
     /// If the string exceeds the limits of the JS engine, this method panics.
@@ -466,11 +658,79 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsObject::new(self)
     }
 
+    /// Convenience method for creating an empty `JsObject` value with a `null` prototype.
+    ///
+    /// Prefer this over [`empty_object`](Context::empty_object) for map-like objects
+    /// whose keys come from untrusted input.
+    fn empty_dict(&mut self) -> JsResult<'a, JsObject> {
+        JsObject::dict(self)
+    }
+
     /// Convenience method for creating an empty `JsArray` value.
     fn empty_array(&mut self) -> Handle<'a, JsArray> {
         JsArray::new(self, 0)
     }
 
+    /// Creates a `JsArray` containing the values of an iterator.
+    ///
+    /// The elements are appended in batches rather than with a property set for
+    /// each element, which is significantly faster for large arrays.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn squares(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    ///     let squares = (0..n)
+    ///         .map(|i| cx.number(i * i))
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     cx.array_from_iter(squares)
+    /// }
+    /// ```
+    fn array_from_iter<'b, I, V>(&mut self, iter: I) -> JsResult<'a, JsArray>
+    where
+        I: IntoIterator<Item = Handle<'b, V>>,
+        V: Value,
+    {
+        let values = iter.into_iter().map(|v| v.to_local()).collect::<Vec<_>>();
+        let arr = JsArray::new(self, 0);
+
+        arr.push_locals(self, &values)?;
+
+        Ok(arr)
+    }
+
+    /// Creates a `JsArray` of numbers from a slice of `f64`, copying the numbers with a
+    /// single call into the JavaScript engine.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn halves(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let values = cx.argument::<JsArray>(0)?
+    ///         .to_f64_vec(&mut cx)?
+    ///         .into_iter()
+    ///         .map(|n| n / 2.0)
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     cx.array_from_f64s(&values)
+    /// }
+    /// ```
+    fn array_from_f64s(&mut self, values: &[f64]) -> JsResult<'a, JsArray> {
+        JsArray::from_f64s(self, values)
+    }
+
+    /// Creates a `JsArray` of numbers from a slice of `i32`, copying the numbers with a
+    /// single call into the JavaScript engine.
+    fn array_from_i32s(&mut self, values: &[i32]) -> JsResult<'a, JsArray> {
+        JsArray::from_i32s(self, values)
+    }
+
+    /// Creates an [`ObjectBuilder`] for constructing an object with many properties
+    /// in a single call.
+    fn object_builder(&mut self) -> ObjectBuilder<'_, 'a> {
+        ObjectBuilder::new(self.cx_mut())
+    }
+
     /// Convenience method for creating an empty `JsArrayBuffer` value.
     fn array_buffer(&mut self, size: usize) -> JsResult<'a, JsArrayBuffer> {
         JsArrayBuffer::new(self, size)
@@ -509,6 +769,25 @@ pub trait Context<'a>: ContextInternal<'a> {
         global.get(self, name)
     }
 
+    /// Sets the value of a global variable, equivalent to `globalThis[name] = v`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn set_global(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let version = cx.string("1.0.0");
+    ///
+    /// cx.global_set("MY_LIBRARY_VERSION", version)?;
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    fn global_set<V: Value>(&mut self, name: &str, v: Handle<V>) -> NeonResult<()> {
+        let global = self.global_object();
+
+        global.set(self, name, v)?;
+
+        Ok(())
+    }
+
     /// Produces a handle to the JavaScript global object.
     fn global_object(&mut self) -> Handle<'a, JsObject> {
         JsObject::build(|out| unsafe {
@@ -517,7 +796,17 @@ pub trait Context<'a>: ContextInternal<'a> {
     }
 
     /// Throws a JS value.
+    ///
+    /// If an error decorator has been registered with
+    /// [`ModuleContext::set_error_decorator`], it is called first when `v` is an `Error`.
     fn throw<T: Value, U>(&mut self, v: Handle<T>) -> NeonResult<U> {
+        #[cfg(feature = "napi-6")]
+        {
+            let v = JsValue::new_internal(v.to_local());
+
+            InstanceData::decorate_error(self, v)?;
+        }
+
         unsafe {
             sys::error::throw(self.env().to_raw(), v.to_local());
             Err(Throw::new())
@@ -539,6 +828,61 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsError::range_error(self, msg)
     }
 
+    /// Captures the current JavaScript stack trace, from the innermost frame outwards.
+    ///
+    /// The number of frames is limited by
+    /// [`Error.stackTraceLimit`](https://v8.dev/docs/stack-trace-api#stack-trace-collection-for-custom-exceptions).
+    /// Native functions, including the current Neon function, are not included.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn caller(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let frames = cx.capture_stack_trace()?;
+    ///
+    ///     match frames.first().and_then(|frame| frame.function_name.as_deref()) {
+    ///         Some(name) => Ok(cx.string(name).upcast()),
+    ///         None => Ok(cx.undefined().upcast()),
+    ///     }
+    /// }
+    /// ```
+    fn capture_stack_trace(&mut self) -> NeonResult<Vec<StackFrame>> {
+        error::capture_stack_trace(self.cx_mut())
+    }
+
+    /// Creates an [`ErrorBuilder`] for an error with a message and additional
+    /// properties, such as `code`.
+    fn error_builder<S: Into<String>>(&mut self, msg: S) -> ErrorBuilder<'_, 'a> {
+        ErrorBuilder::new(self.cx_mut(), msg.into())
+    }
+
+    /// Runs `f`, throwing its error as a JavaScript `Error` if it fails.
+    ///
+    /// The error can be any type implementing [`ThrowError`], which includes common
+    /// standard library errors. This allows the `?` operator to be used on Rust errors
+    /// within `f`, without converting each one to an exception.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn parse_port(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let port = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     cx.with_throw_guard(|cx| {
+    ///         let port: u16 = port.parse()?;
+    ///
+    ///         Ok::<_, std::num::ParseIntError>(cx.number(port))
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// **See also:** [`ResultExt::or_throw`]
+    fn with_throw_guard<T, E, F>(&mut self, f: F) -> NeonResult<T>
+    where
+        E: ThrowError,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        f(self).or_throw(self)
+    }
+
     /// Throws a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class.
     fn throw_error<S: AsRef<str>, T>(&mut self, msg: S) -> NeonResult<T> {
         let err = JsError::error(self, msg)?;
@@ -590,9 +934,12 @@ pub trait Context<'a>: ContextInternal<'a> {
     ///
     /// When using N-API >= 6,the channel returned by this method is backed by a shared queue.
     /// To create a channel backed by a _new_ queue see [`Channel`].
+    ///
+    /// Channels returned by calls in the same function invocation or callback share the
+    /// [async context](Channel#async-context) they capture.
     fn channel(&mut self) -> Channel {
         #[cfg(feature = "napi-6")]
-        let channel = InstanceData::channel(self);
+        let channel = InstanceData::channel(self, None);
 
         #[cfg(not(feature = "napi-6"))]
         let channel = Channel::new(self);
@@ -600,6 +947,86 @@ pub trait Context<'a>: ContextInternal<'a> {
         channel
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Schedules a closure to execute on the JavaScript
+    /// [microtask queue](https://nodejs.org/api/globals.html#queuemicrotaskcallback),
+    /// the same as `queueMicrotask` in JavaScript.
+    ///
+    /// Unlike [`Channel::send`], which must wait for the next turn of the event loop,
+    /// microtasks execute as soon as the currently executing JavaScript has finished,
+    /// before any I/O events or closures sent with [`Channel::send`]. Microtasks
+    /// execute in the order they were queued, interleaved with promise reactions.
+    ///
+    /// Since the closure always executes on the JavaScript thread, it does not need to
+    /// be `Send`. As with [`Channel::send`], an exception thrown by the closure is
+    /// reported as an uncaught exception.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn defer_log(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     cx.queue_microtask(move |mut cx| {
+    ///         let console: Handle<JsObject> = cx.global("console")?;
+    ///
+    ///         console.method(&mut cx, "log")?.arg(msg)?.exec()
+    ///     })?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn queue_microtask<F>(&mut self, f: F) -> NeonResult<()>
+    where
+        F: FnOnce(Cx) -> NeonResult<()> + 'static,
+    {
+        let callback = once(self, f)?;
+
+        crate::intrinsics::queue_microtask(self)?
+            .bind(self.cx_mut())
+            .arg(callback)?
+            .exec()
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Schedules a closure to execute on the
+    /// [`process.nextTick`](https://nodejs.org/api/process.html#processnexttickcallback-args)
+    /// queue.
+    ///
+    /// Closures scheduled with `next_tick` execute in order as soon as the currently
+    /// executing JavaScript has finished, before any I/O events or closures sent with
+    /// [`Channel::send`]. When called from an event loop callback, such as a timer or a
+    /// function called by JavaScript in one, they also execute before microtasks queued
+    /// with [`queue_microtask`](Context::queue_microtask) and promise reactions. When
+    /// called from within a microtask, the microtask queue is drained first.
+    ///
+    /// Exceptions are handled the same as [`queue_microtask`](Context::queue_microtask).
+    fn next_tick<F>(&mut self, f: F) -> NeonResult<()>
+    where
+        F: FnOnce(Cx) -> NeonResult<()> + 'static,
+    {
+        let callback = once(self, f)?;
+        let process = crate::intrinsics::process(self)?;
+
+        crate::intrinsics::next_tick(self)?
+            .bind(self.cx_mut())
+            .this(process)?
+            .arg(callback)?
+            .exec()
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Returns the key-value store shared by the current instance of the module.
+    ///
+    /// See [`InstanceStore`] for details.
+    fn instance_store(&mut self) -> NeonResult<InstanceStore<'_, 'a>> {
+        InstanceStore::new(self.cx_mut())
+    }
+
     /// Creates a [`Deferred`] and [`JsPromise`] pair. The [`Deferred`] handle can be
     /// used to resolve or reject the [`JsPromise`].
     ///
@@ -618,6 +1045,105 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsPromise::new(self)
     }
 
+    /// Returns a [`Permissions`] for querying the Node.js permission model.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn can_spawn(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let allowed = cx.permissions()?.has_child_process()?;
+    ///
+    ///     Ok(cx.boolean(allowed))
+    /// }
+    /// ```
+    fn permissions(&mut self) -> NeonResult<Permissions<'_, 'a>> {
+        Permissions::new(self.cx_mut())
+    }
+
+    /// Returns the memory usage of the JavaScript environment.
+    fn heap_statistics(&mut self) -> NeonResult<HeapStatistics> {
+        memory::heap_statistics(self.cx_mut())
+    }
+
+    /// Informs the garbage collector of a change in the amount of memory allocated
+    /// outside of the JavaScript heap that is kept alive by JavaScript objects. Returns
+    /// the new total of externally allocated memory.
+    ///
+    /// The garbage collector is unaware of large allocations owned by small JavaScript
+    /// objects, such as a [`JsBox`] holding a `Vec<u8>`, and may not collect them often
+    /// enough. Report the size of the allocation when it is created, and report a
+    /// matching negative change when it is freed, for example in [`Finalize`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// struct Image(Vec<u8>);
+    ///
+    /// impl Finalize for Image {
+    ///     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+    ///         cx.adjust_external_memory(-(self.0.len() as i64));
+    ///     }
+    /// }
+    ///
+    /// fn create_image(mut cx: FunctionContext) -> JsResult<JsBox<Image>> {
+    ///     let data = vec![0; 64 * 1024 * 1024];
+    ///
+    ///     cx.adjust_external_memory(data.len() as i64);
+    ///
+    ///     Ok(cx.boxed(Image(data)))
+    /// }
+    /// ```
+    fn adjust_external_memory(&mut self, change_in_bytes: i64) -> i64 {
+        unsafe { sys::mem::adjust_external_memory(self.env().to_raw(), change_in_bytes) }
+    }
+
+    #[cfg(feature = "napi-3")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+    /// Registers `f` to be called when the current JavaScript environment shuts down,
+    /// for example when the main thread exits or a worker thread is terminated.
+    ///
+    /// Native resources owned by an instance of the module, such as background threads,
+    /// sockets, or file handles, can be torn down here. Since a module may be loaded
+    /// in several worker threads, relying on process exit would leak them until every
+    /// environment has stopped.
+    ///
+    /// Hooks are called on the JavaScript thread in the reverse order that they were
+    /// registered, after JavaScript has stopped running; `f` cannot call into
+    /// JavaScript. If `f` panics, the panic is printed to stderr and the remaining
+    /// hooks are still called.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use std::sync::{
+    ///     atomic::{AtomicBool, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// fn start_worker(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let running = Arc::new(AtomicBool::new(true));
+    ///     let worker = std::thread::spawn({
+    ///         let running = running.clone();
+    ///
+    ///         move || {
+    ///             while running.load(Ordering::Relaxed) {
+    ///                 std::thread::sleep(std::time::Duration::from_millis(10));
+    ///             }
+    ///         }
+    ///     });
+    ///
+    ///     cx.on_exit(move || {
+    ///         running.store(false, Ordering::Relaxed);
+    ///         let _ = worker.join();
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn on_exit<F>(&mut self, f: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        unsafe { sys::lifecycle::add_cleanup_hook(self.env().to_raw(), f) };
+    }
+
     /// Creates a [`TaskBuilder`] which can be used to schedule the `execute`
     /// callback to asynchronously execute on the
     /// [Node worker pool](https://nodejs.org/en/docs/guides/dont-block-the-event-loop/).
@@ -643,6 +1169,162 @@ pub trait Context<'a>: ContextInternal<'a> {
         TaskBuilder::new(self, execute)
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Create a task builder for a task that runs on a [`ThreadPool`] instead of the Node
+    /// worker pool.
+    ///
+    /// The completion callbacks are the same as for [`Context::task`] and run on the
+    /// JavaScript thread.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::event::ThreadPool;
+    ///
+    /// fn greet(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let pool = cx.argument::<JsBox<ThreadPool>>(0)?;
+    ///     let name = cx.argument::<JsString>(1)?.value(&mut cx);
+    ///
+    ///     let promise = cx
+    ///         .task_on(&pool, move || format!("Hello, {}!", name))
+    ///         .promise(move |mut cx, greeting| Ok(cx.string(greeting)));
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    fn task_on<'cx, O, E>(&'cx mut self, pool: &ThreadPool, execute: E) -> TaskBuilder<'cx, Self, E>
+    where
+        'a: 'cx,
+        O: Send + 'static,
+        E: FnOnce() -> O + Send + 'static,
+    {
+        TaskBuilder::new(self, execute).on(pool)
+    }
+
+    #[cfg(feature = "diagnostics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+    /// Creates a JavaScript object describing the live handles in the process.
+    ///
+    /// See [`neon::diagnostics`](crate::diagnostics) and
+    /// [`Report::to_object`](crate::diagnostics::Report::to_object).
+    fn diagnostics_report(&mut self) -> JsResult<'a, JsObject> {
+        crate::diagnostics::report().to_object(self)
+    }
+
+    /// Returns the version of the running Node process.
+    ///
+    /// See [`neon::version::node`](crate::version::node).
+    fn node_version(&mut self) -> NodeVersion {
+        crate::version::node(self)
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Reads an environment variable, equivalent to `process.env[name]`.
+    ///
+    /// Unlike [`std::env::var`], this observes changes made to `process.env` by
+    /// JavaScript code. The `process` object is looked up once per module instance.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn is_debug(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let debug = cx.process_env("DEBUG")?.is_some_and(|v| v != "0");
+    ///
+    ///     Ok(cx.boolean(debug))
+    /// }
+    /// ```
+    fn process_env(&mut self, name: &str) -> NeonResult<Option<String>> {
+        let process = crate::intrinsics::process(self)?;
+        let env: Handle<JsObject> = process.get(self, "env")?;
+
+        let value = env.get_opt::<JsString, _, _>(self, name)?;
+
+        Ok(value.map(|v| v.value(self)))
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Reads the command-line arguments, equivalent to `process.argv`.
+    ///
+    /// The first two elements are the path of the Node executable and of the script
+    /// being run. The `process` object is looked up once per module instance.
+    fn process_argv(&mut self) -> NeonResult<Vec<String>> {
+        let process = crate::intrinsics::process(self)?;
+        let argv: Handle<JsArray> = process.get(self, "argv")?;
+
+        argv.to_vec(self)?
+            .into_iter()
+            .map(|arg| Ok(arg.downcast_or_throw::<JsString, _>(self)?.value(self)))
+            .collect()
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Loads a built-in module, e.g., `"node:fs"`, downcasting its exports to the
+    /// type `T`. Throws if `specifier` does not name a built-in module.
+    ///
+    /// Other modules are resolved relative to a file; see [`Context::require_from`].
+    ///
+    /// This requires `process.getBuiltinModule`, available since Node 20.16 and 22.3,
+    /// and throws on older versions.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn tmpdir(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let os: Handle<JsObject> = cx.require("node:os")?;
+    ///
+    ///     os.method(&mut cx, "tmpdir")?.call()
+    /// }
+    /// ```
+    ///
+    /// There is no equivalent for dynamic `import()`, since Node does not support it in
+    /// scripts evaluated through Node-API. An addon that needs to load an ES module can
+    /// be passed a function that calls `import()` from JavaScript.
+    fn require<T: Value>(&mut self, specifier: &str) -> JsResult<'a, T> {
+        let process = crate::intrinsics::process(self)?;
+        let get_builtin_module = crate::intrinsics::get_builtin_module(self)?;
+        let specifier = self.string(specifier);
+        let exports = get_builtin_module.call(self, process, [specifier.upcast()])?;
+
+        if exports.is_a::<JsUndefined, _>(self) {
+            let specifier = specifier.value(self);
+
+            return self.throw_error(format!("Cannot find built-in module '{specifier}'"));
+        }
+
+        exports.downcast_or_throw(self)
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Loads a module with CommonJS `require` as if called from the file at `base`,
+    /// downcasting its exports to the type `T`.
+    ///
+    /// Relative paths and package names resolve the same way as in a JavaScript module
+    /// at `base`. Node-API does not expose the path of the addon, so `base` is usually
+    /// `__filename` of the JavaScript module that loads the addon, passed in from
+    /// JavaScript.
+    ///
+    /// This requires `process.getBuiltinModule`, available since Node 20.16 and 22.3,
+    /// and throws on older versions.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Called from JavaScript as `addon.loadConfig(__filename)`
+    /// fn load_config(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let base = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     cx.require_from(&base, "./config.json")
+    /// }
+    /// ```
+    fn require_from<T: Value>(&mut self, base: &str, specifier: &str) -> JsResult<'a, T> {
+        let cx = self.cx_mut();
+        let create_require = crate::intrinsics::create_require(cx)?;
+        let require: Handle<JsFunction> = create_require.bind(cx).arg(base)?.call()?;
+
+        require.bind(cx).arg(specifier)?.call()
+    }
+
     #[cfg(feature = "sys")]
     #[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
     /// Gets the raw `sys::Env` for usage with Node-API.
@@ -651,6 +1333,24 @@ pub trait Context<'a>: ContextInternal<'a> {
     }
 }
 
+#[cfg(feature = "napi-6")]
+// Wraps a closure in a JavaScript function that may only be called once
+fn once<'a, C, F>(cx: &mut C, f: F) -> JsResult<'a, JsFunction>
+where
+    C: Context<'a>,
+    F: FnOnce(Cx) -> NeonResult<()> + 'static,
+{
+    let f = std::cell::Cell::new(Some(f));
+
+    JsFunction::new(cx, move |mut cx| {
+        if let Some(f) = f.take() {
+            Cx::with_context(cx.env(), f)?;
+        }
+
+        Ok(cx.undefined())
+    })
+}
+
 /// An execution context of module initialization.
 pub struct ModuleContext<'cx> {
     cx: Cx<'cx>,
@@ -679,6 +1379,8 @@ impl<'cx> ModuleContext<'cx> {
         exports: Handle<'cx, JsObject>,
         f: F,
     ) -> T {
+        let _current = CurrentEnv::enter(env);
+
         f(ModuleContext {
             cx: Cx::new(env),
             exports,
@@ -692,7 +1394,7 @@ impl<'cx> ModuleContext<'cx> {
         key: &str,
         f: fn(FunctionContext) -> JsResult<T>,
     ) -> NeonResult<()> {
-        let value = JsFunction::new(self, f)?.upcast::<JsValue>();
+        let value = JsFunction::new_export(self, key, f)?.upcast::<JsValue>();
         self.exports.clone().set(self, key, value)?;
         Ok(())
     }
@@ -704,19 +1406,225 @@ impl<'cx> ModuleContext<'cx> {
         F: Fn(FunctionContext) -> JsResult<V> + 'static,
         V: Value,
     {
-        let value = JsFunction::new(self, f)?.upcast::<JsValue>();
+        let value = JsFunction::new_export(self, key, f)?.upcast::<JsValue>();
         // Note: Cloning `exports` is necessary to avoid holding a shared reference to
         // `self` while attempting to use it mutably in `set`.
         self.exports.clone().set(self, key, value)?;
         Ok(())
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Registers a hook that is called with every `Error` thrown with [`Context::throw`]
+    /// or its helpers (e.g., [`Context::throw_error`]) in this instance of the module.
+    ///
+    /// The decorator may modify the error before it is thrown, for example to attach
+    /// diagnostic context uniformly instead of at each throw site. If the decorator itself
+    /// throws, that exception is thrown instead of the original error. Errors thrown while
+    /// the decorator is running are not decorated.
+    ///
+    /// Registering a new decorator replaces the previous one.
+    ///
+    /// ```
+    /// # fn main() {
+    /// # use neon::prelude::*;
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     cx.set_error_decorator(|cx, err| {
+    ///         err.prop(cx, "addonVersion").set(env!("CARGO_PKG_VERSION"))?;
+    ///         Ok(())
+    ///     });
+    ///
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn set_error_decorator<F>(&mut self, f: F)
+    where
+        F: for<'b> Fn(&mut Cx<'b>, Handle<'b, JsError>) -> NeonResult<()> + Send + Sync + 'static,
+    {
+        InstanceData::set_error_decorator(self, std::sync::Arc::new(f));
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Registers an interceptor that wraps every call to a Neon function in this instance
+    /// of the module, such as the functions it exports.
+    ///
+    /// An interceptor receives the context of the call, a [`CallInfo`] describing the
+    /// function, and the [`Next`] step of the chain. It continues the call with
+    /// [`Next::call`], and may act before and after it, replace the result, or return
+    /// without calling the function at all. This allows cross-cutting concerns, such as
+    /// logging, timing, or mapping errors, to be implemented once for every export.
+    ///
+    /// Interceptors run in the order they were added; the first one added is the
+    /// outermost. An interceptor added while a call is in progress only applies to
+    /// later calls.
+    ///
+    /// ```
+    /// # fn main() {
+    /// # use neon::prelude::*;
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     cx.add_interceptor(|cx, info, next| {
+    ///         let start = std::time::Instant::now();
+    ///         let result = next.call(cx);
+    ///
+    ///         eprintln!("{} took {:?}", info.name(), start.elapsed());
+    ///
+    ///         result
+    ///     });
+    ///
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn add_interceptor<F>(&mut self, f: F)
+    where
+        F: for<'a, 'b> Fn(
+                &mut FunctionContext<'b>,
+                &CallInfo,
+                Next<'a, 'b>,
+            ) -> JsResult<'b, JsValue>
+            + Send
+            + Sync
+            + 'static,
+    {
+        InstanceData::add_interceptor(self, std::sync::Arc::new(f));
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Exports the `Map` backing the module's [`InstanceStore`], so that JavaScript
+    /// code can read and write the same values as the Rust exports.
+    ///
+    /// ```
+    /// # fn main() {
+    /// # use neon::prelude::*;
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     cx.export_instance_store("store")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn export_instance_store(&mut self, key: &str) -> NeonResult<()> {
+        let map = self.instance_store()?.as_map();
+
+        self.export_value(key, map)
+    }
+
+    /// Exports an object describing the module as `__neon`, so that applications can
+    /// introspect loaded native modules for debugging and telemetry.
+    ///
+    /// The object has the following properties:
+    ///
+    /// | Property | Value |
+    /// |----------|-------|
+    /// | `name` | Name of the module's crate |
+    /// | `version` | Version of the module's crate |
+    /// | `gitHash` | Git commit, if provided at build time; see [`neon::metadata!`](crate::metadata) |
+    /// | `debug` | Whether the module was built with debug assertions |
+    /// | `arch` | Target architecture, e.g., `x86_64` |
+    /// | `os` | Target operating system, e.g., `linux` |
+    /// | `neonVersion` | Version of Neon |
+    /// | `napiVersion` | Node-API version the module was built against |
+    ///
+    /// ```
+    /// # fn main() {
+    /// # use neon::prelude::*;
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     cx.export_metadata(neon::metadata!())?;
+    ///
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn export_metadata(&mut self, metadata: ModuleMetadata) -> NeonResult<()> {
+        let value = self
+            .object_builder()
+            .prop("name", metadata.name())?
+            .prop("version", metadata.version())?
+            .prop("gitHash", metadata.git_hash())?
+            .prop("debug", metadata.debug())?
+            .prop("arch", std::env::consts::ARCH)?
+            .prop("os", std::env::consts::OS)?
+            .prop("neonVersion", crate::meta::VERSION)?
+            .prop("napiVersion", sys::bindings::NAPI_VERSION)?
+            .build()?;
+
+        self.export_value("__neon", value)
+    }
+
     /// Exports a JavaScript value from a Neon module.
     pub fn export_value<T: Value>(&mut self, key: &str, val: Handle<T>) -> NeonResult<()> {
         self.exports.clone().set(self, key, val)?;
         Ok(())
     }
 
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    /// Exports a value that is created by `f` the first time it is accessed.
+    ///
+    /// The export is installed as a getter. On the first access, the getter calls `f`
+    /// and replaces itself with an ordinary property holding the value, so `f` is only
+    /// called once and later accesses do not call into Rust. If `f` throws, the exception
+    /// is thrown from the property access and `f` is called again on the next access.
+    ///
+    /// This allows modules with a large API to avoid creating values, such as classes or
+    /// constant tables, that are never used.
+    ///
+    /// ```
+    /// # fn main() {
+    /// # use neon::prelude::*;
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     cx.export_lazy("squares", |cx| {
+    ///         let squares = cx.empty_array();
+    ///
+    ///         for i in 0..1000u32 {
+    ///             squares.prop(cx, i).set(i * i)?;
+    ///         }
+    ///
+    ///         Ok(squares)
+    ///     })?;
+    ///
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn export_lazy<F, V>(&mut self, key: &str, f: F) -> NeonResult<()>
+    where
+        F: for<'b> Fn(&mut Cx<'b>) -> JsResult<'b, V> + 'static,
+        V: Value,
+    {
+        let name = key.to_owned();
+        let get = JsFunction::callback(
+            move |mut cx: FunctionContext| {
+                let value = f(&mut cx)?.upcast::<JsValue>();
+                let this = cx.this::<JsObject>()?;
+
+                this.define_many(&mut cx, &[(name.as_str(), value)])?;
+
+                Ok(value)
+            },
+            &format!("get {key}"),
+        );
+
+        let env = self.env().to_raw();
+        let key = self.string(key).to_local();
+
+        unsafe {
+            match sys::object::define_getter(env, self.exports.to_local(), key, get) {
+                Ok(()) => Ok(()),
+                Err(sys::Status::PendingException) => Err(Throw::new()),
+                Err(_) => self.throw_type_error("cannot define property"),
+            }
+        }
+    }
+
     /// Produces a handle to a module's exports object.
     pub fn exports_object(&mut self) -> JsResult<'cx, JsObject> {
         Ok(self.exports)
@@ -743,6 +1651,7 @@ pub struct FunctionContext<'cx> {
     info: &'cx CallbackInfo<'cx>,
 
     arguments: Option<sys::call::Arguments>,
+    absent: Absent,
 }
 
 impl<'cx> Deref for FunctionContext<'cx> {
@@ -767,15 +1676,52 @@ impl<'cx> FunctionContext<'cx> {
         self.info.kind(self)
     }
 
+    /// Produces the [`new.target`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/new.target)
+    /// of the call, or `None` if the function was called without `new`.
+    ///
+    /// When the function is invoked through the constructor of a subclass, `new.target` is
+    /// the subclass. It can be forwarded to another constructor with
+    /// [`BindOptions::new_target`](crate::types::function::BindOptions::new_target) so that
+    /// the constructed object has the subclass's prototype.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Can be extended by a JavaScript class, e.g. `class MyError extends NativeError {}`
+    /// fn native_error(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let Some(new_target) = cx.new_target() else {
+    ///         return cx.throw_type_error("Constructor must be called with `new`");
+    ///     };
+    ///
+    ///     let message = cx.argument::<JsValue>(0)?;
+    ///     let error: Handle<JsFunction> = cx.global("Error")?;
+    ///
+    ///     error
+    ///         .bind(&mut cx)
+    ///         .new_target(new_target)?
+    ///         .arg(message)?
+    ///         .construct()
+    /// }
+    /// ```
+    pub fn new_target(&mut self) -> Option<Handle<'cx, JsFunction>> {
+        let target = self.info.new_target(self)?;
+
+        Some(Handle::new_internal(unsafe {
+            JsFunction::from_local(self.env(), target)
+        }))
+    }
+
     pub(crate) fn with<U, F: for<'b> FnOnce(FunctionContext<'b>) -> U>(
         env: Env,
         info: &'cx CallbackInfo<'cx>,
         f: F,
     ) -> U {
+        let _current = CurrentEnv::enter(env);
+
         f(FunctionContext {
             cx: Cx::new(env),
             info,
             arguments: None,
+            absent: Absent::default(),
         })
     }
 
@@ -790,16 +1736,54 @@ impl<'cx> FunctionContext<'cx> {
     }
 
     /// Produces the `i`th argument, or `None` if `i` is greater than or equal to `self.len()`.
+    ///
+    /// An argument passed explicitly as `undefined` is `Some`. Use
+    /// [`argument_opt_typed`](FunctionContext::argument_opt_typed) to treat it as missing.
     pub fn argument_opt(&mut self, i: usize) -> Option<Handle<'cx, JsValue>> {
-        let argv = if let Some(argv) = self.arguments.as_ref() {
-            argv
-        } else {
+        let env = self.env();
+
+        self.arguments()
+            .get(i)
+            .map(|v| Handle::new_internal(unsafe { JsValue::from_local(env, v) }))
+    }
+
+    /// Produces the arguments starting at index `from`, like a JavaScript
+    /// [rest parameter](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/rest_parameters).
+    /// Returns an empty `Vec` if `from` is greater than or equal to `self.len()`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Equivalent to `(sep, ...parts) => parts.join(sep)`
+    /// fn join(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let sep = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///     let parts = cx
+    ///         .rest_arguments(1)
+    ///         .into_iter()
+    ///         .map(|part| Ok(part.to_string(&mut cx)?.value(&mut cx)))
+    ///         .collect::<NeonResult<Vec<_>>>()?;
+    ///
+    ///     Ok(cx.string(parts.join(&sep)))
+    /// }
+    /// ```
+    pub fn rest_arguments(&mut self, from: usize) -> Vec<Handle<'cx, JsValue>> {
+        let env = self.env();
+
+        self.arguments()
+            .rest(from)
+            .iter()
+            .map(|&v| Handle::new_internal(unsafe { JsValue::from_local(env, v) }))
+            .collect()
+    }
+
+    // Arguments are read from Node-API once and cached
+    fn arguments(&mut self) -> &sys::call::Arguments {
+        if self.arguments.is_none() {
             let argv = self.info.argv(self);
-            self.arguments.insert(argv)
-        };
 
-        argv.get(i)
-            .map(|v| Handle::new_internal(unsafe { JsValue::from_local(self.env(), v) }))
+            self.arguments = Some(argv);
+        }
+
+        self.arguments.as_ref().unwrap()
     }
 
     /// Produces the `i`th argument and casts it to the type `V`, or throws an exception if `i` is greater than or equal to `self.len()` or cannot be cast to `V`.
@@ -810,6 +1794,81 @@ impl<'cx> FunctionContext<'cx> {
         }
     }
 
+    /// Sets which arguments [`argument_opt_typed`](FunctionContext::argument_opt_typed)
+    /// and [`argument_or`](FunctionContext::argument_or) treat as absent for the rest of
+    /// this call. Defaults to [`Absent::MissingOrUndefined`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::context::Absent;
+    ///
+    /// // Distinguishes `f()` from `f(undefined)`
+    /// fn describe(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     cx.treat_as_absent(Absent::Missing);
+    ///
+    ///     match cx.argument_opt_typed::<JsValue>(0)? {
+    ///         Some(v) => Ok(v),
+    ///         None => Ok(cx.string("missing").upcast()),
+    ///     }
+    /// }
+    /// ```
+    pub fn treat_as_absent(&mut self, absent: Absent) -> &mut Self {
+        self.absent = absent;
+        self
+    }
+
+    /// Produces the `i`th argument cast to the type `V`, or `None` if it is missing or
+    /// `undefined`, the same as a JavaScript
+    /// [default parameter](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Default_parameters).
+    /// `null` is not treated as missing. An explicit `undefined` can be treated as a value
+    /// with [`treat_as_absent`](FunctionContext::treat_as_absent).
+    ///
+    /// Throws an exception if the argument is present but cannot be cast to `V`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn greet(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let name = match cx.argument_opt_typed::<JsString>(0)? {
+    ///         Some(name) => name.value(&mut cx),
+    ///         None => "World".to_string(),
+    ///     };
+    ///
+    ///     Ok(cx.string(format!("Hello, {name}!")))
+    /// }
+    /// ```
+    pub fn argument_opt_typed<V: Value>(&mut self, i: usize) -> NeonResult<Option<Handle<'cx, V>>> {
+        let v = match self.argument_opt(i) {
+            Some(v) if self.absent == Absent::Missing || !v.is_a::<JsUndefined, _>(self) => v,
+            _ => return Ok(None),
+        };
+
+        v.downcast_or_throw(self).map(Some)
+    }
+
+    /// Produces the `i`th argument cast to the type `V`, or converts `default` if it is
+    /// missing or `undefined`, as with
+    /// [`argument_opt_typed`](FunctionContext::argument_opt_typed).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn repeat(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let s = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///     let n = cx.argument_or::<JsNumber>(1, 2.0)?.value(&mut cx);
+    ///
+    ///     Ok(cx.string(s.repeat(n as usize)))
+    /// }
+    /// ```
+    pub fn argument_or<V: Value>(
+        &mut self,
+        i: usize,
+        default: impl TryIntoJs<'cx, Value = V>,
+    ) -> JsResult<'cx, V> {
+        match self.argument_opt_typed(i)? {
+            Some(v) => Ok(v),
+            None => default.try_into_js(self),
+        }
+    }
+
     /// Produces a handle to the `this`-binding and attempts to downcast as a specific type.
     /// Equivalent to calling `cx.this_value().downcast_or_throw(&mut cx)`.
     ///
@@ -818,6 +1877,67 @@ impl<'cx> FunctionContext<'cx> {
         self.this_value().downcast_or_throw(self)
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Returns the Rust value attached to `this` with [`wrap`](crate::object::wrap).
+    ///
+    /// Throws a `TypeError` if `this` is not an object wrapping a `T`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use std::cell::RefCell;
+    ///
+    /// struct Counter {
+    ///     count: u32,
+    /// }
+    ///
+    /// impl Finalize for Counter {}
+    ///
+    /// // Installed as a method on objects wrapping a `RefCell<Counter>`
+    /// fn increment(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let mut counter = cx.this_internals_mut::<Counter>()?;
+    ///
+    ///     counter.count += 1;
+    ///
+    ///     let count = counter.count;
+    ///
+    ///     Ok(cx.number(count))
+    /// }
+    ///
+    /// fn count(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let count = cx.this_internals::<RefCell<Counter>>()?.borrow().count;
+    ///
+    ///     Ok(cx.number(count))
+    /// }
+    /// ```
+    pub fn this_internals<T: Finalize + 'static>(&mut self) -> NeonResult<&'cx T> {
+        let this = self.this::<JsObject>()?;
+
+        match crate::object::unwrap::<T, _>(self, this)? {
+            Some(internals) => Ok(internals),
+            None => self.throw_type_error(format!(
+                "expected `this` to wrap a {}",
+                any::type_name::<T>()
+            )),
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Mutably borrows the [`RefCell`] attached to `this` with [`wrap`](crate::object::wrap).
+    ///
+    /// Throws a `TypeError` if `this` is not an object wrapping a `RefCell<T>`, and an
+    /// `Error` if the value is already borrowed, e.g., by a method further up the
+    /// stack that called back into JavaScript.
+    pub fn this_internals_mut<T: Finalize + 'static>(&mut self) -> NeonResult<RefMut<'cx, T>> {
+        let internals = self.this_internals::<RefCell<T>>()?;
+
+        match internals.try_borrow_mut() {
+            Ok(internals) => Ok(internals),
+            Err(_) => self.throw_error("RefCell is already borrowed"),
+        }
+    }
+
     /// Produces a handle to the function's [`this`-binding](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/this#function_context).
     pub fn this_value(&mut self) -> Handle<'cx, JsValue> {
         JsValue::new_internal(self.info.this(self))
@@ -844,6 +1964,87 @@ impl<'cx> FunctionContext<'cx> {
         T::from_args(self)
     }
 
+    /// Extract Rust data from the JavaScript arguments, requiring the number of arguments
+    /// to exactly match the extractor.
+    ///
+    /// Similar to [`FunctionContext::args`], but throws a `TypeError` if the function was
+    /// called with more or fewer arguments than `T` extracts. JavaScript normally ignores
+    /// extra arguments and treats missing ones as `undefined`.
+    ///
+    /// ```
+    /// # use neon::{prelude::*, types::extract::*};
+    /// fn add(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     // Throws on `add(1)` or `add(1, 2, 3)`
+    ///     let (a, b): (f64, f64) = cx.args_strict()?;
+    ///
+    ///     Ok(cx.number(a + b))
+    /// }
+    /// ```
+    pub fn args_strict<T>(&mut self) -> NeonResult<T>
+    where
+        T: FromArgs<'cx>,
+    {
+        self.check_arity(T::ARITY)?;
+
+        T::from_args(self)
+    }
+
+    #[cfg(feature = "napi-4")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+    /// Declares that this call responds asynchronously, returning a [`Responder`] that
+    /// completes the response from any thread.
+    ///
+    /// Once a response is declared, the function returns a pending [`JsPromise`] to
+    /// its caller instead of the value it returns. The promise is settled when the
+    /// `Responder` is consumed. If the `Responder` is dropped without responding, the
+    /// promise is rejected with an `Error`.
+    ///
+    /// If the function throws, the exception is thrown to the caller as usual and the
+    /// promise is never observed.
+    ///
+    /// Throws an `Error` if a response was already declared for this call.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn compute_later(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    ///     let responder = cx.deferred_response()?;
+    ///
+    ///     std::thread::spawn(move || {
+    ///         responder.resolve(n * 2.0);
+    ///     });
+    ///
+    ///     // The caller receives a `Promise` that resolves to `n * 2`
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn deferred_response(&mut self) -> NeonResult<Responder> {
+        if self.info.response.get().is_some() {
+            return self.throw_error("A deferred response was already declared for this call");
+        }
+
+        let (deferred, promise) = self.promise();
+        let channel = self.channel();
+
+        self.info.response.set(Some(promise.to_local()));
+
+        Ok(Responder::new(deferred, channel))
+    }
+
+    pub(crate) fn check_arity(&mut self, expected: usize) -> NeonResult<()> {
+        let actual = self.len();
+
+        if actual == expected {
+            return Ok(());
+        }
+
+        let s = if expected == 1 { "" } else { "s" };
+
+        self.throw_type_error(format!(
+            "expected {expected} argument{s}, but received {actual}"
+        ))
+    }
+
     /// Extract Rust data from the JavaScript arguments.
     ///
     /// Similar to [`FunctionContext::args`], but does not throw a JavaScript exception on errors. Useful
@@ -0,0 +1,178 @@
+use std::path::Path;
+
+use crate::{
+    context::{Context, Cx},
+    handle::Handle,
+    object::Object,
+    result::NeonResult,
+    types::JsObject,
+};
+
+/// Queries the [Node.js permission model](https://nodejs.org/api/permissions.html).
+///
+/// When Node is started with `--permission` (`--experimental-permission` in Node 20),
+/// access to the file system, child processes, worker threads, and other resources
+/// must be explicitly granted. Operations performed by native code are not checked by
+/// Node, so an addon should query the relevant permission before acting on behalf of
+/// JavaScript and fail with an actionable error.
+///
+/// When the permission model is not enabled, every permission is granted.
+///
+/// `Permissions` is created with [`Context::permissions`].
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// fn read_config(mut cx: FunctionContext) -> JsResult<JsString> {
+///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///     // Throws an `ERR_ACCESS_DENIED` error if reading `path` is not allowed
+///     cx.permissions()?.require_fs_read(&path)?;
+///
+///     let contents = std::fs::read_to_string(&path)
+///         .or_else(|err| cx.throw_error(err.to_string()))?;
+///
+///     Ok(cx.string(contents))
+/// }
+/// ```
+pub struct Permissions<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    // `process.permission`; `None` if the permission model is disabled
+    permission: Option<Handle<'cx, JsObject>>,
+}
+
+impl<'a, 'cx> Permissions<'a, 'cx> {
+    pub(super) fn new(cx: &'a mut Cx<'cx>) -> NeonResult<Self> {
+        let process = cx.global::<JsObject>("process")?;
+        let permission = process
+            .prop(cx, "permission")
+            .get::<Option<Handle<JsObject>>>()?;
+
+        Ok(Self { cx, permission })
+    }
+
+    /// Returns `true` if the permission model is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.permission.is_some()
+    }
+
+    /// Returns `true` if the permission `scope`, such as `"fs.read"` or `"child"`, is
+    /// granted, optionally for a specific resource.
+    ///
+    /// Scopes that are unknown to the running version of Node are not granted.
+    pub fn has(&mut self, scope: &str, reference: Option<&str>) -> NeonResult<bool> {
+        let Some(permission) = self.permission else {
+            return Ok(true);
+        };
+
+        let mut has = permission.method(self.cx, "has")?;
+
+        has.arg(scope)?;
+
+        if let Some(reference) = reference {
+            has.arg(reference)?;
+        }
+
+        has.call()
+    }
+
+    /// Returns `true` if the file system may be read at `path`.
+    ///
+    /// Node only checks paths that are valid Unicode, so other paths are not granted
+    /// when the permission model is enabled.
+    pub fn has_fs_read(&mut self, path: impl AsRef<Path>) -> NeonResult<bool> {
+        self.has_path("fs.read", path.as_ref())
+    }
+
+    /// Returns `true` if the file system may be written at `path`.
+    ///
+    /// Node only checks paths that are valid Unicode, so other paths are not granted
+    /// when the permission model is enabled.
+    pub fn has_fs_write(&mut self, path: impl AsRef<Path>) -> NeonResult<bool> {
+        self.has_path("fs.write", path.as_ref())
+    }
+
+    /// Returns `true` if child processes may be spawned.
+    pub fn has_child_process(&mut self) -> NeonResult<bool> {
+        self.has("child", None)
+    }
+
+    /// Returns `true` if worker threads may be created.
+    pub fn has_worker(&mut self) -> NeonResult<bool> {
+        self.has("worker", None)
+    }
+
+    /// Throws an `ERR_ACCESS_DENIED` error, naming the missing permission and the flag
+    /// that grants it, unless the permission `scope` is granted.
+    pub fn require(&mut self, scope: &str, reference: Option<&str>) -> NeonResult<()> {
+        if self.has(scope, reference)? {
+            return Ok(());
+        }
+
+        self.deny(scope, reference)
+    }
+
+    /// Throws an `ERR_ACCESS_DENIED` error unless the file system may be read at `path`.
+    pub fn require_fs_read(&mut self, path: impl AsRef<Path>) -> NeonResult<()> {
+        self.require_path("fs.read", path.as_ref())
+    }
+
+    /// Throws an `ERR_ACCESS_DENIED` error unless the file system may be written at `path`.
+    pub fn require_fs_write(&mut self, path: impl AsRef<Path>) -> NeonResult<()> {
+        self.require_path("fs.write", path.as_ref())
+    }
+
+    fn has_path(&mut self, scope: &str, path: &Path) -> NeonResult<bool> {
+        match path.to_str() {
+            Some(path) => self.has(scope, Some(path)),
+            // `process.permission.has` only accepts strings, so the path cannot be checked
+            None => Ok(!self.is_enabled()),
+        }
+    }
+
+    fn require_path(&mut self, scope: &str, path: &Path) -> NeonResult<()> {
+        if self.has_path(scope, path)? {
+            return Ok(());
+        }
+
+        self.deny(scope, Some(&path.to_string_lossy()))
+    }
+
+    fn deny<T>(&mut self, scope: &str, reference: Option<&str>) -> NeonResult<T> {
+        let mut msg =
+            format!("Access to this API has been restricted: missing `{scope}` permission");
+
+        if let Some(reference) = reference {
+            msg.push_str(&format!(" for {reference:?}"));
+        }
+
+        if let Some(flag) = flag(scope) {
+            msg.push_str(&format!(". Grant it by starting Node with `{flag}`"));
+        }
+
+        let err = self.cx.error(msg)?;
+
+        err.prop(self.cx, "code").set("ERR_ACCESS_DENIED")?;
+        err.prop(self.cx, "permission").set(scope)?;
+
+        if let Some(reference) = reference {
+            err.prop(self.cx, "resource").set(reference)?;
+        }
+
+        self.cx.throw(err)
+    }
+}
+
+// Command line flag that grants a permission scope
+fn flag(scope: &str) -> Option<&'static str> {
+    Some(match scope {
+        "fs.read" => "--allow-fs-read",
+        "fs.write" => "--allow-fs-write",
+        "child" => "--allow-child-process",
+        "worker" => "--allow-worker",
+        "addon" => "--allow-addons",
+        "wasi" => "--allow-wasi",
+        _ => return None,
+    })
+}
@@ -0,0 +1,201 @@
+//! Diagnostics for finding leaked handles.
+//!
+//! When the `diagnostics` feature is enabled, Neon counts the handles that keep
+//! JavaScript values alive or hold a lock on their data:
+//!
+//! * [`Root`](crate::handle::Root)s, including clones
+//! * [`Deferred`](crate::types::Deferred)s that have not been settled
+//! * Binary data borrowed with [`TypedArray::try_borrow`](crate::types::buffer::TypedArray::try_borrow)
+//!   and [`TypedArray::try_borrow_mut`](crate::types::buffer::TypedArray::try_borrow_mut)
+//!
+//! A count that grows without bound in a long-running process usually indicates a
+//! leak. To find where the leaked handles are created, [`capture_backtraces`] records
+//! a backtrace for each handle created after it is enabled. Capturing backtraces is
+//! slow and should only be enabled while investigating a leak.
+//!
+//! Counts are shared by every instance of the module in the process, including
+//! instances in worker threads.
+//!
+//! # Example
+//!
+//! ```
+//! # use neon::prelude::*;
+//! // Called from JavaScript, e.g., by an admin endpoint of a service
+//! fn handle_report(mut cx: FunctionContext) -> JsResult<JsObject> {
+//!     neon::diagnostics::capture_backtraces(true);
+//!
+//!     cx.diagnostics_report()
+//! }
+//! ```
+
+use std::{
+    backtrace::Backtrace,
+    collections::BTreeMap,
+    fmt,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use crate::{
+    context::Context,
+    object::Object,
+    result::JsResult,
+    types::{JsArray, JsObject},
+};
+
+static COUNTS: [AtomicUsize; 3] = [
+    AtomicUsize::new(0),
+    AtomicUsize::new(0),
+    AtomicUsize::new(0),
+];
+static CAPTURE: AtomicBool = AtomicBool::new(false);
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+// Backtraces of live handles by creation order
+static BACKTRACES: Mutex<BTreeMap<u64, (Kind, Backtrace)>> = Mutex::new(BTreeMap::new());
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// The kind of handle being tracked.
+pub enum Kind {
+    /// A [`Root`](crate::handle::Root)
+    Root,
+    /// A [`Deferred`](crate::types::Deferred)
+    Deferred,
+    /// A borrow of binary data, [`Ref`](crate::types::buffer::Ref) or
+    /// [`RefMut`](crate::types::buffer::RefMut)
+    Borrow,
+}
+
+impl Kind {
+    const ALL: [Kind; 3] = [Kind::Root, Kind::Deferred, Kind::Borrow];
+
+    fn count(self) -> &'static AtomicUsize {
+        &COUNTS[self as usize]
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Kind::Root => "root",
+            Kind::Deferred => "deferred",
+            Kind::Borrow => "borrow",
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Enables or disables capturing a backtrace when a handle is created.
+///
+/// Only handles created while capturing is enabled have a backtrace in the
+/// [`Report`]. Disabling capturing does not discard the backtraces of live handles.
+pub fn capture_backtraces(enabled: bool) {
+    CAPTURE.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns a snapshot of the live handles.
+pub fn report() -> Report {
+    let counts = Kind::ALL.map(|kind| kind.count().load(Ordering::Relaxed));
+    let backtraces = BACKTRACES
+        .lock()
+        .unwrap()
+        .values()
+        .map(|(kind, backtrace)| (*kind, backtrace.to_string()))
+        .collect();
+
+    Report { counts, backtraces }
+}
+
+#[derive(Clone, Debug)]
+/// A snapshot of the live handles, created with [`report`] or
+/// [`Context::diagnostics_report`].
+pub struct Report {
+    counts: [usize; 3],
+    backtraces: Vec<(Kind, String)>,
+}
+
+impl Report {
+    /// Returns the number of live handles of a kind.
+    pub fn count(&self, kind: Kind) -> usize {
+        self.counts[kind as usize]
+    }
+
+    /// Returns the creation backtraces of the live handles that were created while
+    /// [capturing](capture_backtraces) was enabled, oldest first.
+    pub fn backtraces(&self) -> impl Iterator<Item = (Kind, &str)> {
+        self.backtraces
+            .iter()
+            .map(|(kind, backtrace)| (*kind, backtrace.as_str()))
+    }
+
+    /// Converts the report to a JavaScript object with `roots`, `deferreds`, and
+    /// `borrows` counts and a `backtraces` array of `{ kind, backtrace }` objects.
+    pub fn to_object<'cx, C: Context<'cx>>(&self, cx: &mut C) -> JsResult<'cx, JsObject> {
+        let backtraces = JsArray::new(cx, self.backtraces.len());
+
+        for (i, (kind, backtrace)) in self.backtraces().enumerate() {
+            let entry = cx
+                .object_builder()
+                .prop("kind", kind.as_str())?
+                .prop("backtrace", backtrace)?
+                .build()?;
+
+            backtraces.prop(cx.cx_mut(), i as u32).set(entry)?;
+        }
+
+        cx.object_builder()
+            .prop("roots", self.count(Kind::Root) as f64)?
+            .prop("deferreds", self.count(Kind::Deferred) as f64)?
+            .prop("borrows", self.count(Kind::Borrow) as f64)?
+            .prop("backtraces", backtraces)?
+            .build()
+    }
+}
+
+// Counts a handle for as long as it is alive
+pub(crate) struct Tracker {
+    kind: Kind,
+    // Key in `BACKTRACES`, if one was captured
+    id: Option<u64>,
+}
+
+impl Tracker {
+    pub(crate) fn new(kind: Kind) -> Self {
+        kind.count().fetch_add(1, Ordering::Relaxed);
+
+        let id = CAPTURE.load(Ordering::Relaxed).then(|| {
+            let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+            let backtrace = Backtrace::force_capture();
+
+            BACKTRACES.lock().unwrap().insert(id, (kind, backtrace));
+            id
+        });
+
+        Self { kind, id }
+    }
+}
+
+impl fmt::Debug for Tracker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Tracker")
+    }
+}
+
+impl Drop for Tracker {
+    fn drop(&mut self) {
+        self.kind.count().fetch_sub(1, Ordering::Relaxed);
+
+        if let Some(id) = self.id {
+            // Handles may be dropped while unwinding; never panic on a poisoned lock
+            if let Ok(mut backtraces) = BACKTRACES.lock() {
+                backtraces.remove(&id);
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+#[cfg(feature = "napi-5")]
+use crate::{
+    context::Context,
+    result::JsResult,
+    types::{JsFunction, JsUndefined},
+};
+
+/// A flag for cooperatively cancelling background work, such as a
+/// [task](crate::event::TaskBuilder), from JavaScript.
+///
+/// Clones of a token share the same flag. Cancelling does not interrupt the work;
+/// the work should check [`is_cancelled`](CancellationToken::is_cancelled)
+/// periodically and stop early.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::event::CancellationToken;
+///
+/// // Returns `[promise, cancel]`
+/// fn count_primes(mut cx: FunctionContext) -> JsResult<JsArray> {
+///     let limit = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+///     let token = CancellationToken::new();
+///     let cancel = token.to_function(&mut cx)?;
+///
+///     let promise = cx
+///         .task(move || {
+///             let mut count = 0;
+///
+///             for n in 2..limit {
+///                 if token.is_cancelled() {
+///                     return None;
+///                 }
+///
+///                 if (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0) {
+///                     count += 1;
+///                 }
+///             }
+///
+///             Some(count)
+///         })
+///         .promise(|mut cx, count| match count {
+///             Some(count) => Ok(cx.number(count as f64)),
+///             None => cx.throw_error("Cancelled"),
+///         });
+///
+///     let result = cx.empty_array();
+///
+///     result.prop(&mut cx, 0).set(promise)?;
+///     result.prop(&mut cx, 1).set(cancel)?;
+///
+///     Ok(result)
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation. _Idempotent_
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` if cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    /// Creates a JavaScript function that cancels this token when called.
+    pub fn to_function<'a, C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsFunction> {
+        let token = self.clone();
+
+        JsFunction::new(cx, move |mut cx| -> JsResult<JsUndefined> {
+            token.cancel();
+            Ok(cx.undefined())
+        })
+    }
+}
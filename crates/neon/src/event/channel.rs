@@ -2,14 +2,21 @@ use std::{
     error, fmt,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc,
+        Arc, Condvar, Mutex,
     },
 };
 
 use crate::{
-    context::{internal::Env, Context, Cx},
+    context::{
+        internal::{CurrentEnv, Env},
+        Context, Cx,
+    },
     result::{NeonResult, ResultExt, Throw},
-    sys::{self, tsfn::ThreadsafeFunction},
+    sys::{
+        self, async_context::AsyncContext, bindings::ThreadsafeFunctionCallMode,
+        tsfn::ThreadsafeFunction,
+    },
+    types::{extract::TryIntoJs, Deferred, JsValue},
 };
 
 #[cfg(feature = "futures")]
@@ -46,11 +53,25 @@ mod oneshot {
 
 type Callback = Box<dyn FnOnce(sys::Env) + Send + 'static>;
 
+// Default names of the async resources created for a channel and its closures
+const QUEUE_NAME: &str = "neon threadsafe function";
+const CALLBACK_NAME: &str = "neon channel";
+
 /// Channel for scheduling Rust closures to execute on the JavaScript main thread.
 ///
 /// Cloning a `Channel` will create a new channel that shares a backing queue for
 /// events.
 ///
+/// # Async context
+///
+/// Closures execute in the [async context](https://nodejs.org/api/async_context.html)
+/// that was current when the `Channel` was created with [`Context::channel`] or
+/// [`Channel::new`], and are shared by its clones. For example, an
+/// [`AsyncLocalStorage`](https://nodejs.org/api/async_context.html#class-asynclocalstorage)
+/// store that was active when a function called `cx.channel()` is available to closures
+/// sent on the channel, even if they are sent from another thread. Channels created
+/// with the default name during the same call from JavaScript share an async context.
+///
 /// # Example
 ///
 /// The following example spawns a standard Rust thread to complete a computation
@@ -92,6 +113,7 @@ type Callback = Box<dyn FnOnce(sys::Env) + Send + 'static>;
 pub struct Channel {
     state: Arc<ChannelState>,
     has_ref: bool,
+    async_context: Option<Arc<AsyncContext>>,
 }
 
 impl fmt::Debug for Channel {
@@ -104,12 +126,80 @@ impl Channel {
     /// Creates an unbounded channel for scheduling closures on the JavaScript
     /// main thread
     pub fn new<'a, C: Context<'a>>(cx: &mut C) -> Self {
+        Self::with_capacity(cx, 0)
+    }
+
+    /// Creates a bounded channel that holds at most `max_queue_size` pending closures,
+    /// or an unbounded channel if `max_queue_size` is `0`.
+    ///
+    /// When the queue is full, [`Channel::try_send`] fails with a [`SendError`] for
+    /// which [`SendError::is_queue_full`] is `true` and [`Channel::send_blocking`] waits
+    /// for the JavaScript thread to make room. Producers can use these to apply
+    /// backpressure instead of growing the queue without limit.
+    pub fn with_capacity<'a, C: Context<'a>>(cx: &mut C, max_queue_size: usize) -> Self {
         Self {
-            state: Arc::new(ChannelState::new(cx)),
+            state: Arc::new(ChannelState::new(cx, QUEUE_NAME, max_queue_size)),
             has_ref: true,
+            async_context: Some(capture_async_context(cx, CALLBACK_NAME)),
         }
     }
 
+    /// Creates an unbounded channel with async resources named `name`.
+    ///
+    /// The name is the `type` reported to
+    /// [`async_hooks`](https://nodejs.org/api/async_hooks.html) for the channel and the
+    /// closures sent on it, making them identifiable in diagnostics and trace events.
+    /// It is usually prefixed with the name of the addon, e.g., `my-addon:events`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn subscribe(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///     let channel = Channel::with_name(&mut cx, "my-addon:events");
+    ///
+    ///     std::thread::spawn(move || {
+    ///         channel.send(move |mut cx| callback.into_inner(&mut cx).bind(&mut cx).exec());
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn with_name<'a, C: Context<'a>>(cx: &mut C, name: &str) -> Self {
+        Self {
+            state: Arc::new(ChannelState::new(cx, name, 0)),
+            has_ref: true,
+            async_context: Some(capture_async_context(cx, name)),
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    // Creates the unreferenced channel shared by a module instance. It does not
+    // capture an async context, since its clones capture one with `Channel::into_referenced`.
+    pub(crate) fn shared<'a, C: Context<'a>>(cx: &mut C) -> Self {
+        let mut channel = Self {
+            state: Arc::new(ChannelState::new(cx, QUEUE_NAME, 0)),
+            has_ref: true,
+            async_context: None,
+        };
+
+        channel.unref(cx);
+        channel
+    }
+
+    #[cfg(feature = "napi-6")]
+    // References a clone of the shared channel and captures the current async context
+    pub(crate) fn into_referenced<'a, C: Context<'a>>(
+        mut self,
+        cx: &mut C,
+        name: Option<&str>,
+    ) -> Self {
+        let name = name.unwrap_or(CALLBACK_NAME);
+
+        self.reference(cx);
+        self.async_context = Some(capture_async_context(cx, name));
+        self
+    }
+
     /// Allow the Node event loop to exit while this `Channel` exists.
     /// _Idempotent_
     pub fn unref<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
@@ -138,12 +228,23 @@ impl Channel {
 
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
     /// Panics if there is a libuv error
+    ///
+    /// An exception thrown by the closure is reported as an uncaught exception, in
+    /// addition to returning an error from [`JoinHandle::join`].
+    ///
+    /// If the queue of a bounded channel is full, the closure is dropped without being
+    /// executed and [`JoinHandle::join`] returns an error. Use [`Channel::try_send`] or
+    /// [`Channel::send_blocking`] to handle a full queue when sending.
     pub fn send<T, F>(&self, f: F) -> JoinHandle<T>
     where
         T: Send + 'static,
         F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
     {
-        self.try_send(f).unwrap()
+        match self.try_send(f) {
+            Ok(handle) => handle,
+            Err(err) if err.is_queue_full() => JoinHandle::error(JoinErrorType::QueueFull),
+            Err(err) => panic!("{err}"),
+        }
     }
 
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
@@ -155,26 +256,143 @@ impl Channel {
         T: Send + 'static,
         F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
     {
+        self.call(f, false)
+    }
+
+    /// Schedules a closure to execute on the JavaScript thread that created this Channel,
+    /// waiting for room in the queue if this is a bounded channel that is full.
+    ///
+    /// Returns a [`SendError`] if the task could not be scheduled because Node is
+    /// shutting down.
+    ///
+    /// This must not be called from the JavaScript thread. Since only the JavaScript
+    /// thread can make room in the queue, it would wait forever.
+    pub fn send_blocking<T, F>(&self, f: F) -> Result<JoinHandle<T>, SendError>
+    where
+        T: Send + 'static,
+        F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
+    {
+        self.call(f, true)
+    }
+
+    fn call<T, F>(&self, f: F, is_blocking: bool) -> Result<JoinHandle<T>, SendError>
+    where
+        T: Send + 'static,
+        F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
+    {
+        // Released when the closure is executed or dropped
+        let slot = match &self.state.queue {
+            Some(queue) => Some(queue.reserve(is_blocking)?),
+            None => None,
+        };
+
         let (tx, rx) = oneshot::channel();
-        let callback = Box::new(move |env| {
-            let env = Env::from(env);
+        let async_context = self.async_context.clone();
+
+        #[cfg(feature = "tracing")]
+        let span = crate::tracing::send();
+
+        let callback = Box::new(move |raw_env| {
+            let _slot = slot;
+            let env = Env::from(raw_env);
+
+            #[cfg(feature = "tracing")]
+            let _span = span.entered();
 
             // Note: It is sufficient to use `Cx` because
             // N-API creates a `HandleScope` before calling the callback.
-            Cx::with_context(env, move |cx| {
-                // Error can be ignored; it only means the user didn't join
-                let _ = tx.send(f(cx).map_err(Into::into));
-            });
+            let run = move || {
+                Cx::with_context(env, move |cx| {
+                    // Error can be ignored; it only means the user didn't join
+                    let _ = tx.send(f(cx).map_err(|_| JoinError(JoinErrorType::Throw)));
+                });
+            };
+
+            match async_context {
+                Some(async_context) => unsafe {
+                    async_context.enter(raw_env, run);
+
+                    // The last reference may be dropped here if the channel was dropped
+                    if let Ok(async_context) = Arc::try_unwrap(async_context) {
+                        async_context.destroy(raw_env);
+                    }
+                },
+                None => run(),
+            }
         });
 
-        self.state
-            .tsfn
-            .call(callback, None)
-            .map_err(|_| SendError)?;
+        self.state.call(callback)?;
 
         Ok(JoinHandle { rx })
     }
 
+    /// Settles a [`JsPromise`](crate::types::JsPromise) with the result of a closure
+    /// executed on the JavaScript thread that created this Channel.
+    ///
+    /// The value returned by the closure is converted to JavaScript with [`TryIntoJs`].
+    /// A returned `Err` is converted and used to reject the promise, as are exceptions
+    /// thrown during conversion. Otherwise, the promise is resolved with the converted
+    /// value. Errors that implement [`std::error::Error`] can be converted with
+    /// [`Error`](crate::types::extract::Error).
+    ///
+    /// Panics if there is a libuv error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::extract::Error;
+    ///
+    /// fn read_file(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///     let channel = cx.channel();
+    ///     let (deferred, promise) = cx.promise();
+    ///
+    ///     std::thread::spawn(move || {
+    ///         let contents = std::fs::read_to_string(path).map_err(Error::from);
+    ///
+    ///         channel.settle_with(deferred, move |_| contents);
+    ///     });
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    ///
+    /// If the queue of a bounded channel is full, the closure is dropped as with
+    /// [`Channel::send`].
+    pub fn settle_with<T, F>(&self, deferred: Deferred, f: F) -> JoinHandle<()>
+    where
+        T: for<'cx> TryIntoJs<'cx>,
+        F: FnOnce(&mut Cx) -> T + Send + 'static,
+    {
+        deferred.settle_with(self, move |mut cx| {
+            let value = f(&mut cx).try_into_js(&mut cx)?;
+
+            Ok(value.upcast::<JsValue>())
+        })
+    }
+
+    /// Settles a [`JsPromise`](crate::types::JsPromise) with the result of a closure
+    /// executed on the JavaScript thread that created this Channel.
+    ///
+    /// Usage is identical to [`Channel::settle_with`]. Returns a [`SendError`] if the
+    /// closure could not be scheduled, in which case the `Deferred` is dropped.
+    pub fn try_settle_with<T, F>(
+        &self,
+        deferred: Deferred,
+        f: F,
+    ) -> Result<JoinHandle<()>, SendError>
+    where
+        T: for<'cx> TryIntoJs<'cx>,
+        F: FnOnce(&mut Cx) -> T + Send + 'static,
+    {
+        deferred.try_settle_with(self, move |mut cx| {
+            let value = f(&mut cx).try_into_js(&mut cx)?;
+
+            Ok(value.upcast::<JsValue>())
+        })
+    }
+
     /// Returns a boolean indicating if this `Channel` will prevent the Node event
     /// loop from exiting.
     pub fn has_ref(&self) -> bool {
@@ -182,6 +400,19 @@ impl Channel {
     }
 }
 
+// Captures the current async context for closures sent on a channel. Channels with the
+// default name that are created in the same Neon frame share one.
+fn capture_async_context<'a, C: Context<'a>>(cx: &mut C, name: &str) -> Arc<AsyncContext> {
+    let env = cx.env().to_raw();
+    let capture = || unsafe { AsyncContext::new(env, name) };
+
+    if name == CALLBACK_NAME && CurrentEnv::get().is_some() {
+        CurrentEnv::channel_context(capture)
+    } else {
+        Arc::new(capture())
+    }
+}
+
 impl Clone for Channel {
     /// Returns a clone of the Channel instance that shares the internal
     /// unbounded queue with the original channel. Scheduling callbacks on the
@@ -198,6 +429,7 @@ impl Clone for Channel {
             return Self {
                 state: self.state.clone(),
                 has_ref: false,
+                async_context: self.async_context.clone(),
             };
         }
 
@@ -209,12 +441,24 @@ impl Clone for Channel {
         Self {
             state,
             has_ref: true,
+            async_context: self.async_context.clone(),
         }
     }
 }
 
 impl Drop for Channel {
     fn drop(&mut self) {
+        // The async context must be destroyed on the JavaScript thread. If it is still
+        // used by pending closures, the last of them destroys it instead. If the
+        // closure cannot be scheduled, Node is shutting down and the hook is skipped.
+        if let Some(async_context) = self.async_context.take() {
+            if let Ok(async_context) = Arc::try_unwrap(async_context) {
+                let _ = self
+                    .state
+                    .call(Box::new(move |env| unsafe { async_context.destroy(env) }));
+            }
+        }
+
         // Not a referenced event queue
         if !self.has_ref {
             return;
@@ -238,32 +482,42 @@ impl Drop for Channel {
         // UV thread if strong reference count goes to 0.
         let state = Arc::clone(&self.state);
 
-        // `Channel::try_send` will only fail if the environment has shutdown.
-        // In that case, the teardown will perform clean-up.
-        let _ = self.try_send(move |mut cx| {
-            state.unref(&mut cx);
-            Ok(())
-        });
+        // This bypasses the capacity of a bounded channel, since waiting for room
+        // would deadlock on the JavaScript thread. It will only fail if the
+        // environment has shutdown. In that case, the teardown will perform clean-up.
+        let _ = self.state.call(Box::new(move |env| {
+            Cx::with_context(Env::from(env), |mut cx| state.unref(&mut cx));
+        }));
     }
 }
 
 /// An owned permission to join on the result of a closure sent to the JavaScript main
 /// thread with [`Channel::send`].
 pub struct JoinHandle<T> {
-    // `Err` is always `Throw`, but `Throw` cannot be sent across threads
-    rx: oneshot::Receiver<Result<T, SendThrow>>,
+    rx: oneshot::Receiver<Result<T, JoinError>>,
+}
+
+impl<T> JoinHandle<T> {
+    // Creates a handle for a closure that was never executed
+    fn error(kind: JoinErrorType) -> Self {
+        let (tx, rx) = oneshot::channel();
+        let _ = tx.send(Err(JoinError(kind)));
+
+        JoinHandle { rx }
+    }
 }
 
 impl<T> JoinHandle<T> {
     /// Waits for the associated closure to finish executing
     ///
-    /// If the closure panics or throws an exception, `Err` is returned
+    /// If the closure panics, throws an exception or is not executed because the queue
+    /// of a bounded channel was full, `Err` is returned
     ///
     /// # Panics
     ///
     /// This function panics if called within an asynchronous execution context.
     pub fn join(self) -> Result<T, JoinError> {
-        Ok(self.rx.blocking_recv()??)
+        self.rx.blocking_recv()?
     }
 }
 
@@ -274,29 +528,24 @@ impl<T> Future for JoinHandle<T> {
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
         match Pin::new(&mut self.rx).poll(cx) {
-            Poll::Ready(result) => {
-                // Flatten `Result<Result<T, SendThrow>, RecvError>` by mapping to
-                // `Result<T, JoinError>`. This can be simplified by replacing the
-                // closure with a try-block after stabilization.
-                // https://doc.rust-lang.org/beta/unstable-book/language-features/try-blocks.html
-                let get_result = move || Ok(result??);
-
-                Poll::Ready(get_result())
-            }
+            // Flatten `Result<Result<T, JoinError>, RecvError>` to `Result<T, JoinError>`
+            Poll::Ready(result) => Poll::Ready(result.unwrap_or_else(|err| Err(err.into()))),
             Poll::Pending => Poll::Pending,
         }
     }
 }
 
 #[derive(Debug)]
-/// Error returned by [`JoinHandle::join`] indicating the associated closure panicked
-/// or threw an exception.
+/// Error returned by [`JoinHandle::join`] indicating the associated closure panicked,
+/// threw an exception, or was not executed because the queue of a bounded channel
+/// was full.
 pub struct JoinError(JoinErrorType);
 
 #[derive(Debug)]
 enum JoinErrorType {
     Panic,
     Throw,
+    QueueFull,
 }
 
 impl JoinError {
@@ -304,6 +553,7 @@ impl JoinError {
         match &self.0 {
             JoinErrorType::Panic => "Closure panicked before returning",
             JoinErrorType::Throw => "Closure threw an exception",
+            JoinErrorType::QueueFull => "Closure was not executed because the queue is full",
         }
     }
 }
@@ -348,15 +598,43 @@ impl<T> ResultExt<T> for Result<T, JoinError> {
 /// The most likely cause of a failure is that Node is shutting down. This may occur if the
 /// process is forcefully exiting even if the channel is referenced. For example, by calling
 /// `process.exit()`.
-//
-// NOTE: These docs will need to be updated to include `QueueFull` if bounded queues are
-// implemented.
+///
+/// A closure sent with [`Channel::try_send`] may also fail to be scheduled because the
+/// queue of a channel created with [`Channel::with_capacity`] is full. This can be checked
+/// with [`SendError::is_queue_full`].
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
-pub struct SendError;
+pub struct SendError(SendErrorKind);
+
+enum SendErrorKind {
+    Closing,
+    QueueFull,
+}
+
+impl SendError {
+    #[cfg(feature = "napi-6")]
+    pub(crate) fn closing() -> Self {
+        SendError(SendErrorKind::Closing)
+    }
+
+    /// Returns `true` if the closure was not scheduled because the queue of a bounded
+    /// channel was full. Sending may succeed after the JavaScript thread makes room.
+    pub fn is_queue_full(&self) -> bool {
+        matches!(self.0, SendErrorKind::QueueFull)
+    }
+
+    /// Returns `true` if the closure was not scheduled because Node is shutting down.
+    /// Sending will never succeed again.
+    pub fn is_closing(&self) -> bool {
+        matches!(self.0, SendErrorKind::Closing)
+    }
+}
 
 impl fmt::Display for SendError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SendError")
+        match self.0 {
+            SendErrorKind::Closing => write!(f, "SendError"),
+            SendErrorKind::QueueFull => write!(f, "SendError: queue is full"),
+        }
     }
 }
 
@@ -371,17 +649,38 @@ impl error::Error for SendError {}
 struct ChannelState {
     tsfn: ThreadsafeFunction<Callback>,
     ref_count: AtomicUsize,
+    // Capacity of a bounded channel. The threadsafe function itself is always
+    // unbounded, so that internal closures are never blocked by a full queue.
+    queue: Option<Arc<Queue>>,
 }
 
 impl ChannelState {
-    fn new<'a, C: Context<'a>>(cx: &mut C) -> Self {
-        let tsfn = unsafe { ThreadsafeFunction::new(cx.env().to_raw(), Self::callback) };
+    fn new<'a, C: Context<'a>>(cx: &mut C, name: &str, max_queue_size: usize) -> Self {
+        let tsfn = unsafe {
+            ThreadsafeFunction::with_capacity(cx.env().to_raw(), name, 0, Self::callback)
+        };
+        let queue = (max_queue_size > 0).then(|| {
+            Arc::new(Queue {
+                max_size: max_queue_size,
+                len: Mutex::new(0),
+                has_room: Condvar::new(),
+            })
+        });
+
         Self {
             tsfn,
             ref_count: AtomicUsize::new(1),
+            queue,
         }
     }
 
+    // Schedules a callback without counting it against the capacity of the channel
+    fn call(&self, callback: Callback) -> Result<(), SendError> {
+        self.tsfn
+            .call(callback, Some(ThreadsafeFunctionCallMode::NonBlocking))
+            .map_err(|_| SendError(SendErrorKind::Closing))
+    }
+
     fn reference<'a, C: Context<'a>>(&self, cx: &mut C) {
         // We can use relaxed ordering because `reference()` can only be called
         // on the Event-Loop thread.
@@ -417,3 +716,41 @@ impl ChannelState {
         }
     }
 }
+
+// Tracks the closures pending on a bounded channel
+struct Queue {
+    max_size: usize,
+    len: Mutex<usize>,
+    has_room: Condvar,
+}
+
+impl Queue {
+    // Reserves room for a closure, optionally waiting for the JavaScript thread to
+    // make room if the queue is full
+    fn reserve(self: &Arc<Self>, is_blocking: bool) -> Result<Slot, SendError> {
+        let mut len = self.len.lock().unwrap();
+
+        while *len >= self.max_size {
+            if !is_blocking {
+                return Err(SendError(SendErrorKind::QueueFull));
+            }
+
+            len = self.has_room.wait(len).unwrap();
+        }
+
+        *len += 1;
+
+        Ok(Slot(Arc::clone(self)))
+    }
+}
+
+// Room reserved in a bounded queue. It is released when the closure holding it is
+// executed, or dropped because Node is shutting down.
+struct Slot(Arc<Queue>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *self.0.len.lock().unwrap() -= 1;
+        self.0.has_room.notify_one();
+    }
+}
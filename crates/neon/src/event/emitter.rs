@@ -0,0 +1,169 @@
+use std::{
+    fmt, mem,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    context::{Context, Cx},
+    event::{Channel, SendError},
+    handle::{Handle, Root},
+    object::Object,
+    result::NeonResult,
+    types::{extract::TryIntoJs, JsObject},
+};
+
+/// Emits events on a JavaScript [`EventEmitter`](https://nodejs.org/api/events.html#class-eventemitter)
+/// from any thread.
+///
+/// Calls to [`emit`](Emitter::emit) are queued and delivered on the JavaScript thread
+/// by calling `emitter.emit(event, payload)`, with the payload converted to JavaScript.
+/// Events emitted in quick succession are delivered together in a single [`Channel`]
+/// callback, in the order they were emitted.
+///
+/// Cloning an `Emitter` shares the queue and the `EventEmitter`. Like a [`Channel`], an
+/// `Emitter` keeps the event loop alive until it and all of its clones are dropped.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::{thread, time::Duration};
+///
+/// use neon::event::Emitter;
+///
+/// // Emits a `tick` event with a count every `ms` milliseconds, followed by `end`
+/// fn ticker(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let emitter = cx.argument::<JsObject>(0)?;
+///     let ms = cx.argument::<JsNumber>(1)?.value(&mut cx) as u64;
+///     let emitter = Emitter::<Option<f64>>::new(&mut cx, emitter);
+///
+///     thread::spawn(move || {
+///         for i in 0..3 {
+///             thread::sleep(Duration::from_millis(ms));
+///             let _ = emitter.emit("tick", Some(i as f64));
+///         }
+///
+///         let _ = emitter.emit("end", None);
+///     });
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+pub struct Emitter<T> {
+    inner: Arc<Inner<T>>,
+}
+
+struct Inner<T> {
+    channel: Channel,
+    emitter: Root<JsObject>,
+    queue: Mutex<Queue<T>>,
+}
+
+struct Queue<T> {
+    events: Vec<(String, T)>,
+    // A `Channel` callback has been sent and will deliver the queued events
+    scheduled: bool,
+}
+
+impl<T> Clone for Emitter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Emitter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Emitter")
+    }
+}
+
+impl<T> Emitter<T>
+where
+    T: for<'cx> TryIntoJs<'cx> + Send + 'static,
+{
+    /// Creates an `Emitter` for an `EventEmitter`, or any object with a compatible
+    /// `emit` method.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, emitter: Handle<JsObject>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                channel: Channel::new(cx),
+                emitter: emitter.root(cx),
+                queue: Mutex::new(Queue {
+                    events: Vec::new(),
+                    scheduled: false,
+                }),
+            }),
+        }
+    }
+
+    /// Returns the wrapped `EventEmitter`.
+    pub fn to_inner<'a, C: Context<'a>>(&self, cx: &mut C) -> Handle<'a, JsObject> {
+        self.inner.emitter.to_inner(cx)
+    }
+
+    /// Queues an event to be emitted on the JavaScript thread.
+    ///
+    /// Returns an error if the JavaScript environment has shut down. If a listener
+    /// throws, the remaining events in the batch are still emitted and the first
+    /// exception is treated the same as one thrown by a closure sent with
+    /// [`Channel::send`].
+    pub fn emit<E: Into<String>>(&self, event: E, payload: T) -> Result<(), SendError> {
+        let mut queue = self.inner.queue.lock().unwrap();
+
+        queue.events.push((event.into(), payload));
+
+        if queue.scheduled {
+            return Ok(());
+        }
+
+        let inner = self.inner.clone();
+
+        match self
+            .inner
+            .channel
+            .try_send(move |mut cx| inner.flush(&mut cx))
+        {
+            Ok(_) => {
+                queue.scheduled = true;
+                Ok(())
+            }
+            Err(err) => {
+                queue.events.clear();
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<T> Inner<T>
+where
+    T: for<'cx> TryIntoJs<'cx> + Send + 'static,
+{
+    fn flush(&self, cx: &mut Cx) -> NeonResult<()> {
+        let events = {
+            let mut queue = self.queue.lock().unwrap();
+
+            queue.scheduled = false;
+            mem::take(&mut queue.events)
+        };
+
+        let emitter = self.emitter.to_inner(cx);
+        let mut first_err = None;
+
+        for (event, payload) in events {
+            let result =
+                cx.try_catch(|cx| emitter.method(cx, "emit")?.arg(event)?.arg(payload)?.exec());
+
+            if let Err(err) = result {
+                first_err.get_or_insert(err);
+            }
+        }
+
+        match first_err {
+            Some(err) => cx.throw(err),
+            None => Ok(()),
+        }
+    }
+}
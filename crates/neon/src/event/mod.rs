@@ -119,11 +119,32 @@
 //! [psd-crate]: https://crates.io/crates/psd
 //! [psd-file]: https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/
 
+mod cancel;
 #[cfg(feature = "napi-4")]
 mod channel;
-
+#[cfg(feature = "napi-6")]
+mod emitter;
+#[cfg(feature = "napi-6")]
+mod pool;
+#[cfg(feature = "napi-6")]
+mod progress;
+#[cfg(feature = "napi-6")]
+mod stream;
 mod task;
 
+pub use self::cancel::CancellationToken;
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::emitter::Emitter;
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::pool::ThreadPool;
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::progress::ProgressReporter;
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::stream::{channel, Sender};
 pub use self::task::TaskBuilder;
 
 #[cfg(all(feature = "napi-5", feature = "futures"))]
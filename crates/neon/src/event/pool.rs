@@ -0,0 +1,171 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    num::NonZeroUsize,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+use crate::{
+    context::{Context, Cx},
+    lifecycle::InstanceData,
+    object::Object,
+    types::Finalize,
+};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A pool of Rust threads for running [tasks](crate::event::TaskBuilder) outside of the
+/// Node worker pool.
+///
+/// Tasks scheduled with [`Context::task`] run on the Node worker pool, which is shared
+/// with Node's own file system, DNS, and compression operations. Long-running CPU work
+/// can occupy every worker and delay that I/O. Tasks scheduled with
+/// [`Context::task_on`] instead run on a `ThreadPool`, and only the completion callback
+/// runs on the JavaScript thread.
+///
+/// A `ThreadPool` is not tied to a JavaScript environment and may be shared by every
+/// instance of the module, e.g., in a `static`. Cloning a `ThreadPool` shares the same
+/// threads. The threads exit once all clones have been dropped and the queued tasks
+/// have completed.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::num::NonZeroUsize;
+///
+/// use neon::event::ThreadPool;
+/// use once_cell::sync::Lazy;
+///
+/// static POOL: Lazy<ThreadPool> =
+///     Lazy::new(|| ThreadPool::new(NonZeroUsize::new(4).unwrap()));
+///
+/// fn sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+///
+///     let promise = cx
+///         .task_on(&POOL, move || (0..n).sum::<u64>())
+///         .promise(|mut cx, sum| Ok(cx.number(sum as f64)));
+///
+///     Ok(promise)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ThreadPool {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    sender: Mutex<mpsc::Sender<Job>>,
+    threads: usize,
+}
+
+impl fmt::Debug for ThreadPool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ThreadPool")
+            .field("threads", &self.inner.threads)
+            .finish()
+    }
+}
+
+impl Finalize for ThreadPool {}
+
+impl Default for ThreadPool {
+    /// Creates a pool with one thread per available CPU.
+    fn default() -> Self {
+        let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+
+        Self::new(threads)
+    }
+}
+
+impl ThreadPool {
+    /// Creates a pool with the given number of threads.
+    ///
+    /// Panics if a thread cannot be spawned.
+    pub fn new(threads: NonZeroUsize) -> Self {
+        let threads = threads.get();
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for i in 0..threads {
+            let receiver = receiver.clone();
+
+            thread::Builder::new()
+                .name(format!("neon-pool-{i}"))
+                .spawn(move || loop {
+                    // The lock is released before running the job
+                    let job = receiver.lock().unwrap().recv();
+
+                    match job {
+                        Ok(job) => job(),
+                        // All senders have been dropped
+                        Err(_) => break,
+                    }
+                })
+                .expect("Failed to spawn ThreadPool thread");
+        }
+
+        Self {
+            inner: Arc::new(Inner {
+                sender: Mutex::new(sender),
+                threads,
+            }),
+        }
+    }
+
+    /// Returns the number of threads in the pool.
+    pub fn threads(&self) -> usize {
+        self.inner.threads
+    }
+
+    fn execute(&self, job: Job) {
+        // The receivers live as long as the threads, which only exit after the
+        // sender has been dropped
+        let _ = self.inner.sender.lock().unwrap().send(job);
+    }
+}
+
+// Holds the completion callback of a task on the JavaScript thread, since it is not
+// required to be `Send`
+struct Completion<D>(RefCell<Option<D>>);
+
+impl<D> Finalize for Completion<D> {}
+
+// Run `input` on the pool and call `complete` with the result on the JavaScript thread,
+// in an async resource named `name`
+pub(super) fn schedule<'a, C, I, O, D>(
+    cx: &mut C,
+    pool: &ThreadPool,
+    name: &str,
+    input: I,
+    complete: D,
+) where
+    C: Context<'a>,
+    I: FnOnce() -> O + Send + 'static,
+    O: Send + 'static,
+    D: FnOnce(Cx, thread::Result<O>) + 'static,
+{
+    let mut channel = InstanceData::channel(cx, Some(name));
+    let complete = cx.boxed(Completion(RefCell::new(Some(complete)))).root(cx);
+
+    // Keep the event loop alive until the task completes, the same as async work
+    channel.reference(cx);
+
+    pool.execute(Box::new(move || {
+        let output = catch_unwind(AssertUnwindSafe(input));
+
+        // If the environment has shut down, the completion is dropped with the root
+        let _ = channel.try_send(move |mut cx| {
+            let complete = complete.into_inner(&mut cx).0.borrow_mut().take();
+
+            if let Some(complete) = complete {
+                complete(cx, output);
+            }
+
+            Ok(())
+        });
+    }));
+}
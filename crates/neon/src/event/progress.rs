@@ -0,0 +1,95 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use crate::{
+    context::Context,
+    event::{Channel, SendError},
+    handle::{Handle, Root},
+    object::Object,
+    types::{extract::TryIntoJs, JsFunction},
+};
+
+/// Reports progress of background work, such as a [task](crate::event::TaskBuilder),
+/// to a JavaScript callback.
+///
+/// Each call to [`emit`](ProgressReporter::emit) sends a value through a [`Channel`]
+/// and calls the callback with the value converted to JavaScript on the JavaScript
+/// thread. Values are delivered in order, but asynchronously; the work is not paused
+/// while the callback runs.
+///
+/// Like a [`Channel`], a `ProgressReporter` keeps the event loop alive until it and all
+/// of its clones are dropped.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::event::ProgressReporter;
+///
+/// fn sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let on_progress = cx.argument::<JsFunction>(0)?;
+///     let progress = ProgressReporter::new(&mut cx, on_progress);
+///
+///     let promise = cx
+///         .task(move || {
+///             let mut sum = 0u64;
+///
+///             for i in 0..1_000_000 {
+///                 sum += i;
+///
+///                 if i % 100_000 == 0 {
+///                     // Report the fraction completed
+///                     let _ = progress.emit(i as f64 / 1_000_000.0);
+///                 }
+///             }
+///
+///             sum
+///         })
+///         .promise(|mut cx, sum| Ok(cx.number(sum as f64)));
+///
+///     Ok(promise)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ProgressReporter<T> {
+    channel: Channel,
+    callback: Arc<Root<JsFunction>>,
+    _value: PhantomData<fn(T)>,
+}
+
+impl<T> Clone for ProgressReporter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            channel: self.channel.clone(),
+            callback: self.callback.clone(),
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> ProgressReporter<T>
+where
+    T: for<'cx> TryIntoJs<'cx> + Send + 'static,
+{
+    /// Creates a reporter that calls `callback` with each emitted value.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, callback: Handle<JsFunction>) -> Self {
+        Self {
+            channel: Channel::new(cx),
+            callback: Arc::new(callback.root(cx)),
+            _value: PhantomData,
+        }
+    }
+
+    /// Sends a value to the callback.
+    ///
+    /// Returns an error if the JavaScript environment has shut down. An exception
+    /// thrown by the callback is treated the same as one thrown by a closure sent
+    /// with [`Channel::send`].
+    pub fn emit(&self, value: T) -> Result<(), SendError> {
+        let callback = self.callback.clone();
+
+        self.channel
+            .try_send(move |mut cx| callback.to_inner(&mut cx).bind(&mut cx).arg(value)?.exec())?;
+
+        Ok(())
+    }
+}
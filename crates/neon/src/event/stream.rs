@@ -0,0 +1,299 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    context::{Context, Cx},
+    event::{Channel, SendError},
+    handle::{Handle, Root},
+    object::Object,
+    result::{JsResult, NeonResult},
+    types::{Deferred, Finalize, JsBox, JsFunction, JsObject, JsPromise, JsValue, Value},
+};
+
+type Flush = dyn Fn(&mut Cx) -> NeonResult<()> + Send + Sync;
+
+/// Creates a typed channel for sending values from Rust to JavaScript.
+///
+/// Returns a [`Sender`] and a JavaScript
+/// [async iterator](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols#the_async_iterator_and_async_iterable_protocols)
+/// that yields each sent value, converted with `convert` on the JavaScript thread. The
+/// iterator completes after every clone of the `Sender` has been dropped and the
+/// remaining values have been consumed. If `convert` throws, the promise returned by
+/// `next()` is rejected and iteration may continue.
+///
+/// Like a [`Channel`], a `Sender` keeps the event loop alive until it and all of its
+/// clones are dropped.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::thread;
+///
+/// // for await (const n of countdown(3)) { ... }
+/// fn countdown(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+///     let (tx, rx) = neon::event::channel(&mut cx, |cx, n: u32| Ok(cx.number(n)))?;
+///
+///     thread::spawn(move || {
+///         for i in (0..n).rev() {
+///             let _ = tx.send(i);
+///         }
+///     });
+///
+///     Ok(rx)
+/// }
+/// ```
+pub fn channel<'a, C, T, F, V>(
+    cx: &mut C,
+    convert: F,
+) -> NeonResult<(Sender<T>, Handle<'a, JsObject>)>
+where
+    C: Context<'a>,
+    T: Send + 'static,
+    F: for<'cx> Fn(&mut Cx<'cx>, T) -> JsResult<'cx, V> + 'static,
+    V: Value,
+{
+    let shared = Arc::new(Mutex::new(Shared {
+        values: VecDeque::new(),
+        senders_closed: false,
+        receiver_closed: false,
+        scheduled: false,
+    }));
+
+    let state = cx.boxed(State {
+        waiters: RefCell::new(VecDeque::new()),
+        shared: shared.clone(),
+        convert,
+    });
+
+    let state = Arc::new(state.root(cx));
+    let receiver = iterator(cx, state.clone())?;
+    let deliver: Arc<Flush> = Arc::new(move |cx: &mut Cx| {
+        let state = state.to_inner(cx);
+
+        flush(cx, &state)
+    });
+
+    let sender = Sender {
+        inner: Arc::new(SenderInner {
+            channel: Channel::new(cx),
+            shared,
+            flush: deliver,
+        }),
+    };
+
+    Ok((sender, receiver))
+}
+
+/// The sending half of a typed [`channel`].
+///
+/// Cloning a `Sender` creates another sender for the same channel. The JavaScript
+/// iterator completes after all of the senders have been dropped.
+pub struct Sender<T> {
+    inner: Arc<SenderInner<T>>,
+}
+
+struct SenderInner<T> {
+    channel: Channel,
+    shared: Arc<Mutex<Shared<T>>>,
+    flush: Arc<Flush>,
+}
+
+// Values sent from Rust that have not been delivered to JavaScript
+struct Shared<T> {
+    values: VecDeque<T>,
+    senders_closed: bool,
+    // Iteration was ended early by JavaScript with `return()`
+    receiver_closed: bool,
+    // A `Channel` callback has been sent and will deliver the values
+    scheduled: bool,
+}
+
+impl<T> Shared<T> {
+    fn is_closed(&self) -> bool {
+        self.senders_closed || self.receiver_closed
+    }
+}
+
+// State of the receiver, only accessed from the JavaScript thread
+struct State<T, F> {
+    // Promises returned by `next()` that are waiting for a value
+    waiters: RefCell<VecDeque<Deferred>>,
+    shared: Arc<Mutex<Shared<T>>>,
+    convert: F,
+}
+
+impl<T, F> Finalize for State<T, F> {}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Sender")
+    }
+}
+
+impl<T: Send + 'static> Sender<T> {
+    /// Sends a value to the JavaScript iterator.
+    ///
+    /// Returns an error if the JavaScript environment has shut down or if JavaScript
+    /// ended the iteration early, for example with `break` in a `for await` loop.
+    pub fn send(&self, value: T) -> Result<(), SendError> {
+        let mut shared = self.inner.shared.lock().unwrap();
+
+        if shared.receiver_closed {
+            return Err(SendError::closing());
+        }
+
+        shared.values.push_back(value);
+
+        if shared.scheduled {
+            return Ok(());
+        }
+
+        self.inner.schedule(&mut shared)
+    }
+}
+
+impl<T> SenderInner<T> {
+    fn schedule(&self, shared: &mut Shared<T>) -> Result<(), SendError> {
+        let flush = self.flush.clone();
+
+        self.channel.try_send(move |mut cx| flush(&mut cx))?;
+        shared.scheduled = true;
+
+        Ok(())
+    }
+}
+
+impl<T> Drop for SenderInner<T> {
+    fn drop(&mut self) {
+        let mut shared = self.shared.lock().unwrap();
+
+        shared.senders_closed = true;
+
+        // Wake up any pending `next()` calls to complete the iteration
+        if !shared.scheduled {
+            let _ = self.schedule(&mut shared);
+        }
+    }
+}
+
+// Creates the JavaScript async iterator object
+fn iterator<'a, C, T, F, V>(
+    cx: &mut C,
+    state: Arc<Root<JsBox<State<T, F>>>>,
+) -> JsResult<'a, JsObject>
+where
+    C: Context<'a>,
+    T: Send + 'static,
+    F: for<'cx> Fn(&mut Cx<'cx>, T) -> JsResult<'cx, V> + 'static,
+    V: Value,
+{
+    let next = JsFunction::new(cx, {
+        let state = state.clone();
+
+        move |mut cx| -> JsResult<JsPromise> {
+            let state = state.to_inner(&mut cx);
+            let (deferred, promise) = cx.promise();
+
+            state.waiters.borrow_mut().push_back(deferred);
+            flush(&mut cx, &state)?;
+
+            Ok(promise)
+        }
+    })?;
+
+    let ret = JsFunction::new(cx, move |mut cx| -> JsResult<JsPromise> {
+        let state = state.to_inner(&mut cx);
+
+        {
+            let mut shared = state.shared.lock().unwrap();
+
+            shared.receiver_closed = true;
+            shared.values.clear();
+        }
+
+        flush(&mut cx, &state)?;
+
+        let done = result(&mut cx, None)?;
+
+        Ok(JsPromise::resolve(&mut cx, done))
+    })?;
+
+    let this = JsFunction::new(cx, |mut cx| cx.this::<JsObject>())?;
+    let symbol = crate::intrinsics::symbol(cx)?;
+    let async_iterator: Handle<JsValue> = symbol.prop(cx.cx_mut(), "asyncIterator").get()?;
+    let iterator = cx.empty_object();
+
+    iterator.prop(cx.cx_mut(), "next").set(next)?;
+    iterator.prop(cx.cx_mut(), "return").set(ret)?;
+    iterator.prop(cx.cx_mut(), async_iterator).set(this)?;
+
+    Ok(iterator)
+}
+
+// Settles pending `next()` promises with the available values
+fn flush<T, F, V>(cx: &mut Cx, state: &State<T, F>) -> NeonResult<()>
+where
+    F: for<'cx> Fn(&mut Cx<'cx>, T) -> JsResult<'cx, V>,
+    V: Value,
+{
+    state.shared.lock().unwrap().scheduled = false;
+
+    loop {
+        if state.waiters.borrow().is_empty() {
+            return Ok(());
+        }
+
+        let value = {
+            let mut shared = state.shared.lock().unwrap();
+
+            match shared.values.pop_front() {
+                Some(value) => Some(value),
+                None if shared.is_closed() => None,
+                None => return Ok(()),
+            }
+        };
+
+        // The borrow is released before calling `convert`, which may call `next()`
+        let deferred = state.waiters.borrow_mut().pop_front().unwrap();
+
+        let settled = cx.try_catch(|cx| match value {
+            Some(value) => {
+                let value = (state.convert)(cx, value)?.upcast::<JsValue>();
+                result(cx, Some(value))
+            }
+            None => result(cx, None),
+        });
+
+        match settled {
+            Ok(result) => deferred.resolve(cx, result),
+            Err(err) => deferred.reject(cx, err),
+        }
+    }
+}
+
+// Creates an iterator result, `{ value, done }`
+fn result<'cx, C: Context<'cx>>(
+    cx: &mut C,
+    value: Option<Handle<'cx, JsValue>>,
+) -> JsResult<'cx, JsObject> {
+    let done = value.is_none();
+
+    cx.object_builder()
+        .prop("value", value)?
+        .prop("done", done)?
+        .build()
+}
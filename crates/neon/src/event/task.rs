@@ -8,8 +8,18 @@ use crate::{
     types::{Deferred, JsPromise, Value},
 };
 
+#[cfg(feature = "napi-6")]
+use super::{pool, ThreadPool};
+
+// Default name of the async resource for a task
+const NAME: &str = "neon_async_work";
+
 /// Node asynchronous task builder
 ///
+/// Use a [`ProgressReporter`](crate::event::ProgressReporter) to report progress of the
+/// task to JavaScript and a [`CancellationToken`](crate::event::CancellationToken) to
+/// allow JavaScript to cancel it.
+///
 /// ```
 /// # use neon::prelude::*;
 /// fn greet(mut cx: FunctionContext) -> JsResult<JsPromise> {
@@ -25,6 +35,9 @@ use crate::{
 pub struct TaskBuilder<'cx, C, E> {
     cx: &'cx mut C,
     execute: E,
+    name: Option<String>,
+    #[cfg(feature = "napi-6")]
+    pool: Option<ThreadPool>,
 }
 
 impl<'a: 'cx, 'cx, C, O, E> TaskBuilder<'cx, C, E>
@@ -36,7 +49,45 @@ where
     /// Construct a new task builder from an `execute` callback that can be
     /// scheduled to execute on the Node worker pool
     pub fn new(cx: &'cx mut C, execute: E) -> Self {
-        Self { cx, execute }
+        Self {
+            cx,
+            execute,
+            name: None,
+            #[cfg(feature = "napi-6")]
+            pool: None,
+        }
+    }
+
+    /// Sets the name of the async resource for the task. Defaults to `neon_async_work`.
+    ///
+    /// The name is the `type` reported to
+    /// [`async_hooks`](https://nodejs.org/api/async_hooks.html), making the task
+    /// identifiable in diagnostics and trace events. It is usually prefixed with the
+    /// name of the addon, e.g., `my-addon:hash`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn hash(_: &[u8]) -> f64 { todo!() }
+    /// fn hash_file(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     let promise = cx
+    ///         .task(move || hash(&std::fs::read(path).unwrap_or_default()))
+    ///         .name("my-addon:hash")
+    ///         .promise(move |mut cx, hash| Ok(cx.number(hash)));
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[cfg(feature = "napi-6")]
+    pub(crate) fn on(mut self, pool: &ThreadPool) -> Self {
+        self.pool = Some(pool.clone());
+        self
     }
 
     /// Schedules a task to execute on the Node worker pool, executing the
@@ -46,10 +97,33 @@ where
     where
         F: FnOnce(Cx, O) -> NeonResult<()> + 'static,
     {
-        let env = self.cx.env();
         let execute = self.execute;
+        let name = self.name.as_deref().unwrap_or(NAME);
+
+        #[cfg(feature = "tracing")]
+        let (execute, complete) = {
+            let span = crate::tracing::task();
+            let execute_span = span.clone();
+
+            (
+                move || execute_span.in_scope(execute),
+                move |cx: Cx, output| span.in_scope(|| complete(cx, output)),
+            )
+        };
+
+        #[cfg(feature = "napi-6")]
+        if let Some(pool) = self.pool {
+            pool::schedule(self.cx, &pool, name, execute, move |cx, output| {
+                let output = output.unwrap_or_else(|panic| resume_unwind(panic));
+                let _ = complete(cx, output);
+            });
 
-        schedule(env, execute, complete);
+            return;
+        }
+
+        let env = self.cx.env();
+
+        schedule(env, name, execute, complete);
     }
 
     /// Schedules a task to execute on the Node worker pool and returns a
@@ -66,22 +140,60 @@ where
         let env = self.cx.env();
         let (deferred, promise) = JsPromise::new(self.cx);
         let execute = self.execute;
+        let name = self.name.as_deref().unwrap_or(NAME);
+
+        #[cfg(feature = "tracing")]
+        let (execute, complete) = {
+            let span = crate::tracing::task();
+            let execute_span = span.clone();
 
-        schedule_promise(env, execute, complete, deferred);
+            (
+                move || execute_span.in_scope(execute),
+                in_span(span, complete),
+            )
+        };
+
+        #[cfg(feature = "napi-6")]
+        if let Some(pool) = self.pool {
+            pool::schedule(self.cx, &pool, name, execute, move |cx, output| {
+                settle(cx, output, complete, deferred);
+            });
+
+            return promise;
+        }
+
+        schedule_promise(env, name, execute, complete, deferred);
 
         promise
     }
 }
 
+#[cfg(feature = "tracing")]
+// Enters `span` while executing the `complete` callback of a task
+fn in_span<O, V, D>(span: ::tracing::Span, complete: D) -> impl FnOnce(Cx, O) -> JsResult<V>
+where
+    V: Value,
+    D: FnOnce(Cx, O) -> JsResult<V>,
+{
+    move |cx, output| span.in_scope(|| complete(cx, output))
+}
+
 // Schedule a task to execute on the Node worker pool
-fn schedule<I, O, D>(env: Env, input: I, data: D)
+fn schedule<I, O, D>(env: Env, name: &str, input: I, data: D)
 where
     I: FnOnce() -> O + Send + 'static,
     O: Send + 'static,
     D: FnOnce(Cx, O) -> NeonResult<()> + 'static,
 {
     unsafe {
-        async_work::schedule(env.to_raw(), input, execute::<I, O>, complete::<O, D>, data);
+        async_work::schedule(
+            env.to_raw(),
+            name,
+            input,
+            execute::<I, O>,
+            complete::<O, D>,
+            data,
+        );
     }
 }
 
@@ -110,7 +222,7 @@ where
 }
 
 // Schedule a task to execute on the Node worker pool and settle a `Promise` with the result
-fn schedule_promise<I, O, D, V>(env: Env, input: I, complete: D, deferred: Deferred)
+fn schedule_promise<I, O, D, V>(env: Env, name: &str, input: I, complete: D, deferred: Deferred)
 where
     I: FnOnce() -> O + Send + 'static,
     O: Send + 'static,
@@ -120,6 +232,7 @@ where
     unsafe {
         async_work::schedule(
             env.to_raw(),
+            name,
             input,
             execute::<I, O>,
             complete_promise::<O, D, V>,
@@ -139,11 +252,17 @@ fn complete_promise<O, D, V>(
 {
     let env = env.into();
 
-    Cx::with_context(env, move |cx| {
-        deferred.try_catch_settle(cx, move |cx| {
-            let output = output.unwrap_or_else(|panic| resume_unwind(panic));
+    Cx::with_context(env, move |cx| settle(cx, output, complete, deferred));
+}
 
-            complete(cx, output)
-        })
-    });
+fn settle<O, D, V>(cx: Cx, output: thread::Result<O>, complete: D, deferred: Deferred)
+where
+    D: FnOnce(Cx, O) -> JsResult<V>,
+    V: Value,
+{
+    deferred.try_catch_settle(cx, move |cx| {
+        let output = output.unwrap_or_else(|panic| resume_unwind(panic));
+
+        complete(cx, output)
+    })
 }
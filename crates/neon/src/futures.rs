@@ -0,0 +1,67 @@
+//! Running Rust [`Future`]s and settling JavaScript promises with their output.
+//!
+//! Futures are run on the runtime registered with
+//! [`set_global_executor`](crate::set_global_executor). With the `tokio` feature, a
+//! multithreaded tokio runtime is registered automatically unless the module provides
+//! a [`#[neon::main]`](crate::main) function.
+
+use std::future::Future;
+
+use crate::{
+    context::{Context, Cx},
+    executor::RUNTIME,
+    result::JsResult,
+    types::{JsPromise, Value},
+};
+
+/// Spawns a future on the global executor and returns a promise that settles when the
+/// future completes.
+///
+/// The `settle` callback is called on the JavaScript thread with the output of the
+/// future. The promise is resolved with the value it returns, or rejected with the
+/// exception it throws. A panic in the future or in `settle` rejects the promise.
+///
+/// Throws if a global executor has not been registered.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use std::time::Duration;
+/// # async fn sleep(_: Duration) {}
+/// fn delayed_greeting(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let name = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///     neon::futures::spawn(
+///         &mut cx,
+///         async move {
+///             sleep(Duration::from_millis(10)).await;
+///             format!("Hello, {name}!")
+///         },
+///         |mut cx, greeting| Ok(cx.string(greeting)),
+///     )
+/// }
+/// ```
+pub fn spawn<'cx, C, F, S, V>(cx: &mut C, fut: F, settle: S) -> JsResult<'cx, JsPromise>
+where
+    C: Context<'cx>,
+    F: Future + Send + 'static,
+    F::Output: Send,
+    S: FnOnce(Cx, F::Output) -> JsResult<V> + Send + 'static,
+    V: Value,
+{
+    let rt = match RUNTIME.get(cx) {
+        Some(rt) => rt,
+        None => return cx.throw_error("must initialize with neon::set_global_executor"),
+    };
+
+    let ch = cx.channel();
+    let (d, promise) = cx.promise();
+
+    rt.spawn(Box::pin(async move {
+        let res = fut.await;
+        let _ = d.try_settle_with(&ch, move |cx| settle(cx, res));
+    }));
+
+    Ok(promise)
+}
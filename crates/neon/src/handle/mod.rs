@@ -45,8 +45,11 @@
 pub(crate) mod internal;
 
 pub(crate) mod root;
+#[cfg(feature = "napi-6")]
+mod weak;
 
 use std::{
+    any::TypeId,
     error::Error,
     fmt::{self, Debug, Display},
     marker::PhantomData,
@@ -55,6 +58,9 @@ use std::{
 };
 
 pub use self::root::Root;
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::weak::WeakRoot;
 
 use crate::{
     context::Context,
@@ -64,6 +70,9 @@ use crate::{
     types::Value,
 };
 
+#[cfg(feature = "sys")]
+use crate::types::JsValue;
+
 /// A handle to a JavaScript value that is owned by the JavaScript engine.
 #[derive(Debug)]
 #[repr(transparent)]
@@ -156,7 +165,7 @@ impl<'a, T: Value> Handle<'a, T> {
     /// # }
     /// ```
     pub fn is_a<'b, U: Value, C: Context<'b>>(&self, cx: &mut C) -> bool {
-        U::is_typeof(cx.cx_mut(), self.deref())
+        self.as_same_type::<U>().is_some() || U::is_typeof(cx.cx_mut(), self.deref())
     }
 
     /// Attempts to downcast a handle to another type, which may fail. A failure
@@ -164,12 +173,32 @@ impl<'a, T: Value> Handle<'a, T> {
     /// continue interacting with the JS engine if this method produces an `Err`
     /// result.
     pub fn downcast<'b, U: Value, C: Context<'b>>(&self, cx: &mut C) -> DowncastResult<'a, T, U> {
+        if let Some(v) = self.as_same_type::<U>() {
+            return Ok(v);
+        }
+
         match U::downcast(cx.cx_mut(), self.deref()) {
             Some(v) => Ok(Handle::new_internal(v)),
             None => Err(DowncastError::new()),
         }
     }
 
+    // The type of a handle is checked when it is created, so casting it to its own type
+    // can skip asking the engine, e.g., for a `Handle<JsBox<T>>` passed to generic code
+    fn as_same_type<U: Value>(&self) -> Option<Handle<'a, U>> {
+        if TypeId::of::<T>() != TypeId::of::<U>() {
+            return None;
+        }
+
+        // Safety: `T` and `U` are the same type
+        let value = unsafe { mem::transmute_copy::<T::Inner, U::Inner>(&self.value) };
+
+        Some(Handle {
+            value,
+            phantom: PhantomData,
+        })
+    }
+
     /// Attempts to downcast a handle to another type, raising a JavaScript `TypeError`
     /// exception on failure. This method is a convenient shorthand, equivalent to
     /// `self.downcast::<U>().or_throw::<C>(cx)`.
@@ -177,6 +206,12 @@ impl<'a, T: Value> Handle<'a, T> {
         self.downcast(cx).or_throw(cx)
     }
 
+    /// Compares two values with JavaScript's
+    /// [strict equality](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Strict_equality)
+    /// (`===`) operator.
+    ///
+    /// Objects are compared by identity. `NaN` is not equal to itself and `+0` is equal
+    /// to `-0`.
     pub fn strict_equals<'b, U: Value, C: Context<'b>>(
         &self,
         cx: &mut C,
@@ -184,6 +219,72 @@ impl<'a, T: Value> Handle<'a, T> {
     ) -> bool {
         unsafe { sys::mem::strict_equals(cx.env().to_raw(), self.to_local(), other.to_local()) }
     }
+
+    /// Compares two values like
+    /// [`Object.is`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/is).
+    ///
+    /// This is the same as [`strict_equals`](Handle::strict_equals), except that `NaN` is
+    /// equal to itself and `+0` is not equal to `-0`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn f(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let nan = cx.number(f64::NAN);
+    /// let zero = cx.number(0.0);
+    /// let neg_zero = cx.number(-0.0);
+    ///
+    /// assert!(nan.same_value(&mut cx, nan));
+    /// assert!(!zero.same_value(&mut cx, neg_zero));
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    pub fn same_value<'b, U: Value, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        other: Handle<'b, U>,
+    ) -> bool {
+        unsafe { sys::mem::same_value(cx.env().to_raw(), self.to_local(), other.to_local()) }
+    }
+
+    /// Compares two values with the equality used by `Map`, `Set` and
+    /// `Array.prototype.includes`.
+    ///
+    /// This is the same as [`strict_equals`](Handle::strict_equals), except that `NaN` is
+    /// equal to itself.
+    pub fn same_value_zero<'b, U: Value, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        other: Handle<'b, U>,
+    ) -> bool {
+        unsafe { sys::mem::same_value_zero(cx.env().to_raw(), self.to_local(), other.to_local()) }
+    }
+
+    #[cfg(feature = "sys")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
+    /// Returns the raw Node-API value referenced by this handle.
+    ///
+    /// Equivalent to [`Value::to_raw`]. The raw value is only valid for as long as the
+    /// handle; native code outside of Neon must root it, for example with
+    /// `napi_create_reference`, to outlive the current scope.
+    pub fn to_napi_value(&self) -> sys::Value {
+        Value::to_raw(&**self)
+    }
+}
+
+#[cfg(feature = "sys")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
+impl<'cx> Handle<'cx, JsValue> {
+    /// Creates a handle from a raw Node-API value produced outside of Neon.
+    ///
+    /// Equivalent to [`Value::from_raw`] for [`JsValue`]; use
+    /// [`downcast`](Handle::downcast) to check the type of the value.
+    ///
+    /// # Safety
+    ///
+    /// See [`Value::from_raw`].
+    pub unsafe fn from_napi_value<C: Context<'cx>>(cx: &C, value: sys::Value) -> Self {
+        JsValue::from_raw(cx, value)
+    }
 }
 
 impl<'a, V: Value> Deref for Handle<'a, V> {
@@ -11,21 +11,28 @@ use crate::{
 #[cfg(feature = "napi-6")]
 use {
     crate::{
-        lifecycle::{DropData, InstanceData, InstanceId},
+        lifecycle::{DropData, InstanceData},
         sys::tsfn::ThreadsafeFunction,
     },
     std::sync::Arc,
 };
 
+#[cfg(feature = "napi-6")]
+pub(crate) use crate::lifecycle::InstanceId;
+
 #[cfg(not(feature = "napi-6"))]
 use std::thread::{self, ThreadId};
 
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::{Kind, Tracker};
+
+// Without instance data, module instances are distinguished by their thread
 #[cfg(not(feature = "napi-6"))]
-type InstanceId = ThreadId;
+pub(crate) type InstanceId = ThreadId;
 
 #[repr(transparent)]
 #[derive(Clone)]
-pub(crate) struct NapiRef(*mut c_void);
+pub(crate) struct NapiRef(pub(super) *mut c_void);
 
 impl NapiRef {
     /// # Safety
@@ -33,6 +40,15 @@ impl NapiRef {
     pub(crate) unsafe fn unref(self, env: raw::Env) {
         reference::unreference(env, self.0.cast());
     }
+
+    /// Deletes a weak reference
+    ///
+    /// # Safety
+    /// Must only be used from the same module context that created the reference
+    #[cfg(feature = "napi-6")]
+    pub(crate) unsafe fn delete(self, env: raw::Env) {
+        reference::delete(env, self.0.cast());
+    }
 }
 
 // # Safety
@@ -56,6 +72,8 @@ pub struct Root<T> {
     instance_id: InstanceId,
     #[cfg(feature = "napi-6")]
     drop_queue: Arc<ThreadsafeFunction<DropData>>,
+    #[cfg(feature = "diagnostics")]
+    _tracker: Tracker,
     _phantom: PhantomData<T>,
 }
 
@@ -73,12 +91,12 @@ unsafe impl<T> Send for Root<T> {}
 unsafe impl<T> Sync for Root<T> {}
 
 #[cfg(feature = "napi-6")]
-fn instance_id<'a, C: Context<'a>>(cx: &mut C) -> InstanceId {
+pub(crate) fn instance_id<'a, C: Context<'a>>(cx: &mut C) -> InstanceId {
     InstanceData::id(cx)
 }
 
 #[cfg(not(feature = "napi-6"))]
-fn instance_id<'a, C: Context<'a>>(_: &mut C) -> InstanceId {
+pub(crate) fn instance_id<'a, C: Context<'a>>(_: &mut C) -> InstanceId {
     thread::current().id()
 }
 
@@ -101,6 +119,8 @@ impl<T: Object> Root<T> {
             instance_id: instance_id(cx),
             #[cfg(feature = "napi-6")]
             drop_queue: InstanceData::drop_queue(cx),
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Root),
             _phantom: PhantomData,
         }
     }
@@ -130,6 +150,8 @@ impl<T: Object> Root<T> {
             instance_id: instance_id(cx),
             #[cfg(feature = "napi-6")]
             drop_queue: Arc::clone(&self.drop_queue),
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Root),
             _phantom: PhantomData,
         }
     }
@@ -0,0 +1,148 @@
+use std::{fmt, marker::PhantomData, sync::Arc};
+
+use crate::{
+    context::Context,
+    handle::{
+        root::{instance_id, InstanceId, NapiRef},
+        Handle,
+    },
+    lifecycle::{DropData, InstanceData},
+    object::Object,
+    sys::{reference, tsfn::ThreadsafeFunction},
+    types::boxed::Finalize,
+};
+
+/// A thread-safe reference to a JavaScript object that does not prevent it from
+/// being garbage collected.
+///
+/// A [`Root`](crate::handle::Root) keeps its object alive until the `Root` is dropped.
+/// A `WeakRoot` instead allows the object to be collected once it is no longer
+/// reachable from JavaScript, which makes it suitable for caches and other
+/// associations that should not extend the lifetime of an object. Use
+/// [`WeakRoot::upgrade`] to access the object if it is still alive.
+///
+/// Like a `Root`, a `WeakRoot` may be sent across threads and dropped on any thread,
+/// but the object may only be accessed on the JavaScript thread that created it.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::handle::WeakRoot;
+///
+/// struct Listener(WeakRoot<JsObject>);
+///
+/// impl Finalize for Listener {
+///     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+///         self.0.drop(cx);
+///     }
+/// }
+///
+/// fn listen(mut cx: FunctionContext) -> JsResult<JsBox<Listener>> {
+///     let target = cx.argument::<JsObject>(0)?;
+///     let listener = Listener(target.weak_root(&mut cx));
+///
+///     Ok(cx.boxed(listener))
+/// }
+///
+/// fn notify(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+///     let listener = cx.argument::<JsBox<Listener>>(0)?;
+///
+///     // The target may have been garbage collected
+///     let Some(target) = listener.0.upgrade(&mut cx) else {
+///         return Ok(cx.boolean(false));
+///     };
+///
+///     target.method(&mut cx, "notify")?.exec()?;
+///
+///     Ok(cx.boolean(true))
+/// }
+/// ```
+pub struct WeakRoot<T> {
+    // `None` after `WeakRoot::drop` to skip `Drop`
+    internal: Option<NapiRef>,
+    instance_id: InstanceId,
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> fmt::Debug for WeakRoot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WeakRoot<{}>", std::any::type_name::<T>())
+    }
+}
+
+// Safety: A `WeakRoot` only contains a `NapiRef`, which is `Send` and `Sync`, the same
+// as `Root`
+unsafe impl<T> Send for WeakRoot<T> {}
+
+unsafe impl<T> Sync for WeakRoot<T> {}
+
+impl<T: Object> WeakRoot<T> {
+    /// Creates a weak reference to a JavaScript object.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, value: &T) -> Self {
+        let env = cx.env().to_raw();
+        let internal = unsafe { reference::new_weak(env, value.to_local()) };
+
+        Self {
+            internal: Some(NapiRef(internal as *mut _)),
+            instance_id: instance_id(cx),
+            drop_queue: InstanceData::drop_queue(cx),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the referenced object, or `None` if it has been garbage collected.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the `WeakRoot` was created.
+    pub fn upgrade<'a, C: Context<'a>>(&self, cx: &mut C) -> Option<Handle<'a, T>> {
+        if self.instance_id != instance_id(cx) {
+            panic!("Attempted to dereference a `neon::handle::WeakRoot` from the wrong module");
+        }
+
+        let env = cx.env();
+        // `internal` is always `Some` until the `WeakRoot` is consumed
+        let internal = self.internal.as_ref().unwrap();
+        let local = unsafe { reference::get(env.to_raw(), internal.0.cast()) };
+
+        if local.is_null() {
+            return None;
+        }
+
+        Some(Handle::new_internal(unsafe { T::from_local(env, local) }))
+    }
+
+    /// Drops the `WeakRoot` without using the drop queue.
+    ///
+    /// A `WeakRoot` may also be dropped normally, on any thread, at a small runtime
+    /// cost.
+    pub fn drop<'a, C: Context<'a>>(mut self, cx: &mut C) {
+        if self.instance_id != instance_id(cx) {
+            panic!("Attempted to drop a `neon::handle::WeakRoot` from the wrong module");
+        }
+
+        if let Some(internal) = self.internal.take() {
+            unsafe {
+                internal.delete(cx.env().to_raw());
+            }
+        }
+    }
+}
+
+impl<T: Object> Finalize for WeakRoot<T> {
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+        self.drop(cx);
+    }
+}
+
+impl<T> Drop for WeakRoot<T> {
+    fn drop(&mut self) {
+        // If `None`, the `NapiRef` has already been manually dropped
+        if let Some(internal) = self.internal.take() {
+            let _ = self.drop_queue.call(DropData::WeakRef(internal), None);
+        }
+    }
+}
@@ -0,0 +1,361 @@
+//! Cached access to frequently used JavaScript globals.
+//!
+//! Looking up a global with [`Context::global`] reads a property of the global object
+//! on every call. The functions in this module look up each value once per module
+//! instance and return the cached value afterwards.
+//!
+//! Each value is cached the first time it is used in a module instance, so later
+//! calls return it even if JavaScript code has since replaced the global, e.g., by
+//! assigning to `globalThis.Array`. Values that Neon relies on internally are cached
+//! when the module is loaded, before other code has a chance to replace them.
+//!
+//! ```
+//! # use neon::prelude::*;
+//! // Equivalent to `JSON.stringify(value)`
+//! fn stringify(mut cx: FunctionContext) -> JsResult<JsString> {
+//!     let value = cx.argument::<JsValue>(0)?;
+//!
+//!     neon::intrinsics::json(&mut cx)?
+//!         .method(&mut cx, "stringify")?
+//!         .arg(value)?
+//!         .call()
+//! }
+//! ```
+
+use crate::{
+    context::{Context, Cx, ModuleContext},
+    handle::{Handle, Root},
+    object::Object,
+    result::JsResult,
+    thread::LocalKey,
+    types::{JsFunction, JsObject, Value},
+};
+
+macro_rules! intrinsic {
+    ($(#[$attr:meta])* $vis:vis $name:ident: $ty:ty = |$cx:ident| $init:expr) => {
+        $(#[$attr])*
+        $vis fn $name<'cx, C: Context<'cx>>(cx: &mut C) -> JsResult<'cx, $ty> {
+            static VALUE: LocalKey<Root<$ty>> = LocalKey::new();
+
+            VALUE
+                .get_or_try_init(cx, |cx| {
+                    let $cx = cx.cx_mut();
+                    let value: Handle<$ty> = $init;
+
+                    Ok(value.root($cx))
+                })
+                .map(|v| v.to_inner(cx))
+        }
+    };
+
+    ($(#[$attr:meta])* $name:ident: $ty:ty = $global:literal) => {
+        intrinsic!($(#[$attr])* pub $name: $ty = |cx| cx.global::<$ty>($global)?);
+    };
+}
+
+intrinsic!(
+    /// The `Array` constructor.
+    array: JsFunction = "Array"
+);
+
+intrinsic!(
+    /// The `Object` constructor.
+    object: JsFunction = "Object"
+);
+
+intrinsic!(
+    /// The `Promise` constructor.
+    promise: JsFunction = "Promise"
+);
+
+intrinsic!(
+    /// The `Error` constructor.
+    error: JsFunction = "Error"
+);
+
+intrinsic!(
+    /// The `SyntaxError` constructor.
+    syntax_error: JsFunction = "SyntaxError"
+);
+
+intrinsic!(
+    /// The `EvalError` constructor.
+    eval_error: JsFunction = "EvalError"
+);
+
+intrinsic!(
+    /// The `URIError` constructor.
+    uri_error: JsFunction = "URIError"
+);
+
+intrinsic!(
+    /// The `JSON` namespace object.
+    json: JsObject = "JSON"
+);
+
+intrinsic!(
+    /// The `Symbol` function.
+    symbol: JsFunction = "Symbol"
+);
+
+intrinsic!(
+    /// The `process` object.
+    process: JsObject = "process"
+);
+
+intrinsic!(
+    /// The `queueMicrotask` function.
+    queue_microtask: JsFunction = "queueMicrotask"
+);
+
+intrinsic!(
+    /// The `process.nextTick` function.
+    pub(crate) next_tick: JsFunction = |cx| process(cx)?.prop(cx, "nextTick").get()?
+);
+
+intrinsic!(
+    /// The `SharedArrayBuffer` constructor.
+    shared_array_buffer: JsFunction = "SharedArrayBuffer"
+);
+
+intrinsic!(
+    /// The `Uint8Array` constructor.
+    uint8_array: JsFunction = "Uint8Array"
+);
+
+intrinsic!(
+    /// The Node.js `Buffer` class.
+    buffer: JsFunction = "Buffer"
+);
+
+intrinsic!(
+    /// The `Buffer.prototype.subarray` method.
+    pub(crate) buffer_subarray: JsFunction =
+        |cx| prototype(cx, buffer)?.prop(cx, "subarray").get()?
+);
+
+intrinsic!(
+    /// The `Int32Array` constructor.
+    int32_array: JsFunction = "Int32Array"
+);
+
+intrinsic!(
+    /// The `Float64Array` constructor.
+    float64_array: JsFunction = "Float64Array"
+);
+
+intrinsic!(
+    /// The `SharedArrayBuffer.prototype.byteLength` getter, used as a brand check.
+    pub(crate) shared_array_buffer_byte_length: JsFunction = |cx| {
+        let prototype = prototype(cx, shared_array_buffer)?;
+        let key = cx.string("byteLength");
+        let descriptor: Handle<JsObject> = object(cx)?
+            .method(cx, "getOwnPropertyDescriptor")?
+            .args((prototype, key))?
+            .call()?;
+
+        descriptor.prop(cx, "get").get()?
+    }
+);
+
+intrinsic!(
+    /// The `Proxy` constructor.
+    proxy: JsFunction = "Proxy"
+);
+
+intrinsic!(
+    /// The `WeakSet` constructor.
+    weak_set: JsFunction = "WeakSet"
+);
+
+intrinsic!(
+    /// The `WeakSet.prototype.add` method.
+    pub(crate) weak_set_add: JsFunction = |cx| prototype(cx, weak_set)?.prop(cx, "add").get()?
+);
+
+intrinsic!(
+    /// The `WeakSet.prototype.has` method.
+    pub(crate) weak_set_has: JsFunction = |cx| prototype(cx, weak_set)?.prop(cx, "has").get()?
+);
+
+intrinsic!(
+    /// The `Reflect` namespace object.
+    reflect: JsObject = "Reflect"
+);
+
+intrinsic!(
+    /// The `Reflect.apply` function.
+    pub(crate) reflect_apply: JsFunction = |cx| reflect(cx)?.prop(cx, "apply").get()?
+);
+
+intrinsic!(
+    /// The `Reflect.construct` function.
+    pub(crate) reflect_construct: JsFunction = |cx| reflect(cx)?.prop(cx, "construct").get()?
+);
+
+intrinsic!(
+    /// The `Array.from` function.
+    pub(crate) array_from: JsFunction = |cx| array(cx)?.prop(cx, "from").get()?
+);
+
+intrinsic!(
+    /// The `Array.prototype.push` method.
+    pub(crate) array_push: JsFunction = |cx| prototype(cx, array)?.prop(cx, "push").get()?
+);
+
+intrinsic!(
+    /// The `Map` constructor.
+    map: JsFunction = "Map"
+);
+
+intrinsic!(
+    /// The `Map.prototype.get` method.
+    pub(crate) map_get: JsFunction = |cx| prototype(cx, map)?.prop(cx, "get").get()?
+);
+
+intrinsic!(
+    /// The `Map.prototype.set` method.
+    pub(crate) map_set: JsFunction = |cx| prototype(cx, map)?.prop(cx, "set").get()?
+);
+
+intrinsic!(
+    /// The `Map.prototype.has` method.
+    pub(crate) map_has: JsFunction = |cx| prototype(cx, map)?.prop(cx, "has").get()?
+);
+
+intrinsic!(
+    /// The `Map.prototype.delete` method.
+    pub(crate) map_delete: JsFunction = |cx| prototype(cx, map)?.prop(cx, "delete").get()?
+);
+
+intrinsic!(
+    /// The `WeakMap` constructor.
+    weak_map: JsFunction = "WeakMap"
+);
+
+intrinsic!(
+    /// The `WeakMap.prototype.get` method.
+    pub(crate) weak_map_get: JsFunction = |cx| prototype(cx, weak_map)?.prop(cx, "get").get()?
+);
+
+intrinsic!(
+    /// The `WeakMap.prototype.set` method.
+    pub(crate) weak_map_set: JsFunction = |cx| prototype(cx, weak_map)?.prop(cx, "set").get()?
+);
+
+intrinsic!(
+    /// The `WeakMap.prototype.has` method.
+    pub(crate) weak_map_has: JsFunction = |cx| prototype(cx, weak_map)?.prop(cx, "has").get()?
+);
+
+intrinsic!(
+    /// The `MessageChannel` constructor.
+    message_channel: JsFunction = "MessageChannel"
+);
+
+intrinsic!(
+    /// The `URL` constructor.
+    url: JsFunction = "URL"
+);
+
+intrinsic!(
+    /// The `MessagePort` constructor.
+    message_port: JsFunction = "MessagePort"
+);
+
+intrinsic!(
+    /// The `process.getBuiltinModule` function, available since Node 20.16 and 22.3.
+    pub(crate) get_builtin_module: JsFunction = |cx| {
+        match process(cx)?.get_opt(cx, "getBuiltinModule")? {
+            Some(f) => f,
+            None => {
+                return cx.throw_error(
+                    "process.getBuiltinModule is not supported by this version of Node",
+                )
+            }
+        }
+    }
+);
+
+intrinsic!(
+    /// The `createRequire` function of the `node:module` built-in module. It is only
+    /// loaded when first used, since most modules never call `require_from`.
+    pub(crate) create_require: JsFunction = |cx| {
+        let module: Handle<JsFunction> = get_builtin_module(cx)?
+            .bind(cx)
+            .arg("node:module")?
+            .call()?;
+
+        module.prop(cx, "createRequire").get()?
+    }
+);
+
+intrinsic!(
+    /// The `String` constructor.
+    pub(crate) string: JsFunction = |cx| cx.global("String")?
+);
+
+intrinsic!(
+    /// The `String.prototype.concat` method.
+    pub(crate) string_concat: JsFunction = |cx| prototype(cx, string)?.prop(cx, "concat").get()?
+);
+
+intrinsic!(
+    /// The `String.prototype.substring` method.
+    pub(crate) string_substring: JsFunction =
+        |cx| prototype(cx, string)?.prop(cx, "substring").get()?
+);
+
+// Reads the `prototype` of an intrinsic constructor
+fn prototype<'cx>(
+    cx: &mut Cx<'cx>,
+    constructor: fn(&mut Cx<'cx>) -> JsResult<'cx, JsFunction>,
+) -> JsResult<'cx, JsObject> {
+    constructor(cx)?.prop(cx, "prototype").get()
+}
+
+/// Caches the intrinsics that Neon uses internally before other JavaScript code has a
+/// chance to replace them. Other intrinsics are cached on first use, so that loading a
+/// module does not pay for values it never uses.
+///
+/// Intrinsics that are missing from the environment, e.g., `SharedArrayBuffer` without
+/// cross-origin isolation, are skipped and looked up again on first use.
+pub(crate) fn init(cx: &mut ModuleContext) {
+    fn cache<'cx, T: Value>(
+        cx: &mut ModuleContext<'cx>,
+        f: fn(&mut ModuleContext<'cx>) -> JsResult<'cx, T>,
+    ) {
+        let _ = cx.try_catch(|cx| f(cx).map(drop));
+    }
+
+    cache(cx, symbol);
+    cache(cx, process);
+    cache(cx, queue_microtask);
+    cache(cx, next_tick);
+    cache(cx, shared_array_buffer);
+    cache(cx, uint8_array);
+    cache(cx, buffer_subarray);
+    cache(cx, int32_array);
+    cache(cx, float64_array);
+    cache(cx, shared_array_buffer_byte_length);
+    cache(cx, proxy);
+    cache(cx, weak_set);
+    cache(cx, weak_set_add);
+    cache(cx, weak_set_has);
+    cache(cx, reflect_apply);
+    cache(cx, reflect_construct);
+    cache(cx, array_from);
+    cache(cx, array_push);
+    cache(cx, string_concat);
+    cache(cx, string_substring);
+    cache(cx, map);
+    cache(cx, map_get);
+    cache(cx, map_set);
+    cache(cx, map_has);
+    cache(cx, map_delete);
+    cache(cx, weak_map);
+    cache(cx, weak_map_get);
+    cache(cx, weak_map_set);
+    cache(cx, weak_map_has);
+    cache(cx, message_port);
+}
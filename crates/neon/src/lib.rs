@@ -79,8 +79,20 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod context;
+#[cfg(feature = "diagnostics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+pub mod diagnostics;
 pub mod event;
+#[cfg(all(feature = "napi-6", feature = "futures"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "napi-6", feature = "futures"))))]
+pub mod futures;
 pub mod handle;
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub mod intrinsics;
+#[cfg(all(feature = "napi-6", feature = "log"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "napi-6", feature = "log"))))]
+pub mod log;
 mod macros;
 pub mod meta;
 pub mod object;
@@ -97,11 +109,23 @@ pub mod thread;
 // See: https://github.com/mersinvald/aquamarine/issues/5#issuecomment-1168816499
 mod types_docs;
 mod types_impl;
+pub mod version;
 
 #[cfg(feature = "sys")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
 pub mod sys;
 
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub mod util;
+
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub mod tracing;
+
 #[cfg(all(feature = "napi-6", feature = "futures"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-6", feature = "futures"))))]
 pub use executor::set_global_executor;
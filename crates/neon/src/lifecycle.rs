@@ -18,11 +18,12 @@ use std::{
 };
 
 use crate::{
-    context::Context,
+    context::{interceptor::Interceptor, Context, Cx},
     event::Channel,
-    handle::root::NapiRef,
+    handle::{root::NapiRef, Handle},
+    result::NeonResult,
     sys::{lifecycle, raw::Env, tsfn::ThreadsafeFunction},
-    types::promise::NodeApiDeferred,
+    types::{promise::NodeApiDeferred, JsError, JsValue},
 };
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -65,8 +66,23 @@ pub(crate) struct InstanceData {
 
     /// Table of user-defined instance-local cells.
     locals: LocalTable,
+
+    /// Hook called with each error thrown by `Context::throw`
+    error_decorator: Option<ErrorDecorator>,
+
+    /// Set while `error_decorator` is running to prevent re-entrant decoration
+    decorating: bool,
+
+    /// Interceptors wrapping each call to a Neon function, outermost first
+    ///
+    /// _Design Note_: The list is replaced instead of mutated when an interceptor is
+    /// added, so that calls in progress keep using the chain they started with.
+    interceptors: Arc<[Interceptor]>,
 }
 
+pub(crate) type ErrorDecorator =
+    Arc<dyn for<'cx> Fn(&mut Cx<'cx>, Handle<'cx, JsError>) -> NeonResult<()> + Send + Sync>;
+
 #[derive(Default)]
 pub(crate) struct LocalTable {
     cells: Vec<LocalCell>,
@@ -217,6 +233,7 @@ impl<'cx, 'a, C: Context<'cx>> Drop for TryInitTransaction<'cx, 'a, C> {
 pub(crate) enum DropData {
     Deferred(NodeApiDeferred),
     Ref(NapiRef),
+    WeakRef(NapiRef),
 }
 
 impl DropData {
@@ -227,6 +244,7 @@ impl DropData {
                 match data {
                     DropData::Deferred(data) => data.leaked(env),
                     DropData::Ref(data) => data.unref(env),
+                    DropData::WeakRef(data) => data.delete(env),
                 }
             }
         }
@@ -249,27 +267,33 @@ impl InstanceData {
         }
 
         let drop_queue = unsafe {
-            let queue = ThreadsafeFunction::new(env, DropData::drop);
+            let queue = ThreadsafeFunction::new(env, "neon threadsafe function", DropData::drop);
             queue.unref(env);
             queue
         };
 
-        let shared_channel = {
-            let mut channel = Channel::new(cx);
-            channel.unref(cx);
-            channel
-        };
+        let shared_channel = Channel::shared(cx);
 
         let data = InstanceData {
             id: InstanceId::next(),
             drop_queue: Arc::new(drop_queue),
             shared_channel,
             locals: LocalTable::default(),
+            error_decorator: None,
+            decorating: false,
+            interceptors: Arc::new([]),
         };
 
         unsafe { &mut *lifecycle::set_instance_data(env, data) }
     }
 
+    /// Return the data associated with this module instance without initializing it
+    fn try_get<'cx, C: Context<'cx>>(cx: &mut C) -> Option<&mut InstanceData> {
+        let env = cx.env().to_raw();
+
+        unsafe { lifecycle::get_instance_data::<InstanceData>(env).as_mut() }
+    }
+
     /// Helper to return a reference to the `drop_queue` field of `InstanceData`
     pub(crate) fn drop_queue<'cx, C: Context<'cx>>(
         cx: &mut C,
@@ -278,11 +302,13 @@ impl InstanceData {
     }
 
     /// Clones the shared channel and references it since new channels should start
-    /// referenced, but the shared channel is unreferenced.
-    pub(crate) fn channel<'cx, C: Context<'cx>>(cx: &mut C) -> Channel {
-        let mut channel = InstanceData::get(cx).shared_channel.clone();
-        channel.reference(cx);
-        channel
+    /// referenced, but the shared channel is unreferenced. The clone captures the
+    /// current async context, for a resource named `name` if provided.
+    pub(crate) fn channel<'cx, C: Context<'cx>>(cx: &mut C, name: Option<&str>) -> Channel {
+        InstanceData::get(cx)
+            .shared_channel
+            .clone()
+            .into_referenced(cx, name)
     }
 
     /// Unique identifier for this instance of the module
@@ -294,4 +320,74 @@ impl InstanceData {
     pub(crate) fn locals<'cx, C: Context<'cx>>(cx: &mut C) -> &mut LocalTable {
         &mut InstanceData::get(cx).locals
     }
+
+    pub(crate) fn add_interceptor<'cx, C: Context<'cx>>(cx: &mut C, f: Interceptor) {
+        let data = InstanceData::get(cx);
+        let interceptors = data.interceptors.iter().cloned().chain([f]).collect();
+
+        data.interceptors = interceptors;
+    }
+
+    /// Returns the registered interceptors, or `None` if there are none.
+    pub(crate) fn interceptors<'cx, C: Context<'cx>>(cx: &mut C) -> Option<Arc<[Interceptor]>> {
+        match InstanceData::try_get(cx) {
+            Some(data) if !data.interceptors.is_empty() => Some(Arc::clone(&data.interceptors)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_error_decorator<'cx, C: Context<'cx>>(cx: &mut C, f: ErrorDecorator) {
+        InstanceData::get(cx).error_decorator = Some(f);
+    }
+
+    /// Pass `err` to the error decorator, if one is registered and `err` is an `Error`.
+    /// Returns `Err` if the decorator throws.
+    pub(crate) fn decorate_error<'cx, C: Context<'cx>>(
+        cx: &mut C,
+        err: Handle<'cx, JsValue>,
+    ) -> NeonResult<()> {
+        let decorator = match InstanceData::try_get(cx) {
+            Some(InstanceData {
+                error_decorator: Some(decorator),
+                decorating: false,
+                ..
+            }) => Arc::clone(decorator),
+            _ => return Ok(()),
+        };
+
+        // Leave throwing while an exception is pending to fail as it would without a decorator
+        if unsafe { crate::sys::error::is_throwing(cx.env().to_raw()) } {
+            return Ok(());
+        }
+
+        let err = match err.downcast::<JsError, _>(cx) {
+            Ok(err) => err,
+            Err(_) => return Ok(()),
+        };
+
+        let _guard = DecoratingGuard::new(cx);
+
+        decorator(cx.cx_mut(), err)
+    }
+}
+
+/// Marks the error decorator as running for the lifetime of the guard, including
+/// when unwinding from a panic in the decorator
+struct DecoratingGuard(Env);
+
+impl DecoratingGuard {
+    fn new<'cx, C: Context<'cx>>(cx: &mut C) -> Self {
+        InstanceData::get(cx).decorating = true;
+
+        Self(cx.env().to_raw())
+    }
+}
+
+impl Drop for DecoratingGuard {
+    fn drop(&mut self) {
+        if let Some(data) = unsafe { lifecycle::get_instance_data::<InstanceData>(self.0).as_mut() }
+        {
+            data.decorating = false;
+        }
+    }
 }
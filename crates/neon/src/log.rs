@@ -0,0 +1,198 @@
+//! Forwarding [`log`](https://docs.rs/log) records to JavaScript.
+//!
+//! A [`Logger`] delivers records to either the JavaScript
+//! [`console`](https://nodejs.org/api/console.html) or a
+//! [diagnostics channel](https://nodejs.org/api/diagnostics_channel.html), so that
+//! messages logged by a native module, including from threads other than the
+//! JavaScript thread, show up alongside the host application's own logging.
+//!
+//! Records are sent to the JavaScript thread through a [`Channel`] and are delivered
+//! asynchronously, in the order they were logged. Records logged after the module's
+//! environment has shut down are discarded.
+//!
+//! # Example
+//!
+//! ```
+//! # fn main() {
+//! # use neon::prelude::*;
+//! use neon::log::Logger;
+//!
+//! #[neon::main]
+//! fn main(mut cx: ModuleContext) -> NeonResult<()> {
+//!     Logger::console(&mut cx)
+//!         .level(log::LevelFilter::Debug)
+//!         .init()
+//!         .or_else(|err| cx.throw_error(err.to_string()))?;
+//!
+//!     std::thread::spawn(|| log::info!("Hello from a Rust thread!"));
+//!
+//!     Ok(())
+//! }
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use ::log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::{
+    context::{Context, Cx},
+    event::Channel,
+    handle::{Handle, Root},
+    object::Object,
+    result::NeonResult,
+    types::JsObject,
+};
+
+/// A [`log::Log`] implementation that forwards records to JavaScript.
+///
+/// Install a `Logger` as the global logger with [`Logger::init`], or call it from
+/// another `log::Log` implementation to forward only some of the records.
+#[derive(Clone, Debug)]
+pub struct Logger {
+    channel: Channel,
+    level: LevelFilter,
+    sink: Arc<Sink>,
+}
+
+#[derive(Debug)]
+enum Sink {
+    Console,
+    DiagnosticsChannel(Root<JsObject>),
+}
+
+impl Logger {
+    /// Creates a logger that writes records to the `console` method matching their
+    /// level (`console.error`, `console.warn`, `console.info`, or `console.debug`).
+    ///
+    /// Messages are prefixed with the target of the record, e.g.,
+    /// `my_module::db: connected`.
+    pub fn console<'cx, C: Context<'cx>>(cx: &mut C) -> Self {
+        Self::new(cx, Sink::Console)
+    }
+
+    /// Creates a logger that publishes records to a
+    /// [`diagnostics_channel`](https://nodejs.org/api/diagnostics_channel.html#class-channel)
+    /// `Channel` object.
+    ///
+    /// Each record is published as an object with `level` (e.g., `"warn"`), `target`,
+    /// `message`, `file`, and `line` properties. The file and line are `undefined`
+    /// when unknown.
+    pub fn diagnostics_channel<'cx, C: Context<'cx>>(
+        cx: &mut C,
+        channel: Handle<JsObject>,
+    ) -> Self {
+        let channel = channel.root(cx);
+
+        Self::new(cx, Sink::DiagnosticsChannel(channel))
+    }
+
+    fn new<'cx, C: Context<'cx>>(cx: &mut C, sink: Sink) -> Self {
+        let mut channel = Channel::new(cx);
+
+        // Logging should not keep the process alive
+        channel.unref(cx);
+
+        Self {
+            channel,
+            level: LevelFilter::Info,
+            sink: Arc::new(sink),
+        }
+    }
+
+    /// Sets the most verbose level of records that are forwarded. Defaults to
+    /// [`LevelFilter::Info`].
+    pub fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Installs the logger as the global logger of the `log` crate and sets the maximum
+    /// log level.
+    ///
+    /// Fails if a global logger has already been installed, for example by another
+    /// instance of the module running in a worker thread.
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        let level = self.level;
+
+        ::log::set_boxed_logger(Box::new(self))?;
+        ::log::set_max_level(level);
+
+        Ok(())
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let entry = Entry {
+            level: record.level(),
+            target: record.target().to_owned(),
+            message: record.args().to_string(),
+            file: record.file().map(str::to_owned),
+            line: record.line(),
+        };
+
+        let sink = self.sink.clone();
+
+        // The record is dropped if the JavaScript environment has shut down
+        let _ = self
+            .channel
+            .try_send(move |mut cx| entry.deliver(&mut cx, &sink));
+    }
+
+    fn flush(&self) {}
+}
+
+struct Entry {
+    level: Level,
+    target: String,
+    message: String,
+    file: Option<String>,
+    line: Option<u32>,
+}
+
+impl Entry {
+    fn deliver(self, cx: &mut Cx, sink: &Sink) -> NeonResult<()> {
+        match sink {
+            Sink::Console => {
+                let console: Handle<JsObject> = cx.global("console")?;
+                let method = match self.level {
+                    Level::Error => "error",
+                    Level::Warn => "warn",
+                    Level::Info => "info",
+                    Level::Debug | Level::Trace => "debug",
+                };
+
+                console
+                    .method(cx, method)?
+                    .arg(format!("{}: {}", self.target, self.message))?
+                    .exec()
+            }
+            Sink::DiagnosticsChannel(channel) => {
+                let level = self.level.as_str().to_ascii_lowercase();
+                let entry = cx
+                    .object_builder()
+                    .prop("level", level)?
+                    .prop("target", self.target)?
+                    .prop("message", self.message)?
+                    .prop("file", self.file)?
+                    .prop("line", self.line.map(f64::from))?
+                    .build()?;
+
+                channel
+                    .to_inner(cx)
+                    .method(cx, "publish")?
+                    .arg(entry)?
+                    .exec()
+            }
+        }
+    }
+}
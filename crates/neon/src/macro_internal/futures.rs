@@ -1,7 +1,7 @@
 use std::future::Future;
 
 use crate::{
-    context::{Context, Cx, TaskContext},
+    context::{Cx, TaskContext},
     result::JsResult,
     types::JsValue,
 };
@@ -12,18 +12,7 @@ where
     F::Output: Send,
     S: FnOnce(TaskContext, F::Output) -> JsResult<JsValue> + Send + 'static,
 {
-    let rt = match crate::executor::RUNTIME.get(cx) {
-        Some(rt) => rt,
-        None => return cx.throw_error("must initialize with neon::set_global_executor"),
-    };
-
-    let ch = cx.channel();
-    let (d, promise) = cx.promise();
-
-    rt.spawn(Box::pin(async move {
-        let res = fut.await;
-        let _ = d.try_settle_with(&ch, move |cx| settle(cx, res));
-    }));
+    let promise = crate::futures::spawn(cx, fut, settle)?;
 
     Ok(promise.upcast())
 }
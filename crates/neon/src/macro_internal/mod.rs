@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 pub use linkme;
 
 use crate::{
-    context::{Context, Cx, ModuleContext},
+    context::{Context, Cx, FunctionContext, ModuleContext},
     handle::Handle,
     result::{JsResult, NeonResult},
     types::{extract::TryIntoJs, JsValue},
@@ -28,6 +28,15 @@ pub static EXPORTS: [for<'cx> fn(&mut ModuleContext<'cx>) -> NeonResult<Export<'
 #[linkme::distributed_slice]
 pub static MAIN: [for<'cx> fn(ModuleContext<'cx>) -> NeonResult<()>];
 
+#[cfg(feature = "testing")]
+#[linkme::distributed_slice]
+pub static TESTS: [for<'cx> fn(&mut ModuleContext<'cx>) -> NeonResult<Export<'cx>>];
+
+// Throws a `TypeError` unless exactly `expected` arguments were passed
+pub fn check_arity(cx: &mut FunctionContext, expected: usize) -> NeonResult<()> {
+    cx.check_arity(expected)
+}
+
 // Wrapper for the value type and return type tags
 pub struct NeonMarker<Tag, Return>(PhantomData<Tag>, PhantomData<Return>);
 
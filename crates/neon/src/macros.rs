@@ -133,6 +133,22 @@ pub use neon_macros::main;
 /// }
 /// ```
 ///
+/// ### Strict argument count
+///
+/// JavaScript ignores extra arguments and fills in missing ones with `undefined`. Adding
+/// the `strict` attribute throws a `TypeError` unless the function is called with exactly
+/// as many arguments as it declares. Context and `this` arguments are not counted.
+///
+/// ```
+/// #[neon::export(strict)]
+/// fn add(a: f64, b: f64) -> f64 {
+///     a + b
+/// }
+/// ```
+///
+/// The same check is available to hand-written functions with
+/// [`FunctionContext::args_strict`](crate::context::FunctionContext::args_strict).
+///
 /// ### Tasks
 ///
 /// Neon provides an API for spawning tasks to execute asynchronously on Node's worker
@@ -24,3 +24,82 @@ pub fn version() -> Version {
         build: Default::default(),
     }
 }
+
+/// Build information about a Neon module, exported with
+/// [`ModuleContext::export_metadata`](crate::context::ModuleContext::export_metadata).
+///
+/// Created with the [`neon::metadata!`](crate::metadata) macro, which reads the
+/// information when the module is compiled.
+#[derive(Clone, Debug)]
+pub struct ModuleMetadata {
+    name: &'static str,
+    version: &'static str,
+    git_hash: Option<&'static str>,
+    debug: bool,
+}
+
+impl ModuleMetadata {
+    #[doc(hidden)]
+    pub const fn new(
+        name: &'static str,
+        version: &'static str,
+        git_hash: Option<&'static str>,
+        debug: bool,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            git_hash,
+            debug,
+        }
+    }
+
+    /// The name of the module's crate.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The version of the module's crate.
+    pub fn version(&self) -> &'static str {
+        self.version
+    }
+
+    /// The git commit the module was built from, if provided at build time.
+    pub fn git_hash(&self) -> Option<&'static str> {
+        self.git_hash
+    }
+
+    /// Whether the module was built with debug assertions.
+    pub fn debug(&self) -> bool {
+        self.debug
+    }
+}
+
+/// Creates the [`ModuleMetadata`](crate::meta::ModuleMetadata) of the crate it is
+/// used in.
+///
+/// The git commit is read from the `NEON_GIT_HASH` environment variable at compile
+/// time. It can be set from a build script:
+///
+/// ```ignore
+/// // build.rs
+/// use std::process::Command;
+///
+/// fn main() {
+///     let output = Command::new("git").args(["rev-parse", "HEAD"]).output().unwrap();
+///     let hash = String::from_utf8(output.stdout).unwrap();
+///
+///     println!("cargo:rustc-env=NEON_GIT_HASH={}", hash.trim());
+/// }
+/// ```
+#[macro_export]
+macro_rules! metadata {
+    () => {
+        $crate::meta::ModuleMetadata::new(
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            option_env!("NEON_GIT_HASH"),
+            cfg!(debug_assertions),
+        )
+    };
+}
@@ -0,0 +1,146 @@
+use std::{os::raw::c_char, ptr};
+
+use crate::{
+    context::{internal::ContextInternal, Context, Cx},
+    result::{JsResult, NeonResult, Throw},
+    sys::{self, bindings::PropertyDescriptor, raw},
+    types::{extract::TryIntoJs, private::ValueInternal, JsObject},
+};
+
+/// Constructs a [`JsObject`] from a list of properties with a single call into the
+/// JavaScript engine.
+///
+/// Setting properties one at a time, for example with [`Object::prop`](super::Object::prop),
+/// crosses into the engine once per property. An `ObjectBuilder` instead collects the
+/// property values and defines all of them at once when the object is built. The
+/// resulting properties are enumerable, writable, and configurable, the same as
+/// properties created by assignment.
+///
+/// An `ObjectBuilder` is created with
+/// [`Context::object_builder`](crate::context::Context::object_builder).
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// fn user(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     cx.object_builder()
+///         .prop("name", "Ada")?
+///         .prop("id", 1815)?
+///         .prop("admin", true)?
+///         .build()
+/// }
+/// ```
+pub struct ObjectBuilder<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    props: Properties,
+}
+
+// Data properties collected to be defined with a single call
+#[derive(Default)]
+pub(crate) struct Properties {
+    // Property names, each terminated by a NUL byte
+    names: Vec<u8>,
+    props: Vec<Prop>,
+}
+
+struct Prop {
+    name: Name,
+    value: raw::Local,
+}
+
+enum Name {
+    // Offset of a NUL terminated name in `ObjectBuilder::names`
+    Utf8(usize),
+    // Names containing a NUL byte cannot be passed as C strings
+    Value(raw::Local),
+}
+
+impl Properties {
+    pub(crate) fn push(&mut self, cx: &mut Cx, key: &str, value: raw::Local) {
+        let name = if key.as_bytes().contains(&0) {
+            Name::Value(cx.string(key).to_local())
+        } else {
+            let offset = self.names.len();
+
+            self.names.extend_from_slice(key.as_bytes());
+            self.names.push(0);
+
+            Name::Utf8(offset)
+        };
+
+        self.props.push(Prop { name, value });
+    }
+
+    /// Defines all of the properties on `object`, in order.
+    pub(crate) fn define(self, cx: &mut Cx, object: raw::Local) -> NeonResult<()> {
+        let descriptors = self
+            .props
+            .iter()
+            .map(|prop| {
+                let (utf8name, name) = match prop.name {
+                    Name::Utf8(offset) => (
+                        self.names[offset..].as_ptr().cast::<c_char>(),
+                        ptr::null_mut(),
+                    ),
+                    Name::Value(name) => (ptr::null(), name),
+                };
+
+                PropertyDescriptor {
+                    utf8name,
+                    name,
+                    method: None,
+                    getter: None,
+                    setter: None,
+                    value: prop.value,
+                    attributes: sys::bindings::PropertyAttributes::DEFAULT_JSPROPERTY,
+                    data: ptr::null_mut(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let env = cx.env().to_raw();
+
+        unsafe {
+            match sys::object::define_values(env, object, &descriptors) {
+                Ok(()) => Ok(()),
+                Err(sys::Status::PendingException) => Err(Throw::new()),
+                _ => cx.throw_type_error("cannot define property"),
+            }
+        }
+    }
+}
+
+impl<'a, 'cx> ObjectBuilder<'a, 'cx> {
+    pub(crate) fn new(cx: &'a mut Cx<'cx>) -> Self {
+        Self {
+            cx,
+            props: Properties::default(),
+        }
+    }
+
+    /// Adds a property with a value converted from Rust.
+    ///
+    /// If the same key is added more than once, the last value is used.
+    ///
+    /// May throw an exception while converting the value.
+    pub fn prop<V: TryIntoJs<'cx>>(&mut self, key: &str, value: V) -> NeonResult<&mut Self> {
+        let value = value.try_into_js(self.cx)?.to_local();
+
+        self.props.push(self.cx, key, value);
+
+        Ok(self)
+    }
+
+    /// Creates the object with all of the added properties.
+    ///
+    /// The builder is left empty and may be reused to build another object.
+    pub fn build(&mut self) -> JsResult<'cx, JsObject> {
+        let props = std::mem::take(&mut self.props);
+        let obj = JsObject::new(self.cx);
+
+        props.define(self.cx, obj.to_local())?;
+
+        Ok(obj)
+    }
+}
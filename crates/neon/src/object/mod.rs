@@ -33,6 +33,14 @@
 
 use smallvec::smallvec;
 
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::wrap::{unwrap, wrap};
+pub use self::{
+    builder::ObjectBuilder,
+    schema::{Fields, Schema},
+};
+
 use crate::{
     context::{internal::ContextInternal, Context, Cx},
     handle::{Handle, Root},
@@ -49,10 +57,29 @@ use crate::{
 };
 
 #[cfg(feature = "napi-6")]
-use crate::{result::JsResult, types::JsArray};
+use crate::{handle::WeakRoot, result::JsResult, types::JsArray};
+
+mod builder;
+mod schema;
+#[cfg(feature = "napi-6")]
+mod wrap;
 
 /// A property key in a JavaScript object.
+///
+/// The methods of this trait are low-level hooks used by [`Object`]; prefer the methods
+/// on [`Object`], e.g., [`Object::prop`].
+///
+/// # Safety
+///
+/// Each method must only be called with `obj` referring to a valid object in the
+/// environment of `cx`. It returns `false` after throwing or otherwise failing, in
+/// which case `out` is not written.
 pub trait PropertyKey: Copy {
+    /// Reads the property of `obj` named by this key into `out`.
+    ///
+    /// # Safety
+    ///
+    /// See the [trait documentation](PropertyKey#safety).
     unsafe fn get_from<'c, C: Context<'c>>(
         self,
         cx: &mut C,
@@ -60,6 +87,13 @@ pub trait PropertyKey: Copy {
         obj: raw::Local,
     ) -> bool;
 
+    /// Sets the property of `obj` named by this key to `val`, writing whether it
+    /// succeeded to `out`.
+    ///
+    /// # Safety
+    ///
+    /// See the [trait documentation](PropertyKey#safety). `val` must be a valid value in
+    /// the environment of `cx`.
     unsafe fn set_from<'c, C: Context<'c>>(
         self,
         cx: &mut C,
@@ -67,6 +101,54 @@ pub trait PropertyKey: Copy {
         obj: raw::Local,
         val: raw::Local,
     ) -> bool;
+
+    /// Writes whether `obj` has an own property named by this key to `out`.
+    ///
+    /// The default implementation throws a `TypeError`, since a key type defined
+    /// outside of Neon cannot be passed to Node-API directly.
+    ///
+    /// # Safety
+    ///
+    /// See the [trait documentation](PropertyKey#safety).
+    unsafe fn has_own_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let _ = (out, obj);
+
+        unsupported_key(cx, "has_own")
+    }
+
+    /// Deletes the property of `obj` named by this key, writing whether it succeeded
+    /// to `out`.
+    ///
+    /// The default implementation throws a `TypeError`, since a key type defined
+    /// outside of Neon cannot be passed to Node-API directly.
+    ///
+    /// # Safety
+    ///
+    /// See the [trait documentation](PropertyKey#safety).
+    unsafe fn delete_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let _ = (out, obj);
+
+        unsupported_key(cx, "delete")
+    }
+}
+
+// Throws for an operation that a `PropertyKey` does not implement
+fn unsupported_key<'c, C: Context<'c>>(cx: &mut C, operation: &str) -> bool {
+    let _ = cx.throw_type_error::<_, ()>(format!(
+        "`{operation}` is not supported for this property key type"
+    ));
+
+    false
 }
 
 impl PropertyKey for u32 {
@@ -88,6 +170,27 @@ impl PropertyKey for u32 {
     ) -> bool {
         sys::object::set_index(out, cx.env().to_raw(), obj, self, val)
     }
+
+    unsafe fn has_own_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let key = self.to_string();
+        let (ptr, len) = Utf8::from(key.as_str()).into_small_unwrap().lower();
+
+        sys::object::has_own_string(out, cx.env().to_raw(), obj, ptr, len)
+    }
+
+    unsafe fn delete_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        sys::object::delete_index(out, cx.env().to_raw(), obj, self)
+    }
 }
 
 impl<'a, K: Value> PropertyKey for Handle<'a, K> {
@@ -113,6 +216,28 @@ impl<'a, K: Value> PropertyKey for Handle<'a, K> {
 
         sys::object::set(out, env, obj, self.to_local(), val)
     }
+
+    unsafe fn has_own_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let env = cx.env().to_raw();
+
+        sys::object::has_own(out, env, obj, self.to_local())
+    }
+
+    unsafe fn delete_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let env = cx.env().to_raw();
+
+        sys::object::delete(out, env, obj, self.to_local())
+    }
 }
 
 impl<'a> PropertyKey for &'a str {
@@ -140,6 +265,89 @@ impl<'a> PropertyKey for &'a str {
 
         sys::object::set_string(env, out, obj, ptr, len, val)
     }
+
+    unsafe fn has_own_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let (ptr, len) = Utf8::from(self).into_small_unwrap().lower();
+        let env = cx.env().to_raw();
+
+        sys::object::has_own_string(out, env, obj, ptr, len)
+    }
+
+    unsafe fn delete_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let (ptr, len) = Utf8::from(self).into_small_unwrap().lower();
+        let env = cx.env().to_raw();
+
+        sys::object::delete_string(out, env, obj, ptr, len)
+    }
+}
+
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+/// Options for [`Object::get_property_names`].
+///
+/// By default, only the object's own enumerable string keys are included, like
+/// [`Object.keys`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PropertyNamesOptions {
+    symbols: bool,
+    non_enumerable: bool,
+    prototypes: bool,
+}
+
+#[cfg(feature = "napi-6")]
+impl PropertyNamesOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include symbol keys. Defaults to `false`.
+    pub fn symbols(mut self, include: bool) -> Self {
+        self.symbols = include;
+        self
+    }
+
+    /// Include keys of non-enumerable properties. Defaults to `false`.
+    pub fn non_enumerable(mut self, include: bool) -> Self {
+        self.non_enumerable = include;
+        self
+    }
+
+    /// Include keys of properties inherited from the prototype chain. Defaults to `false`.
+    pub fn prototypes(mut self, include: bool) -> Self {
+        self.prototypes = include;
+        self
+    }
+
+    fn to_raw(self) -> (sys::KeyCollectionMode, sys::KeyFilter) {
+        let mode = if self.prototypes {
+            sys::KeyCollectionMode::IncludePrototypes
+        } else {
+            sys::KeyCollectionMode::OwnOnly
+        };
+
+        let mut filter = sys::KeyFilter::ALL_PROPERTIES;
+
+        if !self.symbols {
+            filter |= sys::KeyFilter::SKIP_SYMBOLS;
+        }
+
+        if !self.non_enumerable {
+            filter |= sys::KeyFilter::ENUMERABLE;
+        }
+
+        (mode, filter)
+    }
 }
 
 /// A builder for accessing an object property.
@@ -249,6 +457,7 @@ where
             cx: self.cx,
             callee,
             this,
+            new_target: None,
             args: smallvec![],
         })
     }
@@ -291,10 +500,94 @@ pub trait Object: Value {
             cx,
             callee,
             this,
+            new_target: None,
             args: smallvec![],
         })
     }
 
+    /// Gets the properties named by `keys`, in order.
+    ///
+    /// Each property is read with a single call into the engine, without first creating
+    /// a string for the key as [`Object::prop`] does, which adds up when reading many
+    /// properties, e.g., from an options object.
+    ///
+    /// May throw an exception from a getter.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let point = cx.argument::<JsObject>(0)?;
+    ///     let mut sum = 0.0;
+    ///
+    ///     for v in point.get_many(&mut cx, &["x", "y", "z"])? {
+    ///         sum += v.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx);
+    ///     }
+    ///
+    ///     Ok(cx.number(sum))
+    /// }
+    /// ```
+    fn get_many<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        keys: &[&str],
+    ) -> NeonResult<Vec<Handle<'a, JsValue>>> {
+        let env = cx.env();
+        let mut name = Vec::new();
+
+        keys.iter()
+            .map(|&key| {
+                // Names containing a NUL byte cannot be passed as C strings
+                if key.as_bytes().contains(&0) {
+                    return self.prop(cx.cx_mut(), key).get();
+                }
+
+                name.clear();
+                name.extend_from_slice(key.as_bytes());
+                name.push(0);
+
+                build(env, |out| unsafe {
+                    sys::object::get_named(env.to_raw(), out, self.to_local(), name.as_ptr().cast())
+                })
+            })
+            .collect()
+    }
+
+    /// Defines the properties in `entries` with a single call into the engine.
+    ///
+    /// The properties are defined as enumerable, writable, and configurable data
+    /// properties, the same as with an [`ObjectBuilder`] or `Object.defineProperty`.
+    /// Unlike assignment with [`Object::prop`], setters, including inherited ones, are
+    /// not called and existing properties are replaced.
+    ///
+    /// Throws an exception if a property cannot be defined, e.g., if the object is frozen.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn define_size(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let rect = cx.argument::<JsObject>(0)?;
+    ///     let width = cx.number(4);
+    ///     let height = cx.number(3);
+    ///
+    ///     rect.define_many(&mut cx, &[("width", width), ("height", height)])?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn define_many<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        entries: &[(&str, Handle<'a, V>)],
+    ) -> NeonResult<()> {
+        let cx = cx.cx_mut();
+        let mut props = builder::Properties::default();
+
+        for (key, value) in entries {
+            props.push(cx, key, value.to_local());
+        }
+
+        props.define(cx, self.to_local())
+    }
+
     #[deprecated(since = "TBD", note = "use `Object::prop()` instead")]
     fn get_opt<'a, V: Value, C: Context<'a>, K: PropertyKey>(
         &self,
@@ -340,6 +633,75 @@ pub trait Object: Value {
         })
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Returns the property keys of the object selected by `options` as an array.
+    ///
+    /// Numeric keys are converted to strings. When prototype properties are
+    /// included, the object's own keys come first, followed by the keys of each
+    /// prototype in the chain.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::object::PropertyNamesOptions;
+    ///
+    /// // Equivalent to `Reflect.ownKeys(obj)`
+    /// fn own_keys(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let obj = cx.argument::<JsObject>(0)?;
+    ///     let options = PropertyNamesOptions::new()
+    ///         .symbols(true)
+    ///         .non_enumerable(true);
+    ///
+    ///     obj.get_property_names(&mut cx, options)
+    /// }
+    /// ```
+    fn get_property_names<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        options: PropertyNamesOptions,
+    ) -> JsResult<'a, JsArray> {
+        let env = cx.env();
+        let (mode, filter) = options.to_raw();
+
+        build(cx.env(), |out| unsafe {
+            sys::object::get_property_names(out, env.to_raw(), self.to_local(), mode, filter)
+        })
+    }
+
+    /// Returns `true` if the object has an own property with the key, like
+    /// [`Object.hasOwn`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwn).
+    ///
+    /// Properties inherited from the prototype chain are not included.
+    fn has_own_property<'a, C: Context<'a>, K: PropertyKey>(
+        &self,
+        cx: &mut C,
+        key: K,
+    ) -> NeonResult<bool> {
+        let mut result = false;
+        unsafe {
+            if key.has_own_from(cx, &mut result, self.to_local()) {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    /// Deletes a property from the object, like the JavaScript `delete` operator.
+    ///
+    /// Returns `false` if the property exists but is not configurable, e.g., on a
+    /// frozen object. Deleting a property that does not exist returns `true`.
+    fn delete<'a, C: Context<'a>, K: PropertyKey>(&self, cx: &mut C, key: K) -> NeonResult<bool> {
+        let mut result = false;
+        unsafe {
+            if key.delete_from(cx, &mut result, self.to_local()) {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
     #[cfg(feature = "napi-8")]
     fn freeze<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<&Self> {
         let env = cx.env().to_raw();
@@ -387,6 +749,14 @@ pub trait Object: Value {
         Root::new(cx, self)
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Creates a [`WeakRoot`](crate::handle::WeakRoot) that does not prevent the
+    /// object from being garbage collected.
+    fn weak_root<'a, C: Context<'a>>(&self, cx: &mut C) -> WeakRoot<Self> {
+        WeakRoot::new(cx, self)
+    }
+
     #[deprecated(since = "TBD", note = "use `Object::method()` instead")]
     fn call_method_with<'a, C, K>(&self, cx: &mut C, method: K) -> NeonResult<CallOptions<'a>>
     where
@@ -0,0 +1,217 @@
+use crate::{
+    context::{Context, Cx},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    types::{
+        extract::Array, private::ValueInternal, JsNull, JsObject, JsUndefined, JsValue, Value,
+    },
+};
+
+/// Describes the properties expected on an object, such as an options bag passed to
+/// a function, and validates them in a single call.
+///
+/// Every property is checked before reporting, so a single `TypeError` lists all of
+/// the problems, each prefixed with the path of the property. The messages are also
+/// available as an `errors` array on the thrown error.
+///
+/// Optional properties may be `undefined` or `null`, or be missing entirely.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::object::Schema;
+///
+/// fn connect(mut cx: FunctionContext) -> JsResult<JsString> {
+///     let options = cx.argument::<JsValue>(0)?;
+///     let fields = Schema::new()
+///         .field::<JsString>("host")
+///         .optional::<JsNumber>("port")
+///         .object("retry", Schema::new().field::<JsNumber>("attempts"))
+///         .validate(&mut cx, options)?;
+///
+///     let host = fields.get::<JsString>(&mut cx, "host")?.value(&mut cx);
+///     let port = match fields.get_opt::<JsNumber>(&mut cx, "port")? {
+///         Some(port) => port.value(&mut cx),
+///         None => 80.0,
+///     };
+///     let attempts = fields.get::<JsNumber>(&mut cx, "retry.attempts")?.value(&mut cx);
+///
+///     Ok(cx.string(format!("{host}:{port} ({attempts} attempts)")))
+/// }
+/// ```
+///
+/// Calling `connect({ port: "80", retry: {} })` throws:
+///
+/// ```text
+/// TypeError: invalid options: host: missing; port: expected number; retry.attempts: missing
+/// ```
+#[derive(Default)]
+pub struct Schema {
+    fields: Vec<Field>,
+}
+
+struct Field {
+    name: String,
+    optional: bool,
+    kind: Kind,
+}
+
+enum Kind {
+    Value {
+        is_a: for<'cx> fn(&mut Cx<'cx>, Handle<'cx, JsValue>) -> bool,
+        name: &'static str,
+    },
+    Object(Schema),
+}
+
+fn is_a<'cx, V: Value>(cx: &mut Cx<'cx>, v: Handle<'cx, JsValue>) -> bool {
+    v.is_a::<V, _>(cx)
+}
+
+impl Schema {
+    /// Creates a schema without any properties.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a required property of type `V`.
+    pub fn field<V: Value>(self, name: &str) -> Self {
+        self.value::<V>(name, false)
+    }
+
+    /// Adds an optional property of type `V`.
+    pub fn optional<V: Value>(self, name: &str) -> Self {
+        self.value::<V>(name, true)
+    }
+
+    /// Adds a required object property with properties described by `schema`. Its
+    /// properties are accessed on [`Fields`] with a path joined by `.`, e.g., `retry.attempts`.
+    pub fn object(self, name: &str, schema: Schema) -> Self {
+        self.push(name, false, Kind::Object(schema))
+    }
+
+    /// Adds an optional object property with properties described by `schema`.
+    pub fn optional_object(self, name: &str, schema: Schema) -> Self {
+        self.push(name, true, Kind::Object(schema))
+    }
+
+    fn value<V: Value>(self, name: &str, optional: bool) -> Self {
+        let kind = Kind::Value {
+            is_a: is_a::<V>,
+            name: V::name(),
+        };
+
+        self.push(name, optional, kind)
+    }
+
+    fn push(mut self, name: &str, optional: bool, kind: Kind) -> Self {
+        self.fields.push(Field {
+            name: name.to_owned(),
+            optional,
+            kind,
+        });
+
+        self
+    }
+
+    /// Validates `value` against the schema, returning its properties.
+    ///
+    /// Throws a `TypeError` describing every invalid property if validation fails.
+    /// Reading a property may call a getter, which may also throw.
+    pub fn validate<'cx>(
+        &self,
+        cx: &mut Cx<'cx>,
+        value: Handle<'cx, JsValue>,
+    ) -> NeonResult<Fields<'cx>> {
+        let mut fields = Fields { values: Vec::new() };
+        let mut errors = Vec::new();
+
+        match value.downcast::<JsObject, _>(cx) {
+            Ok(obj) => self.check(cx, obj, "", &mut fields, &mut errors)?,
+            Err(_) => errors.push(format!("expected {}", JsObject::name())),
+        }
+
+        if errors.is_empty() {
+            return Ok(fields);
+        }
+
+        let err = cx.type_error(format!("invalid options: {}", errors.join("; ")))?;
+
+        err.prop(cx, "errors").set(Array(errors))?;
+        cx.throw(err)
+    }
+
+    fn check<'cx>(
+        &self,
+        cx: &mut Cx<'cx>,
+        obj: Handle<'cx, JsObject>,
+        prefix: &str,
+        fields: &mut Fields<'cx>,
+        errors: &mut Vec<String>,
+    ) -> NeonResult<()> {
+        for field in &self.fields {
+            let path = format!("{prefix}{}", field.name);
+            let value: Handle<JsValue> = obj.prop(cx, field.name.as_str()).get()?;
+
+            if value.is_a::<JsUndefined, _>(cx) || value.is_a::<JsNull, _>(cx) {
+                if !field.optional {
+                    errors.push(format!("{path}: missing"));
+                }
+
+                continue;
+            }
+
+            match &field.kind {
+                Kind::Value { is_a, name } => {
+                    if !is_a(cx, value) {
+                        errors.push(format!("{path}: expected {name}"));
+                        continue;
+                    }
+                }
+                Kind::Object(schema) => match value.downcast::<JsObject, _>(cx) {
+                    Ok(nested) => schema.check(cx, nested, &format!("{path}."), fields, errors)?,
+                    Err(_) => {
+                        errors.push(format!("{path}: expected {}", JsObject::name()));
+                        continue;
+                    }
+                },
+            }
+
+            fields.values.push((path, value));
+        }
+
+        Ok(())
+    }
+}
+
+/// Properties of an object validated with [`Schema::validate`].
+pub struct Fields<'cx> {
+    values: Vec<(String, Handle<'cx, JsValue>)>,
+}
+
+impl<'cx> Fields<'cx> {
+    /// Returns the property at `path`, throwing if it is missing or not a `V`.
+    pub fn get<V: Value>(&self, cx: &mut Cx<'cx>, path: &str) -> JsResult<'cx, V> {
+        match self.get_opt(cx, path)? {
+            Some(v) => Ok(v),
+            None => cx.throw_type_error(format!("{path}: missing")),
+        }
+    }
+
+    /// Returns the property at `path`, or `None` if it was missing, `undefined` or `null`.
+    ///
+    /// Throws if the property is not a `V`.
+    pub fn get_opt<V: Value>(
+        &self,
+        cx: &mut Cx<'cx>,
+        path: &str,
+    ) -> NeonResult<Option<Handle<'cx, V>>> {
+        self.values
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, v)| v.downcast_or_throw(cx))
+            .transpose()
+    }
+}
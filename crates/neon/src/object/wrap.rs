@@ -0,0 +1,110 @@
+//! Attaching Rust data to JavaScript objects created outside of Neon.
+//!
+//! Wrapped values are stored in a per-instance `WeakMap` keyed by the object. Unlike
+//! `napi_wrap`, this is safe to use with objects that were wrapped by another addon
+//! and works with frozen or sealed objects, since the object itself is not modified.
+
+use crate::{
+    context::{Context, Cx},
+    handle::{Handle, Root},
+    intrinsics,
+    object::Object,
+    result::{JsResult, NeonResult},
+    thread::LocalKey,
+    types::{Finalize, JsBox, JsObject, JsValue},
+};
+
+fn wraps<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsObject> {
+    static WRAPS: LocalKey<Root<JsObject>> = LocalKey::new();
+
+    WRAPS
+        .get_or_try_init(cx, |cx| {
+            intrinsics::weak_map(cx)?
+                .bind(cx)
+                .construct::<Handle<JsObject>>()
+                .map(|map| map.root(cx))
+        })
+        .map(|map| map.to_inner(cx))
+}
+
+/// Attaches a Rust value to a JavaScript object.
+///
+/// The value is [finalized](Finalize) and dropped after the object is garbage
+/// collected. It may be retrieved with [`unwrap`] while the object is alive.
+///
+/// Throws a JavaScript `Error` if the object has already been wrapped. An object may
+/// only be wrapped once and the value cannot be removed; use a type with interior
+/// mutability (e.g., [`RefCell`](std::cell::RefCell)) if the value needs to change.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::cell::Cell;
+///
+/// struct Visits(Cell<u32>);
+///
+/// impl Finalize for Visits {}
+///
+/// // Counts the number of times each object has been passed to `visit`
+/// fn visit(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///
+///     let visits = match neon::object::unwrap::<Visits, _>(&mut cx, obj)? {
+///         Some(visits) => visits,
+///         None => {
+///             neon::object::wrap(&mut cx, obj, Visits(Cell::new(0)))?;
+///             neon::object::unwrap::<Visits, _>(&mut cx, obj)?.unwrap()
+///         }
+///     };
+///
+///     visits.0.set(visits.0.get() + 1);
+///
+///     Ok(cx.number(visits.0.get()))
+/// }
+/// ```
+pub fn wrap<'cx, T, O>(cx: &mut Cx<'cx>, object: Handle<'cx, O>, value: T) -> NeonResult<()>
+where
+    T: Finalize + 'static,
+    O: Object,
+{
+    let map = wraps(cx)?;
+    let wrapped: bool = intrinsics::weak_map_has(cx)?
+        .bind(cx)
+        .this(map)?
+        .arg(object)?
+        .call()?;
+
+    if wrapped {
+        return cx.throw_error("Object is already wrapped");
+    }
+
+    let value = cx.boxed(value);
+
+    intrinsics::weak_map_set(cx)?
+        .bind(cx)
+        .this(map)?
+        .arg(object)?
+        .arg(value)?
+        .exec()
+}
+
+/// Returns the Rust value attached to a JavaScript object with [`wrap`].
+///
+/// Returns `None` if the object has not been wrapped or was wrapped with a value of
+/// a different type.
+pub fn unwrap<'cx, T, O>(cx: &mut Cx<'cx>, object: Handle<'cx, O>) -> NeonResult<Option<&'cx T>>
+where
+    T: Finalize + 'static,
+    O: Object,
+{
+    let map = wraps(cx)?;
+    let value: Handle<JsValue> = intrinsics::weak_map_get(cx)?
+        .bind(cx)
+        .this(map)?
+        .arg(object)?
+        .call()?;
+
+    Ok(value
+        .downcast::<JsBox<T>, _>(cx)
+        .ok()
+        .map(|value| JsBox::deref(&value)))
+}
@@ -33,8 +33,13 @@
 //! [question-mark]: https://doc.rust-lang.org/edition-guide/rust-2018/error-handling-and-panics/the-question-mark-operator-for-easier-error-handling.html
 
 use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
+    error::Error,
+    fmt::{self, Display, Formatter, Result as FmtResult},
+    io,
     marker::PhantomData,
+    num::{ParseFloatError, ParseIntError},
+    str::Utf8Error,
+    string::FromUtf8Error,
 };
 
 use crate::{context::Context, handle::Handle, types::Value};
@@ -97,3 +102,85 @@ where
         self.or_else(|err| cx.throw(err))
     }
 }
+
+/// A Rust error that can be thrown as a JavaScript `Error`.
+///
+/// The message of the thrown error is the [`Display`] output of the Rust error, and
+/// its `code` property is set from [`code`](ThrowError::code) if present. Implementing
+/// the trait allows [`Context::with_throw_guard`] and [`ResultExt::or_throw`] to
+/// convert the error.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::fmt;
+///
+/// use neon::result::ThrowError;
+///
+/// #[derive(Debug)]
+/// struct NotFound(String);
+///
+/// impl fmt::Display for NotFound {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "no such user: {}", self.0)
+///     }
+/// }
+///
+/// impl ThrowError for NotFound {
+///     fn code(&self) -> Option<&str> {
+///         Some("ERR_NOT_FOUND")
+///     }
+/// }
+///
+/// fn find_user(name: String) -> Result<f64, NotFound> {
+///     Err(NotFound(name))
+/// }
+///
+/// fn user_id(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let name = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let id = find_user(name).or_throw(&mut cx)?;
+///
+///     Ok(cx.number(id))
+/// }
+/// ```
+pub trait ThrowError: Display {
+    /// The `code` property of the thrown error. Defaults to `None`, which leaves
+    /// the property unset.
+    fn code(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: ThrowError,
+{
+    fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
+        self.or_else(|err| {
+            let mut builder = cx.error_builder(err.to_string());
+
+            if let Some(code) = err.code() {
+                builder.code(code);
+            }
+
+            builder.throw()
+        })
+    }
+}
+
+impl ThrowError for String {}
+
+impl ThrowError for &str {}
+
+impl ThrowError for Box<dyn Error + Send + Sync> {}
+
+impl ThrowError for io::Error {}
+
+impl ThrowError for fmt::Error {}
+
+impl ThrowError for ParseIntError {}
+
+impl ThrowError for ParseFloatError {}
+
+impl ThrowError for Utf8Error {}
+
+impl ThrowError for FromUtf8Error {}
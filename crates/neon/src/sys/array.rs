@@ -1,10 +1,16 @@
 //! Facilities for working with Array `napi_value`s.
 
+use std::ptr;
+
 use super::{
     bindings as napi,
     raw::{Env, Local},
 };
 
+// Arguments passed to each call of `Array.prototype.push`, bounded to avoid exceeding
+// the engine's limit on the number of arguments to a function
+const PUSH_CHUNK_LEN: usize = 8192;
+
 pub unsafe fn new(out: &mut Local, env: Env, length: usize) {
     napi::create_array_with_length(env, length, out as *mut _).unwrap();
 }
@@ -19,3 +25,19 @@ pub unsafe fn len(env: Env, array: Local) -> u32 {
     napi::get_array_length(env, array, &mut len as *mut _).unwrap();
     len
 }
+
+/// Appends `values` to the end of `array` in batches by calling `push`, which should be
+/// the built-in `Array.prototype.push`, avoiding a property set for each element.
+/// Returns `false` if pushing throws, e.g., because the array is frozen.
+pub unsafe fn push(env: Env, array: Local, push: Local, values: &[Local]) -> bool {
+    for chunk in values.chunks(PUSH_CHUNK_LEN) {
+        let mut out = ptr::null_mut();
+
+        match napi::call_function(env, array, push, chunk.len(), chunk.as_ptr(), &mut out) {
+            Err(_) if super::error::is_throwing(env) => return false,
+            status => status.unwrap(),
+        }
+    }
+
+    true
+}
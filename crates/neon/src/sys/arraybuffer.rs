@@ -1,6 +1,6 @@
-#[cfg(feature = "external-buffers")]
-use std::os::raw::c_void;
 use std::{mem::MaybeUninit, ptr::null_mut, slice};
+#[cfg(feature = "external-buffers")]
+use std::{os::raw::c_void, sync::Arc};
 
 use super::{
     bindings as napi,
@@ -19,6 +19,25 @@ pub unsafe fn new(env: Env, len: usize) -> Result<Local, napi::Status> {
     Ok(buf.assume_init())
 }
 
+pub unsafe fn from_slice(env: Env, data: &[u8]) -> Result<Local, napi::Status> {
+    let mut buf = MaybeUninit::uninit();
+    let mut bytes = MaybeUninit::uninit();
+    let status = napi::create_arraybuffer(env, data.len(), bytes.as_mut_ptr(), buf.as_mut_ptr());
+
+    match status {
+        Err(err @ napi::Status::PendingException) => return Err(err),
+        status => status.unwrap(),
+    };
+
+    if !data.is_empty() {
+        let bytes = bytes.assume_init().cast::<u8>();
+
+        std::ptr::copy_nonoverlapping(data.as_ptr(), bytes, data.len());
+    }
+
+    Ok(buf.assume_init())
+}
+
 #[cfg(feature = "external-buffers")]
 pub unsafe fn new_external<T>(env: Env, data: T) -> Local
 where
@@ -43,6 +62,33 @@ where
     result.assume_init()
 }
 
+#[cfg(feature = "external-buffers")]
+/// # Safety
+/// * Caller must ensure `env` is valid
+/// * The data must not be written while the `ArrayBuffer` is reachable from JavaScript
+pub unsafe fn new_external_shared<T>(env: Env, data: Arc<T>) -> Local
+where
+    T: AsRef<[u8]> + Send + Sync + ?Sized,
+{
+    // The bytes live in the `Arc` allocation, so boxing the `Arc` does not move them
+    let data = Box::new(data);
+    let buf = (**data).as_ref();
+    let length = buf.len();
+    let mut result = MaybeUninit::uninit();
+
+    napi::create_external_arraybuffer(
+        env,
+        buf.as_ptr() as *mut _,
+        length,
+        Some(drop_external::<Arc<T>>),
+        Box::into_raw(data) as *mut _,
+        result.as_mut_ptr(),
+    )
+    .unwrap();
+
+    result.assume_init()
+}
+
 #[cfg(feature = "external-buffers")]
 unsafe extern "C" fn drop_external<T>(_env: Env, _data: *mut c_void, hint: *mut c_void) {
     drop(Box::<T>::from_raw(hint as *mut _));
@@ -0,0 +1,93 @@
+//! Rust wrappers for Node-API custom asynchronous operations
+//!
+//! An async context is captured when it is created and restored while a callback
+//! scope is open, allowing `async_hooks` and `AsyncLocalStorage` to follow work
+//! that is scheduled from Rust.
+//!
+//! See: [Custom asynchronous operations](https://nodejs.org/api/n-api.html#custom-asynchronous-operations)
+
+use std::mem::MaybeUninit;
+
+use super::{bindings as napi, raw::Env, reference};
+
+#[derive(Debug)]
+pub struct AsyncContext {
+    resource: napi::Ref,
+    context: napi::AsyncContext,
+}
+
+// Safety: The resource and context are only used on the JavaScript thread
+unsafe impl Send for AsyncContext {}
+
+unsafe impl Sync for AsyncContext {}
+
+impl AsyncContext {
+    /// Captures the current async context for a new resource, emitting an `init` hook
+    ///
+    /// # Safety
+    /// `env` must be valid for the current thread
+    pub unsafe fn new(env: Env, name: &str) -> Self {
+        let mut resource = MaybeUninit::uninit();
+
+        napi::create_object(env, resource.as_mut_ptr()).unwrap();
+
+        let resource = resource.assume_init();
+        let mut context = MaybeUninit::uninit();
+
+        napi::async_init(
+            env,
+            resource,
+            super::string(env, name),
+            context.as_mut_ptr(),
+        )
+        .unwrap();
+
+        Self {
+            resource: reference::new(env, resource),
+            context: context.assume_init(),
+        }
+    }
+
+    /// Executes `f` in the captured async context, emitting `before` and `after` hooks
+    ///
+    /// # Safety
+    /// `env` must be the same environment that created the context
+    pub unsafe fn enter<T>(&self, env: Env, f: impl FnOnce() -> T) -> T {
+        let resource = reference::get(env, self.resource);
+        let mut scope = MaybeUninit::uninit();
+
+        napi::open_callback_scope(env, resource, self.context, scope.as_mut_ptr()).unwrap();
+
+        // Close the scope even if `f` panics
+        let _scope = Scope {
+            env,
+            scope: scope.assume_init(),
+        };
+
+        f()
+    }
+
+    /// Emits the `destroy` hook and releases the resource
+    ///
+    /// # Safety
+    /// `env` must be the same environment that created the context
+    pub unsafe fn destroy(self, env: Env) {
+        napi::async_destroy(env, self.context).unwrap();
+        napi::delete_reference(env, self.resource).unwrap();
+    }
+}
+
+struct Scope {
+    env: Env,
+    scope: napi::CallbackScope,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        unsafe {
+            let _status = napi::close_callback_scope(self.env, self.scope);
+
+            debug_assert_eq!(_status, Ok(()),);
+        }
+    }
+}
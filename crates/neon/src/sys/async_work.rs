@@ -27,7 +27,7 @@ const BOUNDARY: FailureBoundary = FailureBoundary {
 type Execute<I, O> = fn(input: I) -> O;
 type Complete<O, D> = fn(env: Env, output: thread::Result<O>, data: D);
 
-/// Schedule work to execute on the libuv thread pool
+/// Schedule work to execute on the libuv thread pool with an async resource named `name`
 ///
 /// # Safety
 /// * `env` must be a valid `napi_env` for the current thread
@@ -35,6 +35,7 @@ type Complete<O, D> = fn(env: Env, output: thread::Result<O>, data: D);
 ///   `execute` is not unwind safe
 pub unsafe fn schedule<I, O, D>(
     env: Env,
+    name: &str,
     input: I,
     execute: Execute<I, O>,
     complete: Complete<O, D>,
@@ -61,7 +62,7 @@ pub unsafe fn schedule<I, O, D>(
     napi::create_async_work(
         env,
         ptr::null_mut(),
-        super::string(env, "neon_async_work"),
+        super::string(env, name),
         Some(call_execute::<I, O, D>),
         Some(call_complete::<I, O, D>),
         Box::into_raw(data).cast(),
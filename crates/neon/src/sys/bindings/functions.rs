@@ -77,6 +77,14 @@ mod napi1 {
                 result: *mut usize,
             ) -> Status;
 
+            fn get_value_string_latin1(
+                env: Env,
+                value: Value,
+                buf: *mut c_char,
+                bufsize: usize,
+                result: *mut usize,
+            ) -> Status;
+
             fn create_type_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
 
             fn create_range_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
@@ -88,6 +96,20 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_string_utf16(
+                env: Env,
+                str: *const u16,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn create_string_latin1(
+                env: Env,
+                str: *const c_char,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
             fn create_arraybuffer(
                 env: Env,
                 byte_length: usize,
@@ -128,6 +150,14 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_buffer_copy(
+                env: Env,
+                length: usize,
+                data: *const c_void,
+                result_data: *mut *mut c_void,
+                result: *mut Value,
+            ) -> Status;
+
             fn get_buffer_info(
                 env: Env,
                 value: Value,
@@ -169,6 +199,13 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn define_properties(
+                env: Env,
+                object: Value,
+                property_count: usize,
+                properties: *const PropertyDescriptor,
+            ) -> Status;
+
             fn create_function(
                 env: Env,
                 utf8name: *const c_char,
@@ -182,10 +219,23 @@ mod napi1 {
 
             fn get_property(env: Env, object: Value, key: Value, result: *mut Value) -> Status;
 
+            fn get_named_property(
+                env: Env,
+                object: Value,
+                utf8name: *const c_char,
+                result: *mut Value,
+            ) -> Status;
+
             fn set_element(env: Env, object: Value, index: u32, value: Value) -> Status;
 
             fn get_element(env: Env, object: Value, index: u32, result: *mut Value) -> Status;
 
+            fn has_own_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn delete_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn delete_element(env: Env, object: Value, index: u32, result: *mut bool) -> Status;
+
             fn escape_handle(
                 env: Env,
                 scope: EscapableHandleScope,
@@ -210,6 +260,9 @@ mod napi1 {
 
             fn strict_equals(env: Env, lhs: Value, rhs: Value, result: *mut bool) -> Status;
 
+            fn instanceof(env: Env, object: Value, constructor: Value, result: *mut bool)
+                -> Status;
+
             #[cfg(any(feature = "sys", feature = "external-buffers"))]
             fn create_external_arraybuffer(
                 env: Env,
@@ -232,6 +285,12 @@ mod napi1 {
 
             fn run_script(env: Env, script: Value, result: *mut Value) -> Status;
 
+            fn adjust_external_memory(
+                env: Env,
+                change_in_bytes: i64,
+                adjusted_value: *mut i64,
+            ) -> Status;
+
             fn create_async_work(
                 env: Env,
                 async_resource: Value,
@@ -244,6 +303,16 @@ mod napi1 {
 
             fn delete_async_work(env: Env, work: AsyncWork) -> Status;
             fn queue_async_work(env: Env, work: AsyncWork) -> Status;
+
+            fn async_init(
+                env: Env,
+                async_resource: Value,
+                async_resource_name: Value,
+                result: *mut AsyncContext,
+            ) -> Status;
+
+            fn async_destroy(env: Env, async_context: AsyncContext) -> Status;
+
             fn create_promise(env: Env, deferred: *mut Deferred, promise: *mut Value) -> Status;
             fn resolve_deferred(env: Env, deferred: Deferred, resolution: Value) -> Status;
             fn reject_deferred(env: Env, deferred: Deferred, rejection: Value) -> Status;
@@ -254,6 +323,35 @@ mod napi1 {
                 message: *const c_char,
                 message_len: usize,
             );
+
+            fn get_version(env: Env, result: *mut u32) -> Status;
+
+            fn get_node_version(env: Env, version: *mut *const NodeVersion) -> Status;
+        }
+    );
+}
+
+#[cfg(feature = "napi-3")]
+mod napi3 {
+    use super::super::types::*;
+    use std::os::raw::c_void;
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+        extern "C" {
+            fn add_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+
+            #[cfg(any(feature = "sys", feature = "napi-6"))]
+            fn remove_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+
+            fn open_callback_scope(
+                env: Env,
+                resource_object: Value,
+                context: AsyncContext,
+                result: *mut CallbackScope,
+            ) -> Status;
+
+            fn close_callback_scope(env: Env, scope: CallbackScope) -> Status;
         }
     );
 }
@@ -407,7 +505,40 @@ mod napi8 {
     );
 }
 
+#[cfg(feature = "napi-experimental")]
+mod napi_experimental {
+    use super::super::types::*;
+    use std::os::raw::{c_char, c_void};
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-experimental")))]
+        extern "C" {
+            fn create_external_string_latin1(
+                env: Env,
+                str: *mut c_char,
+                length: usize,
+                finalize_callback: Finalize,
+                finalize_hint: *mut c_void,
+                result: *mut Value,
+                copied: *mut bool,
+            ) -> Status;
+
+            fn create_external_string_utf16(
+                env: Env,
+                str: *mut u16,
+                length: usize,
+                finalize_callback: Finalize,
+                finalize_hint: *mut c_void,
+                result: *mut Value,
+                copied: *mut bool,
+            ) -> Status;
+        }
+    );
+}
+
 pub use napi1::*;
+#[cfg(feature = "napi-3")]
+pub use napi3::*;
 #[cfg(feature = "napi-4")]
 pub use napi4::*;
 #[cfg(feature = "napi-5")]
@@ -416,11 +547,32 @@ pub use napi5::*;
 pub use napi6::*;
 #[cfg(feature = "napi-8")]
 pub use napi8::*;
+#[cfg(feature = "napi-experimental")]
+pub use napi_experimental::*;
 
 use super::{Env, Status};
 
+/// The minimum Node-API version required by the enabled `napi-*` features
+pub(crate) const NAPI_VERSION: u32 = if cfg!(feature = "napi-8") {
+    8
+} else if cfg!(feature = "napi-7") {
+    7
+} else if cfg!(feature = "napi-6") {
+    6
+} else if cfg!(feature = "napi-5") {
+    5
+} else if cfg!(feature = "napi-4") {
+    4
+} else if cfg!(feature = "napi-3") {
+    3
+} else if cfg!(feature = "napi-2") {
+    2
+} else {
+    1
+};
+
 // This symbol is loaded separately because it is a prerequisite
-unsafe fn get_version(host: &libloading::Library, env: Env) -> Result<u32, libloading::Error> {
+unsafe fn load_version(host: &libloading::Library, env: Env) -> Result<u32, libloading::Error> {
     let get_version = host.get::<fn(Env, *mut u32) -> Status>(b"napi_get_version")?;
     let mut version = 0;
 
@@ -437,18 +589,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
 
     // This never fail since `get_version` is in N-API Version 1 and the module will fail
     // with `Error: Module did not self-register` if N-API does not exist.
-    let actual_version = get_version(&host, env).expect("Failed to find N-API version");
-
-    let expected_version = match () {
-        _ if cfg!(feature = "napi-8") => 8,
-        _ if cfg!(feature = "napi-7") => 7,
-        _ if cfg!(feature = "napi-6") => 6,
-        _ if cfg!(feature = "napi-5") => 5,
-        _ if cfg!(feature = "napi-4") => 4,
-        _ if cfg!(feature = "napi-3") => 3,
-        _ if cfg!(feature = "napi-2") => 2,
-        _ => 1,
-    };
+    let actual_version = load_version(&host, env).expect("Failed to find N-API version");
+
+    let expected_version = NAPI_VERSION;
 
     if actual_version < expected_version {
         eprintln!("Minimum required Node-API version {expected_version}, found {actual_version}.\n\nSee the Node-API support matrix for more details: https://nodejs.org/api/n-api.html#node-api-version-matrix");
@@ -456,6 +599,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
 
     napi1::load(&host);
 
+    #[cfg(feature = "napi-3")]
+    napi3::load(&host);
+
     #[cfg(feature = "napi-4")]
     napi4::load(&host);
 
@@ -468,5 +614,8 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
     #[cfg(feature = "napi-8")]
     napi8::load(&host);
 
+    #[cfg(feature = "napi-experimental")]
+    napi_experimental::load(&host);
+
     Ok(())
 }
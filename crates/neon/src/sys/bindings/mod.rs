@@ -21,6 +21,13 @@ macro_rules! napi_name {
     (typeof_value) => {
         "napi_typeof"
     };
+    // Functions added after the `node_api_` prefix was introduced
+    (create_external_string_latin1) => {
+        "node_api_create_external_string_latin1"
+    };
+    (create_external_string_utf16) => {
+        "node_api_create_external_string_utf16"
+    };
     // Default case: Stringify the identifier and prefix with `napi_`
     ($name:ident) => {
         concat!("napi_", stringify!($name))
@@ -1,4 +1,4 @@
-use std::ffi::c_void;
+use std::{ffi::c_void, os::raw::c_char};
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -88,6 +88,11 @@ pub type Callback = Option<unsafe extern "C" fn(env: Env, info: CallbackInfo) ->
 pub type Finalize =
     Option<unsafe extern "C" fn(env: Env, finalize_data: *mut c_void, finalize_hint: *mut c_void)>;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+#[cfg(feature = "napi-3")]
+/// Cleanup hook passed to [`napi_add_env_cleanup_hook`](https://nodejs.org/api/n-api.html#napi_add_env_cleanup_hook)
+pub type CleanupHook = Option<unsafe extern "C" fn(arg: *mut c_void)>;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
 #[cfg(feature = "napi-4")]
 /// [`napi_threadsafe_function_call_js`](https://nodejs.org/api/n-api.html#napi_threadsafe_function_call_js)
@@ -218,6 +223,7 @@ pub struct KeyFilter(pub ::std::os::raw::c_uint);
 impl KeyFilter {
     pub const ALL_PROPERTIES: KeyFilter = KeyFilter(0);
     pub const WRITABLE: KeyFilter = KeyFilter(1);
+    pub const ENUMERABLE: KeyFilter = KeyFilter(2);
     pub const CONFIGURABLE: KeyFilter = KeyFilter(4);
     pub const SKIP_STRINGS: KeyFilter = KeyFilter(8);
     pub const SKIP_SYMBOLS: KeyFilter = KeyFilter(16);
@@ -257,6 +263,49 @@ impl std::ops::BitAndAssign for KeyFilter {
     }
 }
 
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_property_attributes`](https://nodejs.org/api/n-api.html#napi_property_attributes)
+pub struct PropertyAttributes(pub ::std::os::raw::c_uint);
+
+#[allow(dead_code)]
+impl PropertyAttributes {
+    pub const DEFAULT: PropertyAttributes = PropertyAttributes(0);
+    pub const WRITABLE: PropertyAttributes = PropertyAttributes(1);
+    pub const ENUMERABLE: PropertyAttributes = PropertyAttributes(2);
+    pub const CONFIGURABLE: PropertyAttributes = PropertyAttributes(4);
+    pub const STATIC: PropertyAttributes = PropertyAttributes(1 << 10);
+    /// Equivalent to a property created by assignment
+    pub const DEFAULT_JSPROPERTY: PropertyAttributes = PropertyAttributes(1 | 2 | 4);
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_property_descriptor`](https://nodejs.org/api/n-api.html#napi_property_descriptor)
+pub struct PropertyDescriptor {
+    pub utf8name: *const c_char,
+    pub name: Value,
+    pub method: Callback,
+    pub getter: Callback,
+    pub setter: Callback,
+    pub value: Value,
+    pub attributes: PropertyAttributes,
+    pub data: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_node_version`](https://nodejs.org/api/n-api.html#napi_node_version)
+pub struct NodeVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub release: *const c_char,
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[doc(hidden)]
@@ -277,6 +326,28 @@ pub type AsyncExecuteCallback = Option<unsafe extern "C" fn(env: Env, data: *mut
 pub type AsyncCompleteCallback =
     Option<unsafe extern "C" fn(env: Env, status: Status, data: *mut c_void)>;
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[doc(hidden)]
+pub struct AsyncContext__ {
+    _unused: [u8; 0],
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_async_context`](https://nodejs.org/api/n-api.html#napi_async_context)
+pub type AsyncContext = *mut AsyncContext__;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[doc(hidden)]
+pub struct CallbackScope__ {
+    _unused: [u8; 0],
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+/// [`napi_callback_scope`](https://nodejs.org/api/n-api.html#napi_callback_scope)
+pub type CallbackScope = *mut CallbackScope__;
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[doc(hidden)]
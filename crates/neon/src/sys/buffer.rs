@@ -1,6 +1,6 @@
 #[cfg(feature = "external-buffers")]
 use std::os::raw::c_void;
-use std::{mem::MaybeUninit, slice};
+use std::{mem::MaybeUninit, ptr, slice};
 
 use super::{
     bindings as napi,
@@ -28,6 +28,24 @@ pub unsafe fn uninitialized(env: Env, len: usize) -> Result<(Local, *mut u8), na
     Ok((buf.assume_init(), bytes.assume_init().cast()))
 }
 
+pub unsafe fn from_slice(env: Env, data: &[u8]) -> Result<Local, napi::Status> {
+    let mut buf = MaybeUninit::uninit();
+    let status = napi::create_buffer_copy(
+        env,
+        data.len(),
+        data.as_ptr().cast(),
+        ptr::null_mut(),
+        buf.as_mut_ptr(),
+    );
+
+    match status {
+        Err(err @ napi::Status::PendingException) => return Err(err),
+        status => status.unwrap(),
+    };
+
+    Ok(buf.assume_init())
+}
+
 #[cfg(feature = "external-buffers")]
 pub unsafe fn new_external<T>(env: Env, data: T) -> Local
 where
@@ -28,20 +28,29 @@ impl Arguments {
     pub fn get(&self, i: usize) -> Option<Local> {
         self.0.get(i).cloned()
     }
+
+    #[inline]
+    /// Get the arguments starting at a specific position
+    pub fn rest(&self, from: usize) -> &[Local] {
+        self.0.get(from..).unwrap_or_default()
+    }
 }
 
 pub unsafe fn is_construct(env: Env, info: FunctionCallbackInfo) -> bool {
+    !new_target(env, info).is_null()
+}
+
+/// Gets the `new.target` of the call, or NULL if the function was called without `new`.
+pub unsafe fn new_target(env: Env, info: FunctionCallbackInfo) -> Local {
     let mut target: MaybeUninit<Local> = MaybeUninit::zeroed();
 
     napi::get_new_target(env, info, target.as_mut_ptr()).unwrap();
 
     // get_new_target is guaranteed to assign to target, so it's initialized.
-    let target: Local = target.assume_init();
-
     // By the get_new_target contract, target will either be NULL if the current
     // function was called without `new`, or a valid napi_value handle if the current
     // function was called with `new`.
-    !target.is_null()
+    target.assume_init()
 }
 
 pub unsafe fn this(env: Env, info: FunctionCallbackInfo, out: &mut Local) {
@@ -40,8 +40,13 @@ pub unsafe fn throw(env: Env, val: Local) {
 }
 
 pub unsafe fn new_error(env: Env, out: &mut Local, msg: Local) {
+    new_error_with_code(env, out, ptr::null_mut(), msg);
+}
+
+/// Creates an `Error` with a `code` property. A NULL `code` is omitted.
+pub unsafe fn new_error_with_code(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    napi::create_error(env, ptr::null_mut(), msg, result.as_mut_ptr()).unwrap();
+    napi::create_error(env, code, msg, result.as_mut_ptr()).unwrap();
 
     *out = result.assume_init();
 }
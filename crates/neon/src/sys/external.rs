@@ -83,3 +83,72 @@ pub unsafe fn create<T: 'static>(env: Env, v: T, finalizer: fn(Env, T)) -> Local
 
     external
 }
+
+/// `finalize_tagged` is invoked immediately before a tagged `napi_external` is garbage collected
+#[cfg(feature = "napi-8")]
+extern "C" fn finalize_tagged<T: 'static>(
+    env: Env,
+    // Raw pointer to a `Box<T>`; unlike `create`, the data is not wrapped so that
+    // other native modules may read it directly
+    data: *mut std::ffi::c_void,
+    // Pointer to a Rust `fn` called with the contents of `data`
+    hint: *mut std::ffi::c_void,
+) {
+    unsafe {
+        let data = Box::<T>::from_raw(data as *mut _);
+        let finalizer: fn(Env, T) = std::mem::transmute(hint as *const ());
+
+        finalizer(env, *data);
+    }
+}
+
+/// Creates a `napi_external` pointing directly at a `T`, tagged with `tag`
+#[cfg(feature = "napi-8")]
+pub unsafe fn create_tagged<T: 'static>(
+    env: Env,
+    v: T,
+    tag: &super::TypeTag,
+    finalizer: fn(Env, T),
+) -> Local {
+    let mut result = MaybeUninit::uninit();
+
+    napi::create_external(
+        env,
+        Box::into_raw(Box::new(v)) as *mut _,
+        Some(finalize_tagged::<T>),
+        finalizer as *const () as *mut _,
+        result.as_mut_ptr(),
+    )
+    .unwrap();
+
+    let external = result.assume_init();
+
+    super::tag::type_tag_object(env, external, tag);
+
+    external
+}
+
+/// Returns a pointer to data stored in a `napi_external` created by `create_tagged`,
+/// or an equivalent native module, if it is tagged with `tag`
+#[cfg(feature = "napi-8")]
+pub unsafe fn deref_tagged<T: 'static>(
+    env: Env,
+    local: Local,
+    tag: &super::TypeTag,
+) -> Option<*const T> {
+    let mut result = MaybeUninit::uninit();
+    napi::typeof_value(env, local, result.as_mut_ptr()).unwrap();
+
+    if result.assume_init() != napi::ValueType::External {
+        return None;
+    }
+
+    if !super::tag::check_object_type_tag(env, local, tag) {
+        return None;
+    }
+
+    let mut result = MaybeUninit::uninit();
+    napi::get_value_external(env, local, result.as_mut_ptr()).unwrap();
+
+    Some(result.assume_init().cast_const().cast())
+}
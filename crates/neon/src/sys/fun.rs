@@ -35,19 +35,11 @@ where
 
     #[cfg(feature = "napi-5")]
     {
-        unsafe extern "C" fn drop_function<F>(
-            _env: Env,
-            _finalize_data: *mut c_void,
-            finalize_hint: *mut c_void,
-        ) {
-            drop(Box::from_raw(finalize_hint.cast::<F>()));
-        }
-
         let status = napi::add_finalizer(
             env,
             out,
             ptr::null_mut(),
-            Some(drop_function::<F>),
+            Some(drop_boxed::<F>),
             data.cast(),
             ptr::null_mut(),
         );
@@ -61,9 +53,19 @@ where
     Ok(out)
 }
 
+#[cfg(feature = "napi-5")]
+// Finalizer that drops a boxed closure passed as the hint
+pub(super) unsafe extern "C" fn drop_boxed<F>(
+    _env: Env,
+    _finalize_data: *mut c_void,
+    finalize_hint: *mut c_void,
+) {
+    drop(Box::from_raw(finalize_hint.cast::<F>()));
+}
+
 // C ABI compatible function for invoking a boxed closure from the data field
-// of a Node-API JavaScript function
-unsafe extern "C" fn call_boxed<F>(env: Env, info: napi::CallbackInfo) -> Local
+// of a Node-API JavaScript function or accessor
+pub(super) unsafe extern "C" fn call_boxed<F>(env: Env, info: napi::CallbackInfo) -> Local
 where
     F: Fn(Env, napi::CallbackInfo) -> Local + 'static,
 {
@@ -8,12 +8,22 @@
 //!
 //! [napi-docs]: https://nodejs.org/api/n-api.html#n_api_environment_life_cycle_apis
 
-use std::{mem::MaybeUninit, os::raw::c_void, ptr};
+#[cfg(feature = "napi-6")]
+use std::{mem::MaybeUninit, ptr};
+use std::{
+    os::raw::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
 
-use super::{bindings as napi, raw::Env};
+use super::{
+    bindings as napi,
+    no_panic::{panic_msg, UNKNOWN_PANIC_MESSAGE},
+    raw::Env,
+};
 
 /// # Safety
 /// `env` must point to a valid `napi_env` for this thread
+#[cfg(feature = "napi-6")]
 pub unsafe fn set_instance_data<T: Send + 'static>(env: Env, data: T) -> *mut T {
     let data = Box::into_raw(Box::new(data));
 
@@ -27,6 +37,7 @@ pub unsafe fn set_instance_data<T: Send + 'static>(env: Env, data: T) -> *mut T
 /// * Caller must ensure reference does not outlive `Env`
 /// * Return value may be `null`
 /// * `env` must point to a valid `napi_env` for this thread
+#[cfg(feature = "napi-6")]
 pub unsafe fn get_instance_data<T: Send + 'static>(env: Env) -> *mut T {
     let mut data = MaybeUninit::uninit();
 
@@ -35,6 +46,55 @@ pub unsafe fn get_instance_data<T: Send + 'static>(env: Env) -> *mut T {
     data.assume_init().cast()
 }
 
+#[cfg(feature = "napi-6")]
 unsafe extern "C" fn drop_box<T>(_env: Env, data: *mut c_void, _hint: *mut c_void) {
     drop(Box::<T>::from_raw(data.cast()));
 }
+
+/// Handle to a hook registered with [`add_cleanup_hook`]
+#[cfg_attr(not(any(feature = "sys", feature = "napi-6")), allow(dead_code))]
+pub struct CleanupHook(*mut Box<dyn FnOnce()>);
+
+// Safety: The hook is only called or removed on the JavaScript thread
+unsafe impl Send for CleanupHook {}
+
+/// Registers `f` to be called when the environment is torn down
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread
+pub unsafe fn add_cleanup_hook<F: FnOnce() + 'static>(env: Env, f: F) -> CleanupHook {
+    // Node rejects a hook registered twice with the same data. Boxing a zero-sized
+    // closure does not allocate and would produce the same dangling pointer for every
+    // hook, so the closure is boxed as a trait object first.
+    let hook: Box<dyn FnOnce()> = Box::new(f);
+    let data = Box::into_raw(Box::new(hook));
+
+    napi::add_env_cleanup_hook(env, Some(call_cleanup_hook), data.cast()).unwrap();
+
+    CleanupHook(data)
+}
+
+#[cfg(any(feature = "sys", feature = "napi-6"))]
+/// Unregisters a hook without calling it, dropping its closure
+///
+/// # Safety
+/// * `env` must be the environment the hook was registered with and valid for this thread
+/// * The hook must not have been called
+pub unsafe fn remove_cleanup_hook(env: Env, hook: CleanupHook) {
+    let CleanupHook(data) = hook;
+
+    napi::remove_env_cleanup_hook(env, Some(call_cleanup_hook), data.cast()).unwrap();
+    drop(Box::from_raw(data));
+}
+
+unsafe extern "C" fn call_cleanup_hook(data: *mut c_void) {
+    let hook = Box::<Box<dyn FnOnce()>>::from_raw(data.cast());
+
+    // Unwinding into Node is undefined behavior and JavaScript can no longer run to
+    // receive an error, so a panic is reported and the remaining hooks still run
+    if let Err(panic) = catch_unwind(AssertUnwindSafe(hook)) {
+        let msg = panic_msg(&panic).unwrap_or(UNKNOWN_PANIC_MESSAGE);
+
+        eprintln!("Neon: exit hook panicked: {msg}");
+    }
+}
@@ -3,8 +3,51 @@ use super::{
     raw::{Env, Local},
 };
 
+/// Informs the engine of a change in externally allocated memory kept alive by
+/// JavaScript objects and returns the new total.
+pub unsafe fn adjust_external_memory(env: Env, change_in_bytes: i64) -> i64 {
+    let mut result = 0;
+    napi::adjust_external_memory(env, change_in_bytes, &mut result as *mut _).unwrap();
+    result
+}
+
 pub unsafe fn strict_equals(env: Env, lhs: Local, rhs: Local) -> bool {
     let mut result = false;
     napi::strict_equals(env, lhs, rhs, &mut result as *mut _).unwrap();
     result
 }
+
+/// Returns the numeric values of `lhs` and `rhs` if both are numbers.
+unsafe fn numbers(env: Env, lhs: Local, rhs: Local) -> Option<(f64, f64)> {
+    if super::tag::is_number(env, lhs) && super::tag::is_number(env, rhs) {
+        let lhs = super::primitive::number_value(env, lhs);
+        let rhs = super::primitive::number_value(env, rhs);
+
+        Some((lhs, rhs))
+    } else {
+        None
+    }
+}
+
+/// Compares with the [SameValue] algorithm used by `Object.is`. Differs from strict
+/// equality only in treating `NaN` as equal to itself and `+0` as distinct from `-0`.
+///
+/// [SameValue]: https://tc39.es/ecma262/#sec-samevalue
+pub unsafe fn same_value(env: Env, lhs: Local, rhs: Local) -> bool {
+    match numbers(env, lhs, rhs) {
+        Some((lhs, rhs)) if lhs.is_nan() || rhs.is_nan() => lhs.is_nan() && rhs.is_nan(),
+        Some((lhs, rhs)) => lhs == rhs && lhs.is_sign_negative() == rhs.is_sign_negative(),
+        None => strict_equals(env, lhs, rhs),
+    }
+}
+
+/// Compares with the [SameValueZero] algorithm used by `Array.prototype.includes`, `Map`
+/// and `Set`. Differs from strict equality only in treating `NaN` as equal to itself.
+///
+/// [SameValueZero]: https://tc39.es/ecma262/#sec-samevaluezero
+pub unsafe fn same_value_zero(env: Env, lhs: Local, rhs: Local) -> bool {
+    match numbers(env, lhs, rhs) {
+        Some((lhs, rhs)) => lhs == rhs || (lhs.is_nan() && rhs.is_nan()),
+        None => strict_equals(env, lhs, rhs),
+    }
+}
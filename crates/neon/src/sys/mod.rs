@@ -91,9 +91,12 @@ pub(crate) mod scope;
 pub(crate) mod string;
 pub(crate) mod tag;
 pub(crate) mod typedarray;
+pub(crate) mod version;
 
 pub mod bindings;
 
+#[cfg(feature = "napi-4")]
+pub(crate) mod async_context;
 #[cfg(feature = "napi-4")]
 pub(crate) mod tsfn;
 
@@ -101,7 +104,7 @@ pub(crate) mod tsfn;
 pub(crate) mod date;
 
 mod debug_send_wrapper;
-#[cfg(feature = "napi-6")]
+#[cfg(feature = "napi-3")]
 pub(crate) mod lifecycle;
 
 /// Create a JavaScript `String`, panicking if unsuccessful
@@ -25,7 +25,7 @@ use super::{
 
 type Panic = Box<dyn Any + Send + 'static>;
 
-const UNKNOWN_PANIC_MESSAGE: &str = "Unknown panic";
+pub(super) const UNKNOWN_PANIC_MESSAGE: &str = "Unknown panic";
 
 /// `FailureBoundary`] acts as boundary between Rust and FFI code, protecting
 /// a critical section of code from unhandled failure. It will catch both Rust
@@ -241,7 +241,7 @@ unsafe fn set_property(env: Env, object: Local, key: &str, value: Local) {
 }
 
 #[track_caller]
-unsafe fn panic_msg(panic: &Panic) -> Option<&str> {
+pub(super) unsafe fn panic_msg(panic: &Panic) -> Option<&str> {
     if let Some(msg) = panic.downcast_ref::<&str>() {
         Some(msg)
     } else if let Some(msg) = panic.downcast_ref::<String>() {
@@ -1,4 +1,4 @@
-use std::mem::MaybeUninit;
+use std::{mem::MaybeUninit, os::raw::c_char};
 
 use super::{
     bindings as napi,
@@ -29,13 +29,46 @@ pub unsafe fn seal(env: Env, obj: Local) -> Result<(), napi::Status> {
 /// Mutates the `out` argument to refer to a `napi_value` containing the own property names of the
 /// `object` as a JavaScript Array.
 pub unsafe fn get_own_property_names(out: &mut Local, env: Env, object: Local) -> bool {
+    get_property_names(
+        out,
+        env,
+        object,
+        napi::KeyCollectionMode::OwnOnly,
+        napi::KeyFilter::SKIP_SYMBOLS,
+    )
+}
+
+#[cfg(feature = "napi-6")]
+/// Mutates the `out` argument to refer to a `napi_value` containing the own enumerable property
+/// names of the `object` as a JavaScript Array, like `Object.keys`.
+pub unsafe fn get_own_enumerable_property_names(out: &mut Local, env: Env, object: Local) -> bool {
+    get_property_names(
+        out,
+        env,
+        object,
+        napi::KeyCollectionMode::OwnOnly,
+        napi::KeyFilter::ENUMERABLE | napi::KeyFilter::SKIP_SYMBOLS,
+    )
+}
+
+#[cfg(feature = "napi-6")]
+/// Mutates the `out` argument to refer to a `napi_value` containing the property keys of the
+/// `object` selected by `mode` and `filter` as a JavaScript Array. Numeric keys are converted
+/// to strings. Returns `false` if collecting the keys throws.
+pub unsafe fn get_property_names(
+    out: &mut Local,
+    env: Env,
+    object: Local,
+    mode: napi::KeyCollectionMode,
+    filter: napi::KeyFilter,
+) -> bool {
     let mut property_names = MaybeUninit::uninit();
 
     match napi::get_all_property_names(
         env,
         object,
-        napi::KeyCollectionMode::OwnOnly,
-        napi::KeyFilter::ALL_PROPERTIES | napi::KeyFilter::SKIP_SYMBOLS,
+        mode,
+        filter,
         napi::KeyConversion::NumbersToStrings,
         property_names.as_mut_ptr(),
     ) {
@@ -95,6 +128,19 @@ pub unsafe fn get_string(
     true
 }
 
+/// Mutates `out` to refer to the value at a NUL terminated `key` in the given `object`,
+/// without creating a string for the key. Returns `false` if the value couldn't be retrieved.
+pub unsafe fn get_named(env: Env, out: &mut Local, object: Local, key: *const c_char) -> bool {
+    match napi::get_named_property(env, object, key, out as *mut _) {
+        // A throwing getter is not always reported as `PendingException`
+        Err(_) if super::error::is_throwing(env) => false,
+        status => {
+            status.unwrap();
+            true
+        }
+    }
+}
+
 /// Sets the key value of a `napi_value` at a named key. Returns `true` if the set succeeded.
 ///
 /// The `out` parameter and the return value contain the same information for historical reasons,
@@ -152,3 +198,146 @@ pub unsafe fn set(out: &mut bool, env: Env, object: Local, key: Local, val: Loca
 
     *out
 }
+
+/// Mutates `out` to `true` if `object` has an own property named by the `key` value. Keys other
+/// than strings and symbols are converted to strings. Returns `false` if the check throws.
+pub unsafe fn has_own(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    match napi::has_own_property(env, object, key, out as *mut _) {
+        Err(napi::Status::NameExpected) => {}
+        Err(napi::Status::PendingException) => return false,
+        // Exceptions thrown by proxy traps are reported as a generic failure
+        Err(napi::Status::GenericFailure) if super::error::is_throwing(env) => return false,
+        status => {
+            status.unwrap();
+            return true;
+        }
+    }
+
+    let mut key_str = MaybeUninit::uninit();
+
+    match napi::coerce_to_string(env, key, key_str.as_mut_ptr()) {
+        Err(napi::Status::PendingException) => return false,
+        status => status.unwrap(),
+    }
+
+    has_own(out, env, object, key_str.assume_init())
+}
+
+/// Mutates `out` to `true` if `object` has an own property with the named `key`. Returns `false`
+/// if the check throws.
+pub unsafe fn has_own_string(
+    out: &mut bool,
+    env: Env,
+    object: Local,
+    key: *const u8,
+    len: i32,
+) -> bool {
+    let mut key_val = MaybeUninit::uninit();
+
+    match napi::create_string_utf8(env, key as *const _, len as usize, key_val.as_mut_ptr()) {
+        Err(napi::Status::PendingException) => return false,
+        status => status.unwrap(),
+    }
+
+    has_own(out, env, object, key_val.assume_init())
+}
+
+/// Deletes the property of `object` named by the `key` value. Mutates `out` to `false` if the
+/// property could not be deleted because it is not configurable. Returns `false` if deleting
+/// throws.
+pub unsafe fn delete(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    match napi::delete_property(env, object, key, out as *mut _) {
+        Err(napi::Status::PendingException) => false,
+        Err(napi::Status::GenericFailure) if super::error::is_throwing(env) => false,
+        status => {
+            status.unwrap();
+            true
+        }
+    }
+}
+
+/// Deletes the property of `object` with the named `key`. See [`delete`].
+pub unsafe fn delete_string(
+    out: &mut bool,
+    env: Env,
+    object: Local,
+    key: *const u8,
+    len: i32,
+) -> bool {
+    let mut key_val = MaybeUninit::uninit();
+
+    match napi::create_string_utf8(env, key as *const _, len as usize, key_val.as_mut_ptr()) {
+        Err(napi::Status::PendingException) => return false,
+        status => status.unwrap(),
+    }
+
+    delete(out, env, object, key_val.assume_init())
+}
+
+/// Deletes the element of `object` at `index`. See [`delete`].
+pub unsafe fn delete_index(out: &mut bool, env: Env, object: Local, index: u32) -> bool {
+    match napi::delete_element(env, object, index, out as *mut _) {
+        Err(napi::Status::PendingException) => false,
+        Err(napi::Status::GenericFailure) if super::error::is_throwing(env) => false,
+        status => {
+            status.unwrap();
+            true
+        }
+    }
+}
+
+#[cfg(feature = "napi-5")]
+/// Defines an enumerable and configurable accessor property named `key` on `object`,
+/// with `getter` called as its getter. `getter` is dropped when `object` is collected.
+pub unsafe fn define_getter<F>(
+    env: Env,
+    object: Local,
+    key: Local,
+    getter: F,
+) -> Result<(), napi::Status>
+where
+    F: Fn(Env, napi::CallbackInfo) -> Local + 'static,
+{
+    let data = Box::into_raw(Box::new(getter));
+    let descriptor = napi::PropertyDescriptor {
+        utf8name: std::ptr::null(),
+        name: key,
+        method: None,
+        getter: Some(super::fun::call_boxed::<F>),
+        setter: None,
+        value: std::ptr::null_mut(),
+        attributes: napi::PropertyAttributes(
+            napi::PropertyAttributes::ENUMERABLE.0 | napi::PropertyAttributes::CONFIGURABLE.0,
+        ),
+        data: data.cast(),
+    };
+
+    if let Err(status) = define_values(env, object, &[descriptor]) {
+        drop(Box::from_raw(data));
+
+        return Err(status);
+    }
+
+    napi::add_finalizer(
+        env,
+        object,
+        std::ptr::null_mut(),
+        Some(super::fun::drop_boxed::<F>),
+        data.cast(),
+        std::ptr::null_mut(),
+    )
+}
+
+/// Defines `properties` on `object` with a single call. Returns `PendingException` if
+/// defining a property throws and `InvalidArg` if a property cannot be defined, e.g.,
+/// because `object` is frozen.
+pub unsafe fn define_values(
+    env: Env,
+    object: Local,
+    properties: &[napi::PropertyDescriptor],
+) -> Result<(), napi::Status> {
+    match napi::define_properties(env, object, properties.len(), properties.as_ptr()) {
+        Err(_) if super::error::is_throwing(env) => Err(napi::Status::PendingException),
+        status => status,
+    }
+}
@@ -13,6 +13,23 @@ pub unsafe fn new(env: Env, value: Local) -> napi::Ref {
     result.assume_init()
 }
 
+/// Creates a reference that does not prevent `value` from being garbage collected
+#[cfg(feature = "napi-6")]
+pub unsafe fn new_weak(env: Env, value: Local) -> napi::Ref {
+    let mut result = MaybeUninit::uninit();
+
+    napi::create_reference(env, value, 0, result.as_mut_ptr()).unwrap();
+
+    result.assume_init()
+}
+
+/// # Safety
+/// Must only be used from the same module context that created the reference
+#[cfg(feature = "napi-6")]
+pub unsafe fn delete(env: Env, value: napi::Ref) {
+    napi::delete_reference(env, value).unwrap();
+}
+
 /// # Safety
 /// Must only be used from the same module context that created the reference
 pub unsafe fn reference(env: Env, value: napi::Ref) -> usize {
@@ -35,6 +52,8 @@ pub unsafe fn unreference(env: Env, value: napi::Ref) {
     }
 }
 
+/// Returns a null pointer if the value of a weak reference has been garbage collected
+///
 /// # Safety
 /// Must only be used from the same module context that created the reference
 pub unsafe fn get(env: Env, value: napi::Ref) -> Local {
@@ -1,3 +1,5 @@
+#[cfg(feature = "napi-experimental")]
+use std::ffi::c_void;
 use std::{mem::MaybeUninit, ptr};
 
 use super::{
@@ -11,6 +13,86 @@ pub unsafe fn new(out: &mut Local, env: Env, data: *const u8, len: i32) -> bool
     status.is_ok()
 }
 
+pub unsafe fn new_utf16(out: &mut Local, env: Env, data: *const u16, len: usize) -> bool {
+    let status = napi::create_string_utf16(env, data, len, out);
+
+    status.is_ok()
+}
+
+pub unsafe fn new_latin1(out: &mut Local, env: Env, data: *const u8, len: usize) -> bool {
+    let status = napi::create_string_latin1(env, data as *const _, len, out);
+
+    status.is_ok()
+}
+
+/// Creates a string that references `data` without copying. `data` is dropped when the
+/// string is garbage collected, or immediately if the engine copied it.
+#[cfg(feature = "napi-experimental")]
+pub unsafe fn new_external_latin1<T>(out: &mut Local, env: Env, data: T) -> bool
+where
+    T: AsRef<[u8]> + Send,
+{
+    // Safety: Boxing could move the data; must box before grabbing a raw pointer
+    let data = Box::new(data);
+    let buf = (*data).as_ref();
+    let (ptr, len) = (buf.as_ptr(), buf.len());
+    let data = Box::into_raw(data);
+    let mut copied = false;
+
+    let status = napi::create_external_string_latin1(
+        env,
+        ptr as *mut _,
+        len,
+        Some(drop_external::<T>),
+        data as *mut _,
+        out,
+        &mut copied,
+    );
+
+    // The finalizer is only called on success
+    if status.is_err() {
+        drop(Box::from_raw(data));
+    }
+
+    status.is_ok()
+}
+
+/// Creates a string that references UTF-16 `data` without copying.
+///
+/// **See also:** [`new_external_latin1`]
+#[cfg(feature = "napi-experimental")]
+pub unsafe fn new_external_utf16<T>(out: &mut Local, env: Env, data: T) -> bool
+where
+    T: AsRef<[u16]> + Send,
+{
+    let data = Box::new(data);
+    let buf = (*data).as_ref();
+    let (ptr, len) = (buf.as_ptr(), buf.len());
+    let data = Box::into_raw(data);
+    let mut copied = false;
+
+    let status = napi::create_external_string_utf16(
+        env,
+        ptr as *mut _,
+        len,
+        Some(drop_external::<T>),
+        data as *mut _,
+        out,
+        &mut copied,
+    );
+
+    if status.is_err() {
+        drop(Box::from_raw(data));
+    }
+
+    status.is_ok()
+}
+
+#[cfg(feature = "napi-experimental")]
+unsafe extern "C" fn drop_external<T>(_env: Env, _data: *mut c_void, hint: *mut c_void) {
+    drop(Box::<T>::from_raw(hint as *mut _));
+}
+
 pub unsafe fn utf8_len(env: Env, value: Local) -> usize {
     let mut len = MaybeUninit::uninit();
     napi::get_value_string_utf8(env, value, ptr::null_mut(), 0, len.as_mut_ptr()).unwrap();
@@ -41,6 +123,70 @@ pub unsafe fn data_utf16(env: Env, out: *mut u16, len: usize, value: Local) -> u
     read.assume_init()
 }
 
+pub unsafe fn latin1_len(env: Env, value: Local) -> usize {
+    let mut len = MaybeUninit::uninit();
+
+    napi::get_value_string_latin1(env, value, ptr::null_mut(), 0, len.as_mut_ptr()).unwrap();
+
+    len.assume_init()
+}
+
+pub unsafe fn data_latin1(env: Env, out: *mut u8, len: usize, value: Local) -> usize {
+    let mut read = MaybeUninit::uninit();
+
+    napi::get_value_string_latin1(env, value, out as *mut _, len, read.as_mut_ptr()).unwrap();
+
+    read.assume_init()
+}
+
+/// Calls the built-in `String.prototype` method `method` with `value` as the receiver,
+/// returning `StringExpected` if the result is not a string.
+pub unsafe fn call_method(
+    env: Env,
+    value: Local,
+    method: Local,
+    args: &[Local],
+) -> Result<Local, napi::Status> {
+    let mut out = MaybeUninit::uninit();
+
+    match napi::call_function(
+        env,
+        value,
+        method,
+        args.len(),
+        args.as_ptr(),
+        out.as_mut_ptr(),
+    ) {
+        Ok(()) => {}
+        Err(_) if super::error::is_throwing(env) => return Err(napi::Status::PendingException),
+        Err(status) => return Err(status),
+    }
+
+    let out = out.assume_init();
+
+    if !super::tag::is_string(env, out) {
+        return Err(napi::Status::StringExpected);
+    }
+
+    Ok(out)
+}
+
+/// Returns the arguments for calling `String.prototype.substring` with the UTF-16 code
+/// unit range `start..end`
+pub unsafe fn substring_args(
+    env: Env,
+    start: usize,
+    end: usize,
+) -> Result<[Local; 2], napi::Status> {
+    let mut start_val = MaybeUninit::uninit();
+    let mut end_val = MaybeUninit::uninit();
+
+    napi::create_double(env, start as f64, start_val.as_mut_ptr())?;
+    napi::create_double(env, end as f64, end_val.as_mut_ptr())?;
+
+    Ok([start_val.assume_init(), end_val.assume_init()])
+}
+
 pub unsafe fn run_script(out: &mut Local, env: Env, value: Local) -> bool {
     let status = napi::run_script(env, value, out as *mut _);
 
@@ -1,3 +1,6 @@
+#[cfg(feature = "napi-6")]
+use std::{mem::MaybeUninit, ptr};
+
 use super::{
     bindings as napi,
     raw::{Env, Local},
@@ -53,6 +56,42 @@ pub unsafe fn is_error(env: Env, val: Local) -> bool {
     result
 }
 
+/// Is `val` an instance of `constructor`, as if by the JavaScript `instanceof` operator?
+pub unsafe fn instance_of(env: Env, val: Local, constructor: Local) -> Result<bool, napi::Status> {
+    let mut result = false;
+
+    match napi::instanceof(env, val, constructor, &mut result as *mut _) {
+        Ok(()) => Ok(result),
+        // Errors thrown by `Symbol.hasInstance` or a non-function constructor are not
+        // always reported as `PendingException`
+        Err(_) if super::error::is_throwing(env) => Err(napi::Status::PendingException),
+        Err(status) => Err(status),
+    }
+}
+
+#[cfg(feature = "napi-6")]
+/// Does calling the built-in accessor `getter` with `val` as the receiver succeed?
+///
+/// Accessors such as `SharedArrayBuffer.prototype.byteLength` throw a `TypeError` for
+/// any receiver without the expected internal slots, which makes them an unforgeable
+/// brand check. The exception is cleared when the check fails. If an exception is
+/// already pending, it is left for the caller and the check fails.
+pub unsafe fn has_brand(env: Env, val: Local, getter: Local) -> bool {
+    if super::error::is_throwing(env) || !is_object(env, val) {
+        return false;
+    }
+
+    let mut result = MaybeUninit::uninit();
+
+    match napi::call_function(env, val, getter, 0, ptr::null(), result.as_mut_ptr()) {
+        Ok(()) => true,
+        Err(_) => {
+            super::error::clear_exception(env);
+            false
+        }
+    }
+}
+
 /// Is `val` a Node.js Buffer instance?
 pub unsafe fn is_buffer(env: Env, val: Local) -> bool {
     let mut result = false;
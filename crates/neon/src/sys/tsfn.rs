@@ -38,19 +38,26 @@ struct Callback<T> {
 }
 
 /// Error returned when scheduling a threadsafe function with some data
-pub struct CallError;
+pub enum CallError {
+    /// The queue is full and the call was non-blocking
+    QueueFull,
+    /// The threadsafe function is closing or has been finalized
+    Closing,
+}
 
 impl<T: Send + 'static> ThreadsafeFunction<T> {
     /// Creates a new unbounded N-API Threadsafe Function
     /// Safety: `Env` must be valid for the current thread
-    pub unsafe fn new(env: Env, callback: fn(Option<Env>, T)) -> Self {
-        Self::with_capacity(env, 0, callback)
+    #[cfg(feature = "napi-6")]
+    pub unsafe fn new(env: Env, name: &str, callback: fn(Option<Env>, T)) -> Self {
+        Self::with_capacity(env, name, 0, callback)
     }
 
-    /// Creates a bounded N-API Threadsafe Function
+    /// Creates a bounded N-API Threadsafe Function with an async resource named `name`
     /// Safety: `Env` must be valid for the current thread
     pub unsafe fn with_capacity(
         env: Env,
+        name: &str,
         max_queue_size: usize,
         callback: fn(Option<Env>, T),
     ) -> Self {
@@ -62,7 +69,7 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
                 env,
                 std::ptr::null_mut(),
                 std::ptr::null_mut(),
-                super::string(env, "neon threadsafe function"),
+                super::string(env, name),
                 max_queue_size,
                 // Always set the reference count to 1. Prefer using
                 // Rust `Arc` to maintain the struct.
@@ -121,7 +128,11 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
                 // If the call failed, the callback won't execute
                 let _ = unsafe { Box::from_raw(callback) };
 
-                Err(CallError)
+                if status == napi::Status::QueueFull {
+                    Err(CallError::QueueFull)
+                } else {
+                    Err(CallError::Closing)
+                }
             }
         }
     }
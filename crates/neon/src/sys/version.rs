@@ -0,0 +1,31 @@
+use std::{ffi::CStr, mem::MaybeUninit};
+
+use super::{bindings as napi, raw::Env};
+
+/// Returns the major, minor, and patch version and the release name of Node.
+///
+/// # Safety
+/// `env` must be a valid `napi_env` for the current thread
+pub unsafe fn node_version(env: Env) -> (u32, u32, u32, &'static str) {
+    let mut version = MaybeUninit::uninit();
+
+    napi::get_node_version(env, version.as_mut_ptr()).unwrap();
+
+    // The version is statically allocated by Node and is never freed
+    let version = &*version.assume_init();
+    let release = CStr::from_ptr(version.release).to_str().unwrap_or_default();
+
+    (version.major, version.minor, version.patch, release)
+}
+
+/// Returns the highest Node-API version supported by the runtime.
+///
+/// # Safety
+/// `env` must be a valid `napi_env` for the current thread
+pub unsafe fn napi_version(env: Env) -> u32 {
+    let mut version = 0;
+
+    napi::get_version(env, &mut version).unwrap();
+
+    version
+}
@@ -0,0 +1,247 @@
+//! Benchmarks of the cost of crossing between JavaScript and Rust.
+//!
+//! * [`export`] exports standard benchmark routines, e.g., for argument conversion,
+//!   property access and buffer borrows. Each one exercises a single kind of crossing
+//!   and otherwise does as little as possible.
+//! * [`driver`] generates a JavaScript file that measures them with
+//!   [Criterion](https://docs.rs/criterion)'s linear sampling and reports estimates in
+//!   the same format. Estimates can be saved with `--save-baseline <file>` and compared
+//!   with `--baseline <file>`, which fails the run on a regression.
+//! * [`iter_custom`] times a closure in Rust, so that routines specific to an addon can
+//!   be measured by the same driver.
+//!
+//! # Example
+//!
+//! ```
+//! # use neon::prelude::*;
+//! # fn main() {
+//! #[neon::main]
+//! fn main(mut cx: ModuleContext) -> NeonResult<()> {
+//!     neon::testing::bench::export(&mut cx)
+//! }
+//! # }
+//! ```
+//!
+//! After building the module as `index.node`, write a driver and run it with Node:
+//!
+//! ```no_run
+//! neon::testing::bench::write_driver("bench/boundary.js", "../index.node").unwrap();
+//! ```
+//!
+//! ```sh
+//! node bench/boundary.js --save-baseline main.json
+//! node bench/boundary.js --baseline main.json
+//! ```
+//!
+//! The driver also exports the harness, so that other routines can be measured with it:
+//!
+//! ```js
+//! const { iter, run } = require("./boundary.js");
+//!
+//! run([["parse", iter(() => addon.parse("{}"))]]);
+//! ```
+
+use std::{fs, io, path::Path, time::Instant};
+
+use crate::{
+    context::{Context, FunctionContext, ModuleContext},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    types::{
+        buffer::TypedArray, extract::Buffer, JsBuffer, JsFunction, JsNumber, JsObject, JsUndefined,
+    },
+};
+
+#[cfg(feature = "napi-4")]
+use crate::types::JsPromise;
+
+/// Name of the export containing the benchmark routines.
+pub const EXPORT_NAME: &str = "__neon_bench";
+
+// Criterion style sampling and reporting, shared by every driver
+const HARNESS: &str = include_str!("harness.js");
+
+/// Exports the standard benchmark routines as an object of functions named
+/// [`EXPORT_NAME`].
+pub fn export(cx: &mut ModuleContext) -> NeonResult<()> {
+    let bench = cx.empty_object();
+
+    set(cx, bench, "args", args)?;
+    set(cx, bench, "getProp", get_prop)?;
+    set(cx, bench, "setProp", set_prop)?;
+    set(cx, bench, "bufferBorrow", buffer_borrow)?;
+    set(cx, bench, "bufferCopy", buffer_copy)?;
+    #[cfg(feature = "napi-4")]
+    set(cx, bench, "channelRoundtrip", channel_roundtrip)?;
+    set(cx, bench, "callJs", call_js)?;
+    set(cx, bench, "callJsArgs", call_js_args)?;
+
+    cx.export_value(EXPORT_NAME, bench)
+}
+
+fn set<'cx, V: crate::types::Value>(
+    cx: &mut ModuleContext<'cx>,
+    bench: Handle<'cx, JsObject>,
+    name: &str,
+    f: fn(FunctionContext) -> JsResult<V>,
+) -> NeonResult<()> {
+    let f = JsFunction::new(cx, f)?;
+
+    bench.prop(cx, name).set(f)?;
+
+    Ok(())
+}
+
+/// Returns the source of a JavaScript file that measures each exported routine.
+///
+/// `addon` is the path to the module as passed to `require`, relative to the driver.
+/// The routines are only measured when the file is run directly. When it is required,
+/// it exports the harness functions `iter`, `iterAsync` and `run`.
+pub fn driver(addon: &str) -> String {
+    // Rust string escapes are a subset of JavaScript string escapes
+    format!(
+        r#"// Generated by `neon::testing::bench::driver`
+//
+// Usage: node <driver> [--save-baseline <file>] [--baseline <file>]
+
+{HARNESS}
+if (require.main === module) {{
+  const bench = require({addon:?}).{EXPORT_NAME};
+  const obj = {{ value: 1 }};
+  const buf = Buffer.alloc(1024, 1);
+
+  run([
+    ["noop", iter(() => bench.args(1, "", false))],
+    ["argument conversion", iter(() => bench.args(1, "hello", true))],
+    ["property get", iter(() => bench.getProp(obj))],
+    ["property set", iter(() => bench.setProp(obj, 2))],
+    ["buffer borrow", iter(() => bench.bufferBorrow(buf))],
+    ["buffer copy", iter(() => bench.bufferCopy(buf))],
+    [
+      "channel roundtrip",
+      bench.channelRoundtrip && iterAsync(() => bench.channelRoundtrip()),
+    ],
+    ["call js from rust", (iters) => bench.callJs(() => {{}}, iters)],
+    ["call js, 3 args", (iters) => bench.callJsArgs(() => {{}}, iters, 3)],
+    ["call js, 9 args", (iters) => bench.callJsArgs(() => {{}}, iters, 9)],
+  ].filter(([, routine]) => routine)).catch((err) => {{
+    console.error(err);
+    process.exitCode = 1;
+  }});
+}}
+"#
+    )
+}
+
+/// Writes the output of [`driver`] to `path`.
+pub fn write_driver(path: impl AsRef<Path>, addon: &str) -> io::Result<()> {
+    fs::write(path, driver(addon))
+}
+
+/// Calls `f` `iters` times and returns the elapsed time in nanoseconds.
+///
+/// This follows the contract of Criterion's `iter_custom`, so a function that returns
+/// the result to JavaScript can be measured by the harness of a [`driver`].
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::testing::bench;
+///
+/// // Measures calling `f` from Rust
+/// fn call(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let (f, iters): (Handle<JsFunction>, f64) = cx.args()?;
+///     let elapsed = bench::iter_custom(iters as u64, || f.bind(&mut cx).exec())?;
+///
+///     Ok(cx.number(elapsed))
+/// }
+/// ```
+pub fn iter_custom<F>(iters: u64, mut f: F) -> NeonResult<f64>
+where
+    F: FnMut() -> NeonResult<()>,
+{
+    let start = Instant::now();
+
+    for _ in 0..iters {
+        f()?;
+    }
+
+    Ok(start.elapsed().as_nanos() as f64)
+}
+
+// Argument conversion
+fn args(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let (a, b, c): (f64, String, bool) = cx.args()?;
+    let n = if c { a + b.len() as f64 } else { a };
+
+    Ok(cx.number(n))
+}
+
+// Property access
+fn get_prop(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    obj.prop(&mut cx, "value").get()
+}
+
+fn set_prop(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let (obj, value): (Handle<JsObject>, f64) = cx.args()?;
+
+    obj.prop(&mut cx, "value").set(value)?;
+
+    Ok(cx.undefined())
+}
+
+// Buffer borrow
+fn buffer_borrow(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let buf = cx.argument::<JsBuffer>(0)?;
+    let sum: f64 = buf.as_slice(&cx).iter().map(|&b| b as f64).sum();
+
+    Ok(cx.number(sum))
+}
+
+// Buffer copy, for comparison with a borrow
+fn buffer_copy(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let (Buffer(buf),): (Buffer<Vec<u8>>,) = cx.args()?;
+    let sum: f64 = buf.iter().map(|&b| b as f64).sum();
+
+    Ok(cx.number(sum))
+}
+
+#[cfg(feature = "napi-4")]
+// Channel roundtrip; resolves once the closure has been scheduled, queued and executed
+fn channel_roundtrip(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    deferred.settle_with(&channel, |mut cx| Ok(cx.undefined()));
+
+    Ok(promise)
+}
+
+// Calls `f` `iters` times from Rust. Timing happens in Rust.
+fn call_js(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let (f, iters): (Handle<JsFunction>, f64) = cx.args()?;
+    let elapsed = iter_custom(iters as u64, || f.bind(&mut cx).exec())?;
+
+    Ok(cx.number(elapsed))
+}
+
+// Calls `f` with `argc` arguments per call, like a per-row callback. Up to 8 arguments
+// are kept on the stack; more spill to the heap.
+fn call_js_args(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let (f, iters, argc): (Handle<JsFunction>, f64, f64) = cx.args()?;
+    let mut row = 0;
+    let elapsed = iter_custom(iters as u64, || {
+        let mut call = f.bind(&mut cx);
+
+        for i in 0..argc as u64 {
+            call.arg((row + i) as f64)?;
+        }
+
+        row += 1;
+        call.exec()
+    })?;
+
+    Ok(cx.number(elapsed))
+}
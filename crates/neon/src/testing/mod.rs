@@ -0,0 +1,202 @@
+//! Helpers for end-to-end testing of a Neon module's exports.
+//!
+//! Unit tests of Rust code cannot exercise Neon APIs, since they require a running
+//! JavaScript engine. This module provides the pieces needed to run tests inside of
+//! Node instead:
+//!
+//! * The [`test`] attribute registers a function as a test.
+//! * [`export`] exports the registered tests from the module. Modules without a
+//!   [`neon::main`](crate::main) function export them automatically.
+//! * [`driver`] generates a JavaScript file that runs each test with the built-in
+//!   [`node:test`](https://nodejs.org/api/test.html) runner.
+//! * Assertion helpers, such as [`assert_strict_eq`], throw a JavaScript
+//!   `AssertionError` on failure.
+//! * The [`bench`] module measures the cost of crossing between JavaScript and Rust.
+//!
+//! Since tests are compiled into the module, they are typically placed behind a
+//! feature of the addon crate that enables `neon/testing`.
+//!
+//! # Example
+//!
+//! ```
+//! # use neon::prelude::*;
+//! use neon::testing;
+//!
+//! #[neon::export]
+//! fn add(a: f64, b: f64) -> f64 {
+//!     a + b
+//! }
+//!
+//! #[testing::test]
+//! fn adds_numbers(cx: &mut Cx) -> NeonResult<()> {
+//!     let sum = cx.number(add(1.0, 2.0));
+//!
+//!     testing::assert_strict_eq(cx, sum, 3.0)
+//! }
+//! ```
+//!
+//! After building the module as `index.node`, write a driver and run it with Node:
+//!
+//! ```no_run
+//! neon::testing::write_driver("test/exports.test.js", "../index.node").unwrap();
+//! ```
+//!
+//! ```sh
+//! node --test test/
+//! ```
+
+pub mod bench;
+
+use std::{fs, io, path::Path};
+
+use crate::{
+    context::{Context, Cx, ModuleContext},
+    handle::Handle,
+    object::Object,
+    result::NeonResult,
+    types::{extract::TryIntoJs, JsString, JsValue, Value},
+};
+
+/// Registers a function as a test of the module.
+///
+/// The function takes a [`Cx`] and returns a [`NeonResult<()>`](NeonResult). The
+/// test fails if the function throws.
+///
+/// ```
+/// # use neon::prelude::*;
+/// #[neon::testing::test]
+/// fn creates_empty_arrays(cx: &mut Cx) -> NeonResult<()> {
+///     let len = cx.empty_array().len(cx);
+///
+///     neon::testing::assert(cx, len == 0, "expected an empty array")
+/// }
+/// ```
+pub use neon_macros::test;
+
+/// Name of the export containing the registered tests.
+pub const EXPORT_NAME: &str = "__neon_tests";
+
+/// Exports the registered tests as an object of functions named [`EXPORT_NAME`].
+///
+/// This only needs to be called from a [`neon::main`](crate::main) function; modules
+/// without one export their tests automatically.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn main() {
+/// #[neon::main]
+/// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+///     neon::registered().export(&mut cx)?;
+///
+///     #[cfg(feature = "testing")]
+///     neon::testing::export(&mut cx)?;
+///
+///     Ok(())
+/// }
+/// # }
+/// ```
+pub fn export(cx: &mut ModuleContext) -> NeonResult<()> {
+    let tests = cx.empty_object();
+
+    for create in crate::macro_internal::TESTS {
+        let (name, f) = create(cx)?;
+
+        tests.set(cx, name, f)?;
+    }
+
+    cx.export_value(EXPORT_NAME, tests)
+}
+
+/// Returns the source of a JavaScript file that runs every exported test with the
+/// [`node:test`](https://nodejs.org/api/test.html) runner.
+///
+/// `addon` is the path to the module as passed to `require`, relative to the driver.
+pub fn driver(addon: &str) -> String {
+    // Rust string escapes are a subset of JavaScript string escapes
+    format!(
+        r#"// Generated by `neon::testing::driver`
+"use strict";
+
+const {{ test }} = require("node:test");
+const tests = require({addon:?}).{EXPORT_NAME};
+
+for (const [name, f] of Object.entries(tests)) {{
+  test(name, () => f());
+}}
+"#
+    )
+}
+
+/// Writes the output of [`driver`] to `path`.
+pub fn write_driver(path: impl AsRef<Path>, addon: &str) -> io::Result<()> {
+    fs::write(path, driver(addon))
+}
+
+/// Throws an `AssertionError` with `msg` unless `cond` is `true`.
+pub fn assert<'cx, C: Context<'cx>>(cx: &mut C, cond: bool, msg: &str) -> NeonResult<()> {
+    if cond {
+        return Ok(());
+    }
+
+    throw_assertion(cx.cx_mut(), msg)
+}
+
+/// Throws an `AssertionError` unless `actual` is strictly equal (`===`) to `expected`.
+pub fn assert_strict_eq<'cx, C, V, T>(cx: &mut C, actual: Handle<V>, expected: T) -> NeonResult<()>
+where
+    C: Context<'cx>,
+    V: Value,
+    T: TryIntoJs<'cx>,
+{
+    let cx = cx.cx_mut();
+    let expected = expected.try_into_js(cx)?;
+
+    if actual.strict_equals(cx, expected) {
+        return Ok(());
+    }
+
+    let actual = describe(cx, actual.upcast());
+    let expected = describe(cx, expected.upcast());
+
+    throw_assertion(
+        cx,
+        &format!("Expected values to be strictly equal:\n\n{actual} !== {expected}\n"),
+    )
+}
+
+/// Calls `f` and returns the exception it throws, or throws an `AssertionError` if
+/// it does not throw.
+pub fn assert_throws<'cx, C, T, F>(cx: &mut C, f: F) -> NeonResult<Handle<'cx, JsValue>>
+where
+    C: Context<'cx>,
+    F: FnOnce(&mut C) -> NeonResult<T>,
+{
+    match cx.try_catch(f) {
+        Ok(_) => throw_assertion(cx.cx_mut(), "Missing expected exception."),
+        Err(err) => Ok(err),
+    }
+}
+
+// Best effort description of a value for an assertion message
+fn describe(cx: &mut Cx, v: Handle<JsValue>) -> String {
+    let description = cx.try_catch(|cx| {
+        let s = v.to_string(cx)?.value(cx);
+
+        Ok(if v.is_a::<JsString, _>(cx) {
+            format!("{s:?}")
+        } else {
+            s
+        })
+    });
+
+    description.unwrap_or_else(|_| String::from("<value>"))
+}
+
+fn throw_assertion<T>(cx: &mut Cx, msg: &str) -> NeonResult<T> {
+    let err = cx.error(msg)?;
+
+    err.prop(cx, "name").set("AssertionError")?;
+    err.prop(cx, "code").set("ERR_ASSERTION")?;
+
+    cx.throw(err)
+}
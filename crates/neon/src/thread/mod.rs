@@ -110,6 +110,39 @@ use once_cell::sync::OnceCell;
 use crate::context::Context;
 use crate::lifecycle::LocalCell;
 
+pub use self::{
+    spawn::{spawn, JoinHandle},
+    store::InstanceStore,
+    worker::Worker,
+};
+
+mod spawn;
+mod store;
+mod worker;
+
+/// Returns `true` if the current thread is a JavaScript thread that has loaded this
+/// module, i.e., the main thread or a [worker](https://nodejs.org/api/worker_threads.html)
+/// that required the addon.
+///
+/// Values tied to a JavaScript thread, such as a [`Handle`](crate::handle::Handle) or
+/// a context, may only be used on that thread. Their types are not `Send`, but `unsafe`
+/// code can still move them to another thread, with undefined behavior.
+pub fn is_js_thread() -> bool {
+    crate::context::internal::is_js_thread()
+}
+
+/// Panics if the current thread is not a JavaScript thread.
+///
+/// This is useful for checking code that is only correct on a JavaScript thread, such
+/// as code called from C with a pointer to Neon data. In debug builds, Neon performs
+/// the same check whenever a context is used.
+///
+/// **See also:** [`is_js_thread`]
+#[track_caller]
+pub fn assert_js_thread() {
+    crate::context::internal::assert_js_thread()
+}
+
 static COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 fn next_id() -> usize {
@@ -0,0 +1,186 @@
+use std::{
+    mem,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use crate::{
+    context::Context,
+    event::CancellationToken,
+    sys::{self, lifecycle::CleanupHook},
+};
+
+/// Spawns a Rust thread that is stopped and joined when the JavaScript thread exits.
+///
+/// The closure is passed a [`CancellationToken`] that is cancelled when the environment
+/// is torn down, i.e. when a worker thread terminates or the main thread exits. Neon
+/// then waits for the thread to return before the module is unloaded, so a thread
+/// cannot outlive the environment that spawned it.
+///
+/// A thread that returns earlier is joined on the JavaScript thread, which then stops
+/// tracking it.
+///
+/// The thread should check the token regularly and return soon after it is cancelled.
+/// After the token is cancelled, the JavaScript thread is blocked waiting for it and
+/// cannot run closures sent on a [`Channel`](crate::event::Channel). The thread must not
+/// wait on their results, e.g., with [`JoinHandle::join`](crate::event::JoinHandle::join),
+/// or the environment will never exit.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::time::Duration;
+///
+/// fn start_heartbeat(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+///     let callback = std::sync::Arc::new(callback);
+///     let channel = cx.channel();
+///
+///     neon::thread::spawn(&mut cx, move |token| {
+///         while !token.is_cancelled() {
+///             let callback = callback.clone();
+///
+///             // `try_send` fails instead of panicking if the environment is closing
+///             let _ = channel.try_send(move |mut cx| {
+///                 callback.to_inner(&mut cx).bind(&mut cx).exec()
+///             });
+///
+///             std::thread::sleep(Duration::from_millis(100));
+///         }
+///     });
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+pub fn spawn<'cx, C, F, T>(cx: &mut C, f: F) -> JoinHandle<T>
+where
+    C: Context<'cx>,
+    F: FnOnce(CancellationToken) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let token = CancellationToken::new();
+    let shared = Arc::new(Shared {
+        // Replaced with the thread before this function returns to JavaScript
+        state: Mutex::new(State::Taken),
+        hook: Mutex::new(None),
+    });
+
+    // Unreferenced, so that a running thread does not keep the event loop alive
+    let mut channel = cx.channel();
+
+    channel.unref(cx);
+
+    let thread = thread::spawn({
+        let token = token.clone();
+        let shared = shared.clone();
+
+        move || {
+            let result = f(token);
+
+            // Fails if the environment is closing, in which case the exit hook joins it
+            let _ = channel.try_send(move |mut cx| {
+                shared.finish(&mut cx);
+                Ok(())
+            });
+
+            result
+        }
+    });
+
+    *shared.state.lock().unwrap() = State::Running(thread);
+
+    let hook = unsafe {
+        sys::lifecycle::add_cleanup_hook(cx.env().to_raw(), {
+            let token = token.clone();
+            let shared = shared.clone();
+
+            move || {
+                // The hook is being called and can no longer be removed
+                shared.hook.lock().unwrap().take();
+                token.cancel();
+                State::wait(&shared.state);
+            }
+        })
+    };
+
+    *shared.hook.lock().unwrap() = Some(hook);
+
+    JoinHandle { token, shared }
+}
+
+/// An owned permission to stop and join a thread spawned with [`spawn`].
+///
+/// Dropping the handle does not detach the thread; it is still stopped and joined when
+/// the JavaScript thread exits.
+pub struct JoinHandle<T> {
+    token: CancellationToken,
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Cancels the token passed to the thread, requesting that it stop. _Idempotent_
+    pub fn stop(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns `true` if the thread has finished running.
+    pub fn is_finished(&self) -> bool {
+        match &*self.shared.state.lock().unwrap() {
+            State::Running(thread) => thread.is_finished(),
+            _ => true,
+        }
+    }
+
+    /// Waits for the thread to finish, returning its result.
+    ///
+    /// If the thread panicked, `Err` is returned with the panic payload, the same as
+    /// [`std::thread::JoinHandle::join`]. This does not stop the thread; call
+    /// [`JoinHandle::stop`] first if it only returns once cancelled.
+    pub fn join(self) -> thread::Result<T> {
+        State::wait(&self.shared.state);
+
+        match mem::replace(&mut *self.shared.state.lock().unwrap(), State::Taken) {
+            State::Finished(result) => result,
+            _ => unreachable!("thread was joined"),
+        }
+    }
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    // Exit hook that joins the thread, until it is called or removed
+    hook: Mutex<Option<CleanupHook>>,
+}
+
+impl<T> Shared<T> {
+    // Joins the finished thread and removes the exit hook
+    fn finish<'cx, C: Context<'cx>>(&self, cx: &mut C) {
+        State::wait(&self.state);
+
+        if let Some(hook) = self.hook.lock().unwrap().take() {
+            unsafe { sys::lifecycle::remove_cleanup_hook(cx.env().to_raw(), hook) };
+        }
+    }
+}
+
+enum State<T> {
+    Running(thread::JoinHandle<T>),
+    Finished(thread::Result<T>),
+    Taken,
+}
+
+impl<T> State<T> {
+    // Joins the thread if it has not already been joined, keeping the result
+    fn wait(state: &Mutex<Self>) {
+        let mut state = state.lock().unwrap();
+
+        if !matches!(*state, State::Running(_)) {
+            return;
+        }
+
+        if let State::Running(thread) = mem::replace(&mut *state, State::Taken) {
+            *state = State::Finished(thread.join());
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    context::Cx,
+    handle::{Handle, Root},
+    intrinsics,
+    object::Object,
+    result::{JsResult, NeonResult},
+    types::{
+        extract::{TryFromJs, TryIntoJs},
+        JsObject,
+    },
+};
+
+use super::LocalKey;
+
+static STORE: LocalKey<Root<JsObject>> = LocalKey::new();
+
+/// A key-value store shared by all of the Rust and JavaScript code in a single
+/// instance of the module.
+///
+/// The store is backed by a JavaScript [`Map`][map] that is created on first use and
+/// lives as long as the module instance. Values are converted with
+/// [`TryIntoJs`] and [`TryFromJs`], so the store can hold any JavaScript value as
+/// well as Rust data wrapped in [`Boxed`](crate::types::extract::Boxed).
+///
+/// Since every instance of the module has its own store, it is a safe replacement
+/// for addon-wide state kept in JavaScript globals. The backing `Map` can be
+/// exposed to JavaScript with [`ModuleContext::export_instance_store`](crate::context::ModuleContext::export_instance_store).
+///
+/// Obtained with [`Context::instance_store`].
+///
+/// ```
+/// # use neon::prelude::*;
+/// #[neon::export]
+/// fn count(cx: &mut Cx) -> NeonResult<f64> {
+///     let mut store = cx.instance_store()?;
+///     let n = store.get::<f64>("count")?.unwrap_or(0.0) + 1.0;
+///
+///     store.set("count", n)?;
+///
+///     Ok(n)
+/// }
+/// ```
+///
+/// [map]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map
+pub struct InstanceStore<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    map: Handle<'cx, JsObject>,
+}
+
+impl<'a, 'cx> InstanceStore<'a, 'cx> {
+    pub(crate) fn new(cx: &'a mut Cx<'cx>) -> NeonResult<Self> {
+        let map = STORE
+            .get_or_try_init(cx, |cx| create_map(cx).map(|map| map.root(cx)))?
+            .to_inner(cx);
+
+        Ok(Self { cx, map })
+    }
+
+    /// Gets the value stored at `key`, or `None` if there is no value.
+    ///
+    /// Throws a `TypeError` if the value cannot be converted to `T`. Since a missing
+    /// key reads as `undefined`, storing `undefined` is equivalent to deleting the key.
+    pub fn get<T: TryFromJs<'cx>>(&mut self, key: &str) -> NeonResult<Option<T>> {
+        intrinsics::map_get(self.cx)?
+            .bind(self.cx)
+            .this(self.map)?
+            .arg(key)?
+            .call()
+    }
+
+    /// Stores `value` at `key`, replacing any previous value.
+    pub fn set<V: TryIntoJs<'cx>>(&mut self, key: &str, value: V) -> NeonResult<()> {
+        intrinsics::map_set(self.cx)?
+            .bind(self.cx)
+            .this(self.map)?
+            .arg(key)?
+            .arg(value)?
+            .exec()
+    }
+
+    /// Returns `true` if a value is stored at `key`.
+    pub fn has(&mut self, key: &str) -> NeonResult<bool> {
+        intrinsics::map_has(self.cx)?
+            .bind(self.cx)
+            .this(self.map)?
+            .arg(key)?
+            .call()
+    }
+
+    /// Removes the value stored at `key`. Returns `true` if a value was removed.
+    pub fn delete(&mut self, key: &str) -> NeonResult<bool> {
+        intrinsics::map_delete(self.cx)?
+            .bind(self.cx)
+            .this(self.map)?
+            .arg(key)?
+            .call()
+    }
+
+    /// Returns the JavaScript `Map` backing the store.
+    pub fn as_map(&self) -> Handle<'cx, JsObject> {
+        self.map
+    }
+}
+
+fn create_map<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsObject> {
+    intrinsics::map(cx)?.bind(cx).construct()
+}
@@ -0,0 +1,220 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{mpsc, Arc},
+};
+
+use crate::{
+    context::{Context, Cx},
+    event::{Channel, JoinHandle, SendError},
+    handle::{Handle, Root},
+    object::Object,
+    result::{JsResult, NeonResult},
+    types::{
+        extract::{TryFromJs, TryIntoJs},
+        JsFunction, JsObject, JsPromise, JsUndefined, JsValue,
+    },
+};
+
+/// A Node [worker thread](https://nodejs.org/api/worker_threads.html) started from Rust.
+///
+/// Messages are sent to the worker with [`Worker::post_message`] on the JavaScript
+/// thread, or with [`Worker::send`] from any thread. Messages posted by the worker are
+/// received from a [`Receiver`](mpsc::Receiver) returned by [`Worker::messages`], which
+/// may be moved to another thread. Both directions convert messages with
+/// [`TryIntoJs`] and [`TryFromJs`].
+///
+/// Inside the worker, messages are exchanged with
+/// [`parentPort`](https://nodejs.org/api/worker_threads.html#workerparentport).
+///
+/// Starting a worker loads `node:worker_threads` with [`Context::require`], which
+/// requires `process.getBuiltinModule`, available since Node 20.16 and 22.3.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::thread::Worker;
+///
+/// const DOUBLE: &str = r#"
+///     const { parentPort } = require("node:worker_threads");
+///
+///     parentPort.on("message", (n) => parentPort.postMessage(n * 2));
+/// "#;
+///
+/// fn double_in_worker(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+///     let worker = Worker::eval(&mut cx, DOUBLE)?;
+///     let messages = worker.messages::<f64>(&mut cx)?;
+///     let channel = cx.channel();
+///     let (deferred, promise) = cx.promise();
+///
+///     std::thread::spawn(move || {
+///         worker.send(n);
+///
+///         let doubled = messages.recv().ok();
+///
+///         channel.settle_with(deferred, move |cx| {
+///             let _ = worker.terminate(cx);
+///
+///             doubled
+///         });
+///     });
+///
+///     Ok(promise)
+/// }
+/// ```
+pub struct Worker {
+    worker: Arc<Root<JsObject>>,
+    channel: Channel,
+}
+
+impl Worker {
+    /// Starts a worker running the script at `filename`, an absolute path or a
+    /// `file:` or `data:` URL.
+    ///
+    /// A URL is passed to the `Worker` constructor as a `URL` object, since Node
+    /// only accepts paths as strings. Code in a `data:` URL is loaded as an ES
+    /// module.
+    pub fn new<'cx>(cx: &mut Cx<'cx>, filename: &str) -> NeonResult<Self> {
+        Self::start(cx, filename, false)
+    }
+
+    /// Starts a worker evaluating `code` as a CommonJS script.
+    pub fn eval<'cx>(cx: &mut Cx<'cx>, code: &str) -> NeonResult<Self> {
+        Self::start(cx, code, true)
+    }
+
+    fn start<'cx>(cx: &mut Cx<'cx>, source: &str, eval: bool) -> NeonResult<Self> {
+        let worker_threads: Handle<JsObject> = cx.require("node:worker_threads")?;
+        let constructor: Handle<JsFunction> = worker_threads.prop(cx, "Worker").get()?;
+        let source = if !eval && is_url(source) {
+            let source = cx.string(source);
+
+            crate::intrinsics::url(cx)?
+                .bind(cx)
+                .arg(source)?
+                .construct::<Handle<JsValue>>()?
+        } else {
+            cx.string(source).upcast()
+        };
+        let options = cx.empty_object();
+
+        options.prop(cx, "eval").set(eval)?;
+
+        let worker: Handle<JsObject> = constructor.bind(cx).args((source, options))?.construct()?;
+        let mut channel = cx.channel();
+
+        // The worker keeps the event loop alive while it is running
+        channel.unref(cx);
+
+        Ok(Self {
+            worker: Arc::new(worker.root(cx)),
+            channel,
+        })
+    }
+
+    /// Returns the JavaScript `Worker` object, e.g., to listen for `error` events.
+    pub fn to_inner<'cx>(&self, cx: &mut Cx<'cx>) -> Handle<'cx, JsObject> {
+        self.worker.to_inner(cx)
+    }
+
+    /// Posts a message to the worker.
+    pub fn post_message<'cx, V>(&self, cx: &mut Cx<'cx>, message: V) -> NeonResult<()>
+    where
+        V: TryIntoJs<'cx>,
+    {
+        let message = message.try_into_js(cx)?;
+
+        self.to_inner(cx)
+            .method(cx, "postMessage")?
+            .arg(message)?
+            .exec()
+    }
+
+    /// Posts a message to the worker from any thread. The message is converted on the
+    /// JavaScript thread.
+    ///
+    /// Panics if the message cannot be scheduled, the same as [`Channel::send`].
+    pub fn send<V>(&self, message: V) -> JoinHandle<()>
+    where
+        V: for<'cx> TryIntoJs<'cx> + Send + 'static,
+    {
+        self.try_send(message).unwrap()
+    }
+
+    /// Posts a message to the worker from any thread, returning a [`SendError`] if
+    /// the message could not be scheduled.
+    pub fn try_send<V>(&self, message: V) -> Result<JoinHandle<()>, SendError>
+    where
+        V: for<'cx> TryIntoJs<'cx> + Send + 'static,
+    {
+        let worker = self.worker.clone();
+
+        self.channel.try_send(move |mut cx| {
+            let message = message.try_into_js(&mut cx)?;
+
+            worker
+                .to_inner(&mut cx)
+                .method(&mut cx, "postMessage")?
+                .arg(message)?
+                .exec()
+        })
+    }
+
+    /// Returns a receiver for messages posted by the worker, converted to `T`.
+    ///
+    /// The receiver is disconnected when the worker exits. A message that cannot be
+    /// converted throws in the `message` listener and is reported as an uncaught
+    /// exception.
+    pub fn messages<T>(&self, cx: &mut Cx) -> NeonResult<mpsc::Receiver<T>>
+    where
+        T: for<'cx> TryFromJs<'cx> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        let tx = Rc::new(RefCell::new(Some(tx)));
+
+        let on_message = JsFunction::new(cx, {
+            let tx = tx.clone();
+
+            move |mut cx| -> JsResult<JsUndefined> {
+                let message = cx.argument::<JsValue>(0)?;
+                let message = T::from_js(&mut cx, message)?;
+
+                if let Some(tx) = &*tx.borrow() {
+                    // The receiver may have been dropped
+                    let _ = tx.send(message);
+                }
+
+                Ok(cx.undefined())
+            }
+        })?;
+
+        let on_exit = JsFunction::new(cx, move |mut cx| -> JsResult<JsUndefined> {
+            tx.borrow_mut().take();
+            Ok(cx.undefined())
+        })?;
+
+        let worker = self.to_inner(cx);
+
+        worker
+            .method(cx, "on")?
+            .args(("message", on_message))?
+            .exec()?;
+
+        worker.method(cx, "once")?.args(("exit", on_exit))?.exec()?;
+
+        Ok(rx)
+    }
+
+    /// Stops the worker as soon as possible, returning a promise for its exit code.
+    pub fn terminate<'cx>(&self, cx: &mut Cx<'cx>) -> JsResult<'cx, JsPromise> {
+        self.to_inner(cx).method(cx, "terminate")?.call()
+    }
+}
+
+// URL schemes accepted by the `Worker` constructor, which only accepts them as `URL`
+// objects
+fn is_url(filename: &str) -> bool {
+    filename.starts_with("file:") || filename.starts_with("data:")
+}
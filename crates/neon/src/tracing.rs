@@ -0,0 +1,33 @@
+//! Instrumentation of calls between JavaScript and Rust with [`tracing`](https://docs.rs/tracing).
+//!
+//! With the `tracing` feature enabled, Neon records a span for each crossing of the
+//! boundary between JavaScript and Rust. All spans use the `neon` target and the
+//! `TRACE` level, so they can be enabled separately from the module's own spans, e.g.,
+//! with the `neon=trace` directive of
+//! [`EnvFilter`](https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.EnvFilter.html).
+//!
+//! | Span | Fields | Entered while |
+//! |------|--------|---------------|
+//! | `call` | `name` | A Neon function executes. The name is the one the function was exported or created with. |
+//! | `send` | | A closure sent with [`Channel`](crate::event::Channel) executes on the JavaScript thread. |
+//! | `task` | | A [task](crate::event::TaskBuilder) executes on the worker pool and when it completes on the JavaScript thread. |
+//!
+//! The `send` and `task` spans are created when the closure is scheduled, as children
+//! of the span that is current at that point. Subscribers that report idle time, such
+//! as [`tracing-subscriber`](https://docs.rs/tracing-subscriber) with
+//! `FmtSpan::CLOSE`, therefore include the time spent waiting in a queue.
+
+use ::tracing::{trace_span, Span};
+
+pub(crate) fn call(name: &str) -> Span {
+    trace_span!(target: "neon", "call", name)
+}
+
+#[cfg(feature = "napi-4")]
+pub(crate) fn send() -> Span {
+    trace_span!(target: "neon", "send")
+}
+
+pub(crate) fn task() -> Span {
+    trace_span!(target: "neon", "task")
+}
@@ -0,0 +1,140 @@
+//! Interning of JavaScript strings.
+//!
+//! Parsers and other hot paths often receive the same small set of strings from
+//! JavaScript, such as property names or keywords. Converting each one to a Rust
+//! [`String`] requires transcoding to UTF-8 and an allocation. An [`AtomTable`]
+//! instead maps every distinct string to a small integer, an _atom_, that is cheap
+//! to compare, hash, and store.
+
+use std::{collections::HashMap, fmt};
+
+use crate::{context::Context, handle::Handle, types::JsString};
+
+/// A table that interns strings as `u32` atoms.
+///
+/// Atoms are assigned sequentially from `0` in the order strings are first seen and
+/// are stable for the lifetime of the table. Equal strings always produce the same
+/// atom, whether they are interned from JavaScript with [`intern`](AtomTable::intern)
+/// or from Rust with [`intern_str`](AtomTable::intern_str).
+///
+/// Node-API does not expose the engine's own string identity or hash, so strings are
+/// compared by their contents. JavaScript strings are read as UTF-16, the engine's
+/// native encoding, into a reusable buffer; UTF-8 conversion only happens the first
+/// time a string is seen.
+///
+/// Strings are compared by their UTF-16 code units, so strings containing unpaired
+/// surrogates are kept distinct. The text returned by [`resolve`](AtomTable::resolve)
+/// replaces unpaired surrogates with `U+FFFD`.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::atom::AtomTable;
+///
+/// fn count_keywords(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let mut atoms = AtomTable::new();
+///     let keywords = ["let", "const", "var"].map(|k| atoms.intern_str(k));
+///     let words = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+///     let mut count = 0;
+///
+///     for word in words {
+///         let word = word.downcast_or_throw::<JsString, _>(&mut cx)?;
+///
+///         if keywords.contains(&atoms.intern(&mut cx, word)) {
+///             count += 1;
+///         }
+///     }
+///
+///     Ok(cx.number(count))
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct AtomTable {
+    atoms: HashMap<Box<[u16]>, u32>,
+    strings: Vec<String>,
+    scratch: Vec<u16>,
+}
+
+impl AtomTable {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the atom for a JavaScript string, interning it if it has not been seen.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` distinct strings are interned.
+    pub fn intern<'cx, C: Context<'cx>>(&mut self, cx: &mut C, s: Handle<JsString>) -> u32 {
+        s.read_utf16(cx, &mut self.scratch);
+
+        if let Some(&atom) = self.atoms.get(self.scratch.as_slice()) {
+            return atom;
+        }
+
+        let text = String::from_utf16_lossy(&self.scratch);
+        let key = self.scratch.as_slice().into();
+
+        self.insert(key, text)
+    }
+
+    /// Returns the atom for a Rust string, interning it if it has not been seen.
+    ///
+    /// This is useful for assigning atoms to a fixed set of strings, such as keywords,
+    /// before reading strings from JavaScript.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` distinct strings are interned.
+    pub fn intern_str(&mut self, s: &str) -> u32 {
+        self.scratch.clear();
+        self.scratch.extend(s.encode_utf16());
+
+        if let Some(&atom) = self.atoms.get(self.scratch.as_slice()) {
+            return atom;
+        }
+
+        let key = self.scratch.as_slice().into();
+
+        self.insert(key, s.to_owned())
+    }
+
+    /// Returns the atom for a Rust string without interning it.
+    pub fn get(&self, s: &str) -> Option<u32> {
+        let key = s.encode_utf16().collect::<Vec<_>>();
+
+        self.atoms.get(key.as_slice()).copied()
+    }
+
+    /// Returns the text of an atom, or `None` if the atom was not produced by this table.
+    pub fn resolve(&self, atom: u32) -> Option<&str> {
+        self.strings.get(atom as usize).map(String::as_str)
+    }
+
+    /// Returns the number of interned strings.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    fn insert(&mut self, key: Box<[u16]>, text: String) -> u32 {
+        let atom = u32::try_from(self.strings.len()).expect("AtomTable overflowed u32 atoms");
+
+        self.atoms.insert(key, atom);
+        self.strings.push(text);
+
+        atom
+    }
+}
+
+impl fmt::Debug for AtomTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.strings).finish()
+    }
+}
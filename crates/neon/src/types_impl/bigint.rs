@@ -1,6 +1,8 @@
 //! Types for working with [`JsBigInt`].
 
-use std::{error, fmt, mem::MaybeUninit};
+use std::mem::MaybeUninit;
+
+pub use crate::types::number::RangeError;
 
 use crate::{
     context::{
@@ -8,7 +10,6 @@ use crate::{
         Context, Cx,
     },
     handle::{internal::TransparentNoCopyWrapper, Handle},
-    result::{NeonResult, ResultExt},
     sys::{self, raw},
     types::{private, JsBigInt, Value},
 };
@@ -20,44 +21,6 @@ pub enum Sign {
     Negative,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-/// Indicates a lossless conversion from a [`JsBigInt`] to a Rust integer
-/// could not be performed.
-///
-/// Failures include:
-/// * Negative sign on an unsigned int
-/// * Overflow of an int
-/// * Underflow of a signed int
-pub struct RangeError<T>(T);
-
-impl<T> RangeError<T> {
-    /// Get the lossy value read from a `BigInt`. It may be truncated,
-    /// sign extended or wrapped.
-    pub fn into_inner(self) -> T {
-        self.0
-    }
-}
-
-impl<T> fmt::Display for RangeError<T>
-where
-    T: fmt::Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Loss of precision reading BigInt ({})", self.0)
-    }
-}
-
-impl<T> error::Error for RangeError<T> where T: fmt::Display + fmt::Debug {}
-
-impl<T, E> ResultExt<T> for Result<T, RangeError<E>>
-where
-    E: fmt::Display,
-{
-    fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
-        self.or_else(|err| cx.throw_range_error(err.to_string()))
-    }
-}
-
 impl JsBigInt {
     pub const POSITIVE: Sign = Sign::Positive;
     pub const NEGATIVE: Sign = Sign::Negative;
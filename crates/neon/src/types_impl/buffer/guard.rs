@@ -0,0 +1,134 @@
+use std::{
+    fmt::{self, Debug},
+    ops::Deref,
+    slice,
+    sync::Arc,
+};
+
+use crate::{context::Context, types::buffer::TypedArray};
+
+/// Owned binary data that remains valid across `.await` points.
+///
+/// Slices borrowed from a JavaScript buffer are only valid while the borrowing
+/// [`Context`] is active, so they must never be stashed in a [`Future`](std::future::Future)
+/// or sent to another thread. A `BytesGuard` instead owns its data: it is `Send + Sync`
+/// and may be held for as long as needed.
+///
+/// By default the contents of a JavaScript buffer are copied with [`BytesGuard::new`].
+/// Data allocated by the addon itself can be shared without copying through
+/// [`BytesGuard::from_shared`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "napi-6", feature = "futures"))]
+/// # {
+/// # use std::future::Future;
+/// # use neon::prelude::*;
+/// use neon::types::buffer::BytesGuard;
+///
+/// #[neon::export(async)]
+/// fn checksum(cx: &mut FunctionContext) -> NeonResult<impl Future<Output = f64>> {
+///     let buf: Handle<JsBuffer> = cx.argument(0)?;
+///     let bytes = BytesGuard::new(cx, &*buf);
+///
+///     Ok(async move { bytes.iter().fold(0u8, |a, b| a ^ b) as f64 })
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct BytesGuard {
+    data: Data,
+}
+
+#[derive(Clone)]
+enum Data {
+    Owned(Arc<[u8]>),
+    Shared(Arc<dyn AsRef<[u8]> + Send + Sync>),
+}
+
+impl BytesGuard {
+    /// Copies the contents of a JavaScript binary value into a new guard.
+    ///
+    /// Typed arrays of any element type are copied as their underlying bytes
+    /// in native byte order.
+    pub fn new<'cx, C, T>(cx: &C, buf: &T) -> Self
+    where
+        C: Context<'cx>,
+        T: TypedArray,
+    {
+        let items = buf.as_slice(cx);
+
+        // Safety: `Binary` is only implemented for primitive numeric types, which
+        // have no padding and may always be viewed as bytes
+        let bytes = unsafe {
+            slice::from_raw_parts(items.as_ptr().cast::<u8>(), std::mem::size_of_val(items))
+        };
+
+        Self {
+            data: Data::Owned(Arc::from(bytes)),
+        }
+    }
+
+    /// Wraps data owned by the addon without copying.
+    ///
+    /// This is the zero-copy path for bytes that Rust allocated and continues to own,
+    /// for example the backing storage of an external buffer.
+    pub fn from_shared<T>(data: Arc<T>) -> Self
+    where
+        T: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        Self {
+            data: Data::Shared(data),
+        }
+    }
+
+    /// Returns `true` if the guard shares its data rather than holding a copy.
+    pub fn is_shared(&self) -> bool {
+        matches!(self.data, Data::Shared(_))
+    }
+
+    /// Returns the guarded bytes as a slice.
+    pub fn as_slice(&self) -> &[u8] {
+        match &self.data {
+            Data::Owned(data) => data,
+            Data::Shared(data) => (**data).as_ref(),
+        }
+    }
+
+    /// Copies the guarded bytes into a `Vec`.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.as_slice().to_vec()
+    }
+}
+
+impl Deref for BytesGuard {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for BytesGuard {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl From<Vec<u8>> for BytesGuard {
+    fn from(data: Vec<u8>) -> Self {
+        Self {
+            data: Data::Owned(Arc::from(data)),
+        }
+    }
+}
+
+impl Debug for BytesGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BytesGuard")
+            .field("len", &self.len())
+            .field("shared", &self.is_shared())
+            .finish()
+    }
+}
@@ -5,6 +5,9 @@ use crate::{
     types::buffer::{BorrowError, Ref, RefMut},
 };
 
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::{Kind, Tracker};
+
 #[derive(Debug)]
 /// A temporary lock of an execution context.
 ///
@@ -54,15 +57,26 @@ impl Ledger {
 
     // Dynamically check a slice conforms to borrow rules before returning by
     // using interior mutability of the ledger.
+    //
+    // The data is taken mutably so that the `Ref` keeps a pointer that may later be
+    // used to upgrade the borrow.
     pub(super) fn try_borrow<'a, T>(
         ledger: &'a RefCell<Self>,
-        data: &'a [T],
+        data: &'a mut [T],
     ) -> Result<Ref<'a, T>, BorrowError> {
         if !data.is_empty() {
             ledger.borrow_mut().try_add_borrow(data)?;
         }
 
-        Ok(Ref { ledger, data })
+        let ptr = data.as_mut_ptr();
+
+        Ok(Ref {
+            ledger,
+            data,
+            ptr,
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Borrow),
+        })
     }
 
     // Dynamically check a mutable slice conforms to borrow rules before returning by
@@ -75,7 +89,50 @@ impl Ledger {
             ledger.borrow_mut().try_add_borrow_mut(data)?;
         }
 
-        Ok(RefMut { ledger, data })
+        Ok(RefMut {
+            ledger,
+            data,
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Borrow),
+        })
+    }
+
+    // Replace a borrow with borrows of its parts, e.g., after splitting the slice. Parts
+    // of a borrow are not checked since they cannot overlap with any other borrows.
+    pub(super) fn replace<T>(
+        ranges: &mut Vec<Range<*const u8>>,
+        range: Range<*const u8>,
+        parts: &[&[T]],
+    ) {
+        if !range.is_empty() {
+            let i = ranges.iter().rposition(|r| r == &range).unwrap();
+
+            ranges.remove(i);
+        }
+
+        for part in parts.iter().filter(|part| !part.is_empty()) {
+            ranges.push(Self::slice_to_range(part));
+        }
+    }
+
+    // Convert an immutable borrow to a mutable borrow if it does not overlap with any
+    // other immutable borrow
+    pub(super) fn try_upgrade(&mut self, range: Range<*const u8>) -> Result<(), BorrowError> {
+        if range.is_empty() {
+            return Ok(());
+        }
+
+        let i = self.shared.iter().rposition(|r| r == &range).unwrap();
+        let range = self.shared.remove(i);
+
+        if let Err(err) = check_overlap(&self.shared, &range) {
+            self.shared.insert(i, range);
+            return Err(err);
+        }
+
+        self.owned.push(range);
+
+        Ok(())
     }
 
     // Try to add an immutable borrow to the ledger
@@ -130,7 +187,7 @@ mod tests {
     use std::mem;
     use std::slice;
 
-    use super::{BorrowError, Ledger};
+    use super::{BorrowError, Ledger, Ref};
 
     // Super unsafe, but we only use it for testing `Ledger`
     fn unsafe_aliased_slice<T>(data: &mut [T]) -> &'static mut [T] {
@@ -140,11 +197,11 @@ mod tests {
     #[test]
     fn test_overlapping_immutable_borrows() -> Result<(), Box<dyn Error>> {
         let ledger = RefCell::new(Ledger::default());
-        let data = [0u8; 128];
+        let mut data = [0u8; 128];
 
-        Ledger::try_borrow(&ledger, &data[0..10])?;
-        Ledger::try_borrow(&ledger, &data[0..100])?;
-        Ledger::try_borrow(&ledger, &data[20..])?;
+        Ledger::try_borrow(&ledger, unsafe_aliased_slice(&mut data[0..10]))?;
+        Ledger::try_borrow(&ledger, unsafe_aliased_slice(&mut data[0..100]))?;
+        Ledger::try_borrow(&ledger, unsafe_aliased_slice(&mut data[20..]))?;
 
         Ok(())
     }
@@ -161,13 +218,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_split_borrows() -> Result<(), Box<dyn Error>> {
+        let ledger = RefCell::new(Ledger::default());
+        let mut data = [0; 16];
+        let whole = unsafe_aliased_slice(&mut data);
+        let (a, b) = unsafe_aliased_slice(&mut data).split_at_mut(8);
+
+        ledger.borrow_mut().try_add_borrow_mut(whole)?;
+        let whole = Ledger::slice_to_range(whole);
+
+        Ledger::replace(&mut ledger.borrow_mut().owned, whole, &[&*a, &*b]);
+
+        // Should fail because each half is still borrowed
+        assert_eq!(
+            Ledger::try_borrow(&ledger, unsafe_aliased_slice(&mut data[0..1])).unwrap_err(),
+            BorrowError::new(),
+        );
+
+        // Should succeed after releasing the first half
+        let a = Ledger::slice_to_range(a);
+
+        Ledger::replace::<u8>(&mut ledger.borrow_mut().owned, a, &[]);
+        Ledger::try_borrow(&ledger, unsafe_aliased_slice(&mut data[0..1]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_borrows() -> Result<(), Box<dyn Error>> {
+        let mut ledger = Ledger::default();
+        let data = [0u8; 16];
+        let range = Ledger::slice_to_range(&data[0..8]);
+
+        ledger.try_add_borrow(&data[0..8])?;
+        ledger.try_add_borrow(&data[4..12])?;
+
+        // Should fail because another immutable borrow overlaps
+        assert_eq!(
+            ledger.try_upgrade(range.clone()).unwrap_err(),
+            BorrowError::new()
+        );
+        assert_eq!(ledger.shared.len(), 2);
+
+        // Should succeed after releasing the other borrow
+        ledger.shared.pop();
+        ledger.try_upgrade(range)?;
+
+        assert!(ledger.shared.is_empty());
+        assert_eq!(ledger.owned.len(), 1);
+
+        Ok(())
+    }
+
     #[test]
     fn test_overlapping_borrows() -> Result<(), Box<dyn Error>> {
         let ledger = RefCell::new(Ledger::default());
         let mut data = [0; 16];
         let a = unsafe_aliased_slice(&mut data[4..8]);
         let b = unsafe_aliased_slice(&mut data[6..12]);
-        let ab = Ledger::try_borrow(&ledger, a)?;
+        let ab = Ledger::try_borrow(&ledger, &mut *a)?;
 
         // Should fail because it overlaps
         assert_eq!(
@@ -183,7 +293,7 @@ mod tests {
 
         // Should fail because it overlaps
         assert_eq!(
-            Ledger::try_borrow(&ledger, a).unwrap_err(),
+            Ledger::try_borrow(&ledger, &mut *a).unwrap_err(),
             BorrowError::new(),
         );
 
@@ -191,8 +301,19 @@ mod tests {
         mem::drop(bb);
 
         // Should succeed because previous borrow was dropped
-        let _ab = Ledger::try_borrow(&ledger, a)?;
+        let _ab = Ledger::try_borrow(&ledger, &mut *a)?;
 
         Ok(())
     }
+
+    #[test]
+    #[should_panic(expected = "mapped data must be part of the original borrow")]
+    fn test_map_outside_borrow() {
+        let ledger = RefCell::new(Ledger::default());
+        let other = [0u8; 4];
+        let mut data = [0u8; 16];
+        let borrow = Ledger::try_borrow(&ledger, &mut data[..]).unwrap();
+
+        Ref::map(borrow, |_| &other[..]);
+    }
 }
@@ -5,7 +5,7 @@ use std::{
     error::Error,
     fmt::{self, Debug, Display},
     marker::PhantomData,
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Range},
 };
 
 use crate::{
@@ -18,10 +18,29 @@ use crate::{
     },
 };
 
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::{Kind, Tracker};
+
+mod guard;
 pub(crate) mod lock;
+#[cfg(feature = "external-buffers")]
+mod pool;
+#[cfg(feature = "napi-6")]
+pub(super) mod shared_array;
 pub(super) mod types;
+mod uninit;
+
+pub use guard::BytesGuard;
 
+#[cfg(feature = "external-buffers")]
+#[cfg_attr(docsrs, doc(cfg(feature = "external-buffers")))]
+pub use pool::BufferPool;
 pub use types::Binary;
+pub use uninit::UninitBuffer;
+
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use shared_array::SharedMemory;
 
 /// A trait allowing Rust to borrow binary data from the memory buffer of JavaScript
 /// [typed arrays][typed-arrays].
@@ -89,7 +108,8 @@ pub trait TypedArray: Value {
     where
         C: Context<'cx>;
 
-    /// Returns the size, in bytes, of the allocated binary data.
+    /// Returns the size, in bytes, of the allocated binary data, equivalent to
+    /// `byteLength` in JavaScript.
     fn size<'cx, C>(&self, cx: &mut C) -> usize
     where
         C: Context<'cx>;
@@ -98,13 +118,83 @@ pub trait TypedArray: Value {
     fn from_slice<'cx, C>(cx: &mut C, slice: &[Self::Item]) -> JsResult<'cx, Self>
     where
         C: Context<'cx>;
+
+    /// Copies the elements of `src` into the binary data, starting at the element
+    /// index `offset`.
+    ///
+    /// Throws a `RangeError` if `src` does not fit between `offset` and the end of the
+    /// binary data.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::buffer::TypedArray;
+    ///
+    /// // Writes a header to the start of a buffer provided by JavaScript
+    /// fn write_header(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let mut buf = cx.argument::<JsBuffer>(0)?;
+    ///
+    ///     buf.copy_from(&mut cx, 0, b"NEON")?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn copy_from<'cx, C>(&mut self, cx: &mut C, offset: usize, src: &[Self::Item]) -> NeonResult<()>
+    where
+        C: Context<'cx>,
+    {
+        let len = self.as_slice(cx).len();
+
+        if !in_bounds(len, offset, src.len()) {
+            return cx.throw_range_error(format!(
+                "cannot copy {} elements to offset {offset} of length {len}",
+                src.len(),
+            ));
+        }
+
+        self.as_mut_slice(cx)[offset..offset + src.len()].copy_from_slice(src);
+
+        Ok(())
+    }
+
+    /// Copies elements of the binary data, starting at the element index `offset`,
+    /// into `dst`, filling it completely.
+    ///
+    /// Throws a `RangeError` if there are fewer than `dst.len()` elements after
+    /// `offset`.
+    fn copy_to<'cx, C>(&self, cx: &mut C, offset: usize, dst: &mut [Self::Item]) -> NeonResult<()>
+    where
+        C: Context<'cx>,
+    {
+        let data = self.as_slice(cx);
+        let len = data.len();
+
+        if !in_bounds(len, offset, dst.len()) {
+            return cx.throw_range_error(format!(
+                "cannot copy {} elements from offset {offset} of length {len}",
+                dst.len(),
+            ));
+        }
+
+        dst.copy_from_slice(&data[offset..offset + dst.len()]);
+
+        Ok(())
+    }
+}
+
+fn in_bounds(len: usize, offset: usize, count: usize) -> bool {
+    offset.checked_add(count).is_some_and(|end| end <= len)
 }
 
 #[derive(Debug)]
 /// Wraps binary data immutably borrowed from a JavaScript value.
 pub struct Ref<'a, T> {
     data: &'a [T],
+    // Points to the start of `data`, derived from the mutable JavaScript memory that
+    // it was borrowed from, so that the borrow may be upgraded
+    ptr: *mut T,
     ledger: &'a RefCell<Ledger>,
+    #[cfg(feature = "diagnostics")]
+    _tracker: Tracker,
 }
 
 #[derive(Debug)]
@@ -112,6 +202,8 @@ pub struct Ref<'a, T> {
 pub struct RefMut<'a, T> {
     data: &'a mut [T],
     ledger: &'a RefCell<Ledger>,
+    #[cfg(feature = "diagnostics")]
+    _tracker: Tracker,
 }
 
 impl<'a, T> Deref for Ref<'a, T> {
@@ -136,6 +228,243 @@ impl<'a, T> DerefMut for RefMut<'a, T> {
     }
 }
 
+impl<'a, T> Ref<'a, T> {
+    /// Makes a new `Ref` for a part of the borrowed data, e.g., a subslice.
+    ///
+    /// The rest of the data is released and may be mutably borrowed. This is an
+    /// associated function that needs to be used as `Ref::map(...)`, so that it does
+    /// not conflict with methods of the borrowed slice.
+    ///
+    /// Panics if the returned slice is not part of the borrowed data.
+    pub fn map<F>(mut orig: Ref<'a, T>, f: F) -> Ref<'a, T>
+    where
+        F: FnOnce(&'a [T]) -> &'a [T],
+    {
+        let data = std::mem::take(&mut orig.data);
+        let range = Ledger::slice_to_range(data);
+        let part = f(data);
+        let ptr = part_ptr(orig.ptr, &range, part);
+
+        Ledger::replace(&mut orig.ledger.borrow_mut().shared, range, &[part]);
+
+        Ref {
+            data: part,
+            ptr,
+            ledger: orig.ledger,
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Borrow),
+        }
+    }
+
+    /// Splits a `Ref` into multiple `Ref`s for different parts of the borrowed data.
+    ///
+    /// This is an associated function that needs to be used as `Ref::map_split(...)`.
+    ///
+    /// Panics if either returned slice is not part of the borrowed data.
+    pub fn map_split<F>(mut orig: Ref<'a, T>, f: F) -> (Ref<'a, T>, Ref<'a, T>)
+    where
+        F: FnOnce(&'a [T]) -> (&'a [T], &'a [T]),
+    {
+        let data = std::mem::take(&mut orig.data);
+        let range = Ledger::slice_to_range(data);
+        let (a, b) = f(data);
+        let ptrs = (part_ptr(orig.ptr, &range, a), part_ptr(orig.ptr, &range, b));
+
+        Ledger::replace(&mut orig.ledger.borrow_mut().shared, range, &[a, b]);
+
+        let part = |data, ptr| Ref {
+            data,
+            ptr,
+            ledger: orig.ledger,
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Borrow),
+        };
+
+        (part(a, ptrs.0), part(b, ptrs.1))
+    }
+
+    /// Converts an immutable borrow to a mutable borrow of the same data, returning
+    /// the original `Ref` if any other active borrow overlaps with it.
+    ///
+    /// Unlike dropping the `Ref` and calling [`TypedArray::try_borrow_mut`], the data
+    /// stays borrowed throughout, so it cannot be mutably borrowed elsewhere in between.
+    /// The `lock` must be the one that the data was borrowed with.
+    ///
+    /// This is an associated function that needs to be used as `Ref::try_upgrade(...)`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::buffer::{BorrowError, Ref, TypedArray};
+    ///
+    /// // Clears an array if it contains any non-zero bytes
+    /// fn clear(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let buf = cx.argument::<JsUint8Array>(0)?;
+    ///     let mut run = || -> Result<_, BorrowError> {
+    ///         let lock = cx.lock();
+    ///         let data = buf.try_borrow(&lock)?;
+    ///
+    ///         if data.iter().all(|&b| b == 0) {
+    ///             return Ok(false);
+    ///         }
+    ///
+    ///         match Ref::try_upgrade(data, &lock) {
+    ///             Ok(mut data) => data.fill(0),
+    ///             // Another borrow is reading the data
+    ///             Err(_) => return Ok(false),
+    ///         }
+    ///
+    ///         Ok(true)
+    ///     };
+    ///
+    ///     let cleared = run().or_throw(&mut cx)?;
+    ///
+    ///     Ok(cx.boolean(cleared))
+    /// }
+    /// ```
+    pub fn try_upgrade<C>(
+        mut orig: Ref<'a, T>,
+        lock: &'a Lock<C>,
+    ) -> Result<RefMut<'a, T>, Ref<'a, T>> {
+        if !std::ptr::eq(orig.ledger, &lock.ledger) {
+            return Err(orig);
+        }
+
+        let range = Ledger::slice_to_range(orig.data);
+
+        if orig.ledger.borrow_mut().try_upgrade(range).is_err() {
+            return Err(orig);
+        }
+
+        let len = std::mem::take(&mut orig.data).len();
+
+        // Safety: `ptr` was derived from the mutable JavaScript memory that the data
+        // was borrowed from, rather than from the shared slice, and the ledger now
+        // records it as the only active borrow.
+        let data = if len == 0 {
+            &mut []
+        } else {
+            unsafe { std::slice::from_raw_parts_mut(orig.ptr, len) }
+        };
+
+        Ok(RefMut {
+            data,
+            ledger: orig.ledger,
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Borrow),
+        })
+    }
+}
+
+impl<'a, T> RefMut<'a, T> {
+    /// Makes a new `RefMut` for a part of the borrowed data, e.g., a subslice.
+    ///
+    /// The rest of the data is released and may be borrowed again. This is an
+    /// associated function that needs to be used as `RefMut::map(...)`, so that it does
+    /// not conflict with methods of the borrowed slice.
+    ///
+    /// Panics if the returned slice is not part of the borrowed data.
+    pub fn map<F>(mut orig: RefMut<'a, T>, f: F) -> RefMut<'a, T>
+    where
+        F: FnOnce(&'a mut [T]) -> &'a mut [T],
+    {
+        let data = std::mem::take(&mut orig.data);
+        let range = Ledger::slice_to_range(data);
+        let part = f(data);
+
+        assert_within(&range, part);
+        Ledger::replace(&mut orig.ledger.borrow_mut().owned, range, &[&*part]);
+
+        RefMut {
+            data: part,
+            ledger: orig.ledger,
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Borrow),
+        }
+    }
+
+    /// Splits a `RefMut` into multiple `RefMut`s for disjoint parts of the borrowed
+    /// data, e.g., to mutate both halves of a buffer at the same time.
+    ///
+    /// This is an associated function that needs to be used as `RefMut::map_split(...)`.
+    ///
+    /// Panics if either returned slice is not part of the borrowed data.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::buffer::{BorrowError, RefMut, TypedArray};
+    ///
+    /// // Copies the first half of an array into the second half
+    /// fn mirror(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let mut arr = cx.argument::<JsUint8Array>(0)?;
+    ///     let mut run = || -> Result<_, BorrowError> {
+    ///         let lock = cx.lock();
+    ///         let data = arr.try_borrow_mut(&lock)?;
+    ///         let mid = data.len() / 2;
+    ///         let (front, mut back) = RefMut::map_split(data, |data| data.split_at_mut(mid));
+    ///
+    ///         back[..mid].copy_from_slice(&front);
+    ///
+    ///         Ok(())
+    ///     };
+    ///
+    ///     run().or_throw(&mut cx)?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn map_split<F>(mut orig: RefMut<'a, T>, f: F) -> (RefMut<'a, T>, RefMut<'a, T>)
+    where
+        F: FnOnce(&'a mut [T]) -> (&'a mut [T], &'a mut [T]),
+    {
+        let data = std::mem::take(&mut orig.data);
+        let range = Ledger::slice_to_range(data);
+        let (a, b) = f(data);
+
+        assert_within(&range, a);
+        assert_within(&range, b);
+        Ledger::replace(&mut orig.ledger.borrow_mut().owned, range, &[&*a, &*b]);
+
+        let ledger = orig.ledger;
+        let part = |data| RefMut {
+            data,
+            ledger,
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Borrow),
+        };
+
+        (part(a), part(b))
+    }
+}
+
+// Panics if a non-empty part returned by a `map` function is not inside the original
+// borrow. The ledger only tracks the parts, so any other slice would be unguarded.
+fn assert_within<T>(range: &Range<*const u8>, part: &[T]) {
+    if part.is_empty() {
+        return;
+    }
+
+    let part = Ledger::slice_to_range(part);
+
+    assert!(
+        range.start <= part.start && part.end <= range.end,
+        "mapped data must be part of the original borrow",
+    );
+}
+
+// Offsets the pointer to the start of a borrow to the start of one of its parts
+fn part_ptr<T>(ptr: *mut T, range: &Range<*const u8>, part: &[T]) -> *mut T {
+    assert_within(range, part);
+
+    if part.is_empty() {
+        return part.as_ptr().cast_mut();
+    }
+
+    let offset = part.as_ptr() as usize - range.start as usize;
+
+    // Safety: The part is inside of the original borrow, which `ptr` points to
+    unsafe { ptr.cast::<u8>().add(offset).cast() }
+}
+
 impl<'a, T> Drop for Ref<'a, T> {
     fn drop(&mut self) {
         if self.is_empty() {
@@ -0,0 +1,177 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex, MutexGuard, Weak},
+};
+
+use crate::{context::Context, handle::Handle, result::JsResult, types::JsBuffer};
+
+/// A pool of fixed-size allocations that back external [`JsBuffer`]s.
+///
+/// Servers that create many short-lived buffers spend a significant amount of time
+/// allocating and freeing their backing memory. A `BufferPool` instead hands out
+/// buffers backed by _slabs_ of `slab_size` bytes. When a buffer is garbage collected,
+/// its slab is returned to the pool to back a future buffer.
+///
+/// Requests larger than the slab size fall back to a regular allocation with
+/// [`JsBuffer::new`]. At most `max_idle` slabs are kept while they are not in use;
+/// additional slabs are freed when their buffer is collected.
+///
+/// A pool may be cloned cheaply and shared between threads and module instances.
+/// Buffers hold a weak reference to their pool, so dropping every clone of the pool
+/// frees idle slabs immediately and outstanding slabs when their buffers are collected.
+///
+/// See [`JsBuffer::external`] for the environments where external buffers are supported.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::buffer::{BufferPool, TypedArray};
+///
+/// static POOL: once_cell::sync::Lazy<BufferPool> =
+///     once_cell::sync::Lazy::new(|| BufferPool::new(4096, 256));
+///
+/// fn read_chunk(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+///     let mut buf = POOL.buffer(&mut cx, 1024)?;
+///
+///     buf.as_mut_slice(&mut cx).fill(b'a');
+///
+///     Ok(buf)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BufferPool {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    slab_size: usize,
+    max_idle: usize,
+    idle: Mutex<Vec<Box<[u8]>>>,
+}
+
+impl Inner {
+    fn idle(&self) -> MutexGuard<'_, Vec<Box<[u8]>>> {
+        // Slabs are only ever pushed or popped, so a poisoned lock is still consistent
+        self.idle.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl BufferPool {
+    /// Creates an empty pool of `slab_size` byte slabs that keeps at most `max_idle`
+    /// unused slabs.
+    pub fn new(slab_size: usize, max_idle: usize) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                slab_size,
+                max_idle,
+                idle: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Returns a new zero-filled `JsBuffer` of `len` bytes, backed by a pooled slab when
+    /// `len` does not exceed the slab size.
+    pub fn buffer<'a, C: Context<'a>>(&self, cx: &mut C, len: usize) -> JsResult<'a, JsBuffer> {
+        if len > self.inner.slab_size {
+            return JsBuffer::new(cx, len);
+        }
+
+        let mut slab = self.take();
+
+        // Recycled slabs contain data from a previous buffer
+        slab[..len].fill(0);
+
+        Ok(self.wrap(cx, slab, len))
+    }
+
+    /// Returns a new `JsBuffer` containing a copy of `data`, backed by a pooled slab when
+    /// it does not exceed the slab size.
+    pub fn from_slice<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        data: &[u8],
+    ) -> JsResult<'a, JsBuffer> {
+        if data.len() > self.inner.slab_size {
+            return JsBuffer::from_slice(cx, data);
+        }
+
+        let mut slab = self.take();
+
+        slab[..data.len()].copy_from_slice(data);
+
+        Ok(self.wrap(cx, slab, data.len()))
+    }
+
+    /// Returns the size, in bytes, of each slab.
+    pub fn slab_size(&self) -> usize {
+        self.inner.slab_size
+    }
+
+    /// Returns the number of slabs that are allocated but not backing a buffer.
+    pub fn idle(&self) -> usize {
+        self.inner.idle().len()
+    }
+
+    fn take(&self) -> Box<[u8]> {
+        let slab = self.inner.idle().pop();
+
+        slab.unwrap_or_else(|| vec![0; self.inner.slab_size].into_boxed_slice())
+    }
+
+    fn wrap<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        slab: Box<[u8]>,
+        len: usize,
+    ) -> Handle<'a, JsBuffer> {
+        let slab = PooledSlab {
+            data: Some(slab),
+            len,
+            pool: Arc::downgrade(&self.inner),
+        };
+
+        JsBuffer::external(cx, slab)
+    }
+}
+
+impl fmt::Debug for BufferPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferPool")
+            .field("slab_size", &self.inner.slab_size)
+            .field("max_idle", &self.inner.max_idle)
+            .field("idle", &self.idle())
+            .finish()
+    }
+}
+
+// Backing store of a pooled buffer, returned to the pool when the buffer is finalized
+struct PooledSlab {
+    // Only `None` while being dropped
+    data: Option<Box<[u8]>>,
+    len: usize,
+    pool: Weak<Inner>,
+}
+
+impl AsMut<[u8]> for PooledSlab {
+    fn as_mut(&mut self) -> &mut [u8] {
+        let len = self.len;
+
+        // `data` is always `Some` until `PooledSlab` is dropped
+        &mut self.data.as_mut().unwrap()[..len]
+    }
+}
+
+impl Drop for PooledSlab {
+    fn drop(&mut self) {
+        let (Some(data), Some(pool)) = (self.data.take(), self.pool.upgrade()) else {
+            return;
+        };
+
+        let mut idle = pool.idle();
+
+        if idle.len() < pool.max_idle {
+            idle.push(data);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::{slice, sync::atomic::AtomicU8};
+
+use crate::{
+    context::{
+        internal::{ContextInternal, Env},
+        Context, Cx,
+    },
+    handle::{internal::TransparentNoCopyWrapper, Handle, Root},
+    intrinsics,
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types_impl::{private::ValueInternal, Value},
+};
+
+/// The type of JavaScript
+/// [`SharedArrayBuffer`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer)
+/// objects.
+///
+/// The contents of a `SharedArrayBuffer` may be modified concurrently by any worker
+/// thread that holds it, so they cannot be borrowed as a slice. Instead,
+/// [`share`](JsSharedArrayBuffer::share) produces a [`SharedMemory`] handle that can be
+/// sent to other Rust threads and accessed with atomic operations.
+///
+/// Node-API does not provide direct support for `SharedArrayBuffer`, so these
+/// operations use the built-in `SharedArrayBuffer` and `Uint8Array` constructors from
+/// [`neon::intrinsics`](crate::intrinsics). Values are recognized with the built-in
+/// `SharedArrayBuffer.prototype.byteLength` getter, so a value is only recognized as a
+/// `JsSharedArrayBuffer` if it was created in the same realm.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::sync::atomic::Ordering;
+/// use neon::types::JsSharedArrayBuffer;
+///
+/// fn fill_in_background(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let buf = cx.argument::<JsSharedArrayBuffer>(0)?;
+///     let memory = buf.share(&mut cx)?;
+///
+///     std::thread::spawn(move || {
+///         for byte in memory.as_atomic_slice() {
+///             byte.store(0xff, Ordering::Relaxed);
+///         }
+///     });
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSharedArrayBuffer(raw::Local);
+
+impl JsSharedArrayBuffer {
+    /// Constructs a new zero-filled `JsSharedArrayBuffer` of `len` bytes.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, len: usize) -> JsResult<'a, Self> {
+        let cx = cx.cx_mut();
+        let len = cx.number(len as f64).upcast();
+        let buf = intrinsics::shared_array_buffer(cx)?.construct(cx, [len])?;
+
+        Ok(Handle::new_internal(Self(buf.to_local())))
+    }
+
+    /// Returns the size, in bytes, of the buffer.
+    pub fn size<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<usize> {
+        Ok(self.view(cx.cx_mut())?.length)
+    }
+
+    /// Returns a [`SharedMemory`] handle to the contents of the buffer. The handle keeps
+    /// the buffer alive and may be sent to other threads.
+    pub fn share<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<SharedMemory> {
+        let cx = cx.cx_mut();
+        let info = self.view(cx)?;
+
+        Ok(SharedMemory {
+            _buffer: Root::new(cx, self),
+            data: info.data.cast(),
+            len: info.length,
+        })
+    }
+
+    // `napi_get_arraybuffer_info` rejects a `SharedArrayBuffer`, but it can be viewed
+    // with a `Uint8Array` in order to read its data pointer
+    fn view(&self, cx: &mut Cx) -> NeonResult<sys::typedarray::TypedArrayInfo> {
+        let buf = self.as_value(cx);
+        let view = intrinsics::uint8_array(cx)?.construct(cx, [buf])?;
+        let env = cx.env().to_raw();
+
+        if !unsafe { sys::tag::is_typedarray(env, view.to_local()) } {
+            return cx.throw_type_error("Expected a Uint8Array view of the SharedArrayBuffer");
+        }
+
+        let info = unsafe { sys::typedarray::info(env, view.to_local()) };
+
+        // The data pointer is only valid for as long as this buffer keeps it alive
+        if !unsafe { sys::mem::strict_equals(env, info.buf, self.to_local()) } {
+            return cx.throw_type_error("Expected a Uint8Array view of the SharedArrayBuffer");
+        }
+
+        Ok(info)
+    }
+}
+
+unsafe impl TransparentNoCopyWrapper for JsSharedArrayBuffer {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSharedArrayBuffer {
+    fn name() -> &'static str {
+        "JsSharedArrayBuffer"
+    }
+
+    fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
+        let env = cx.env().to_raw();
+
+        // Leave an exception that is already pending for the caller
+        if unsafe { sys::error::is_throwing(env) } {
+            return false;
+        }
+
+        // The environment may not provide `SharedArrayBuffer` at all
+        let Ok(getter) = cx.try_catch(intrinsics::shared_array_buffer_byte_length) else {
+            return false;
+        };
+
+        unsafe { sys::tag::has_brand(env, other.to_local(), getter.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        Self(h)
+    }
+}
+
+impl Value for JsSharedArrayBuffer {}
+
+impl Object for JsSharedArrayBuffer {}
+
+/// A handle to the contents of a [`JsSharedArrayBuffer`] that may be sent to other threads.
+///
+/// The memory is kept alive for as long as the handle exists. Since JavaScript may read
+/// and write it concurrently, it is only exposed as atomics or as a raw pointer.
+pub struct SharedMemory {
+    _buffer: Root<JsSharedArrayBuffer>,
+    data: *mut u8,
+    len: usize,
+}
+
+// # Safety
+// The memory is owned by a `SharedArrayBuffer`, which is designed to be accessed
+// concurrently and is never detached. It is kept alive by the `Root`.
+unsafe impl Send for SharedMemory {}
+
+unsafe impl Sync for SharedMemory {}
+
+impl SharedMemory {
+    /// Returns the size, in bytes, of the memory.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the memory is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the memory as a slice of atomic bytes.
+    pub fn as_atomic_slice(&self) -> &[AtomicU8] {
+        if self.len == 0 {
+            return &[];
+        }
+
+        // # Safety
+        // `AtomicU8` has the same in-memory representation as `u8` and all access
+        // from Rust through this slice is atomic
+        unsafe { slice::from_raw_parts(self.data.cast(), self.len) }
+    }
+
+    /// Returns a raw pointer to the memory.
+    ///
+    /// Any access through the pointer may race with other threads, including JavaScript
+    /// workers. It is the caller's responsibility to synchronize access.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.data
+    }
+}
+
+impl std::fmt::Debug for SharedMemory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedMemory")
+            .field("len", &self.len)
+            .finish()
+    }
+}
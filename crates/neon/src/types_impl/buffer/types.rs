@@ -1,5 +1,8 @@
 use std::{marker::PhantomData, slice};
 
+#[cfg(feature = "external-buffers")]
+use std::sync::Arc;
+
 use crate::{
     context::{
         internal::{ContextInternal, Env},
@@ -7,13 +10,13 @@ use crate::{
     },
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    result::{JsResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw, typedarray::TypedArrayInfo, TypedArrayType},
     types_impl::{
         buffer::{
             lock::{Ledger, Lock},
             private::{self, JsTypedArrayInner},
-            BorrowError, Ref, RefMut, Region, TypedArray,
+            BorrowError, Ref, RefMut, Region, TypedArray, UninitBuffer,
         },
         private::ValueInternal,
         Value,
@@ -91,6 +94,24 @@ impl JsBuffer {
         }
     }
 
+    /// Constructs a new `Buffer` object without initializing its memory, returning an
+    /// [`UninitBuffer`] that tracks which bytes have been written.
+    ///
+    /// This is a safe alternative to [`JsBuffer::uninitialized`].
+    pub fn uninit<'a, C: Context<'a>>(cx: &mut C, len: usize) -> NeonResult<UninitBuffer<'a>> {
+        let result = unsafe { sys::buffer::uninitialized(cx.env().to_raw(), len) };
+
+        if let Ok((buf, data)) = result {
+            Ok(UninitBuffer::new(
+                Handle::new_internal(Self(buf)),
+                data,
+                len,
+            ))
+        } else {
+            Err(unsafe { Throw::new() })
+        }
+    }
+
     #[cfg(feature = "external-buffers")]
     #[cfg_attr(docsrs, doc(cfg(feature = "external-buffers")))]
     /// Construct a new `Buffer` from bytes allocated by Rust.
@@ -206,10 +227,15 @@ impl TypedArray for JsBuffer {
     where
         C: Context<'cx>,
     {
-        let mut buffer = cx.buffer(slice.len())?;
-        let target = buffer.as_mut_slice(cx);
-        target.copy_from_slice(slice);
-        Ok(buffer)
+        unsafe {
+            let result = sys::buffer::from_slice(cx.env().to_raw(), slice);
+
+            if let Ok(buf) = result {
+                Ok(Handle::new_internal(Self(buf)))
+            } else {
+                Err(Throw::new())
+            }
+        }
     }
 }
 
@@ -291,6 +317,48 @@ impl JsArrayBuffer {
         Handle::new_internal(Self(value))
     }
 
+    #[cfg(feature = "external-buffers")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "external-buffers")))]
+    /// Construct a new `JsArrayBuffer` that shares bytes owned by Rust, without copying.
+    ///
+    /// Unlike [`JsArrayBuffer::external`], the data is not moved into the buffer; it
+    /// may be a long-lived allocation that other Rust code continues to read, such as a
+    /// memory-mapped file. The buffer holds a clone of the `Arc`, which is dropped when
+    /// the buffer is garbage collected.
+    ///
+    /// See [`JsArrayBuffer::external`] for environments that do not support external buffers.
+    ///
+    /// # Safety
+    ///
+    /// Other owners of the `Arc` may read the data at any time, so the contents must never
+    /// be written while the buffer is reachable. Neither JavaScript nor Rust may write to
+    /// the buffer or to any view of it, including `Buffer`s and typed arrays that
+    /// JavaScript creates over it; e.g., [`TypedArray::as_mut_slice`] and
+    /// [`TypedArray::try_borrow_mut`] must not be used. In practice, the buffer should
+    /// only be exposed to trusted JavaScript that treats it as read-only.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use neon::prelude::*;
+    /// static TABLE: once_cell::sync::Lazy<Arc<Vec<u8>>> =
+    ///     once_cell::sync::Lazy::new(|| Arc::new((0..=255).collect()));
+    ///
+    /// fn lookup_table(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
+    ///     // Safety: The table is only read by the module's own JavaScript
+    ///     Ok(unsafe { JsArrayBuffer::external_shared(&mut cx, TABLE.clone()) })
+    /// }
+    /// ```
+    pub unsafe fn external_shared<'a, C, T>(cx: &mut C, data: Arc<T>) -> Handle<'a, Self>
+    where
+        C: Context<'a>,
+        T: AsRef<[u8]> + Send + Sync + ?Sized + 'static,
+    {
+        let env = cx.env().to_raw();
+        let value = sys::arraybuffer::new_external_shared(env, data);
+
+        Handle::new_internal(Self(value))
+    }
+
     /// Returns a region of this buffer.
     ///
     /// See also: [`Handle<JsArrayBuffer>::region()`](Handle::region) for a more
@@ -415,11 +483,15 @@ impl TypedArray for JsArrayBuffer {
     where
         C: Context<'cx>,
     {
-        let len = slice.len();
-        let mut buffer = JsArrayBuffer::new(cx, len)?;
-        let target = buffer.as_mut_slice(cx);
-        target.copy_from_slice(slice);
-        Ok(buffer)
+        unsafe {
+            let result = sys::arraybuffer::from_slice(cx.env().to_raw(), slice);
+
+            if let Ok(buf) = result {
+                Ok(Handle::new_internal(Self(buf)))
+            } else {
+                Err(Throw::new())
+            }
+        }
     }
 }
 
@@ -582,7 +654,7 @@ where
             let info = sys::typedarray::info(env, value);
 
             // The borrowed data must be guarded by `Ledger` before returning
-            Ledger::try_borrow(&lock.ledger, slice_from_info(info))
+            Ledger::try_borrow(&lock.ledger, slice_from_info_mut(info))
         }
     }
 
@@ -747,7 +819,11 @@ where
     }
 
     /// Returns the offset (in bytes) of the typed array from the start of its
-    /// [`JsArrayBuffer`](JsArrayBuffer).
+    /// [`JsArrayBuffer`](JsArrayBuffer), equivalent to `byteOffset` in JavaScript.
+    ///
+    /// Typed arrays passed in from JavaScript, including `Buffer`s allocated from Node's
+    /// pool, often do not start at the beginning of their buffer. Use this offset when
+    /// reading the [`buffer()`](JsTypedArray::buffer) directly.
     pub fn offset<'cx, C>(&self, cx: &mut C) -> usize
     where
         C: Context<'cx>,
@@ -759,7 +835,8 @@ where
     /// Returns the length of the typed array, i.e. the number of elements.
     ///
     /// Note that, depending on the element size, this is not necessarily the same as
-    /// [`size()`](crate::types::buffer::TypedArray::size). In particular:
+    /// [`size()`](crate::types::buffer::TypedArray::size), which is the `byteLength`.
+    /// In particular:
     ///
     /// ```ignore
     /// self.size() == self.len() * size_of::<T>()
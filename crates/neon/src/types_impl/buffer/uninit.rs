@@ -0,0 +1,209 @@
+use std::{fmt, mem::MaybeUninit, ptr, slice};
+
+use crate::{context::Context, handle::Handle, result::JsResult, types::JsBuffer};
+#[cfg(not(feature = "napi-6"))]
+use crate::{object::Object, types::JsFunction};
+
+/// A newly allocated [`JsBuffer`] whose contents have not been initialized.
+///
+/// Zero-filling a buffer that is about to be overwritten is wasted work in
+/// high-throughput allocation paths. An `UninitBuffer` skips the fill and tracks how
+/// much of its memory has been written, so it can never expose uninitialized bytes to
+/// Rust.
+///
+/// Bytes are written either safely, with [`write`](UninitBuffer::write), or directly
+/// into [`spare_capacity_mut`](UninitBuffer::spare_capacity_mut) followed by
+/// [`assume_init_to`](UninitBuffer::assume_init_to). Once the data is written,
+/// [`finish`](UninitBuffer::finish) produces a `JsBuffer` of the initialized bytes.
+///
+/// The buffer is not visible to JavaScript until it is finished.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::buffer::TypedArray;
+///
+/// fn concat(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+///     let a = cx.argument::<JsBuffer>(0)?;
+///     let b = cx.argument::<JsBuffer>(1)?;
+///     let len = a.as_slice(&cx).len() + b.as_slice(&cx).len();
+///     let mut buf = JsBuffer::uninit(&mut cx, len)?;
+///
+///     buf.write(a.as_slice(&cx));
+///     buf.write(b.as_slice(&cx));
+///
+///     buf.finish(&mut cx)
+/// }
+/// ```
+pub struct UninitBuffer<'cx> {
+    buf: Handle<'cx, JsBuffer>,
+    data: *mut MaybeUninit<u8>,
+    capacity: usize,
+    filled: usize,
+}
+
+impl<'cx> UninitBuffer<'cx> {
+    pub(super) fn new(buf: Handle<'cx, JsBuffer>, data: *mut u8, capacity: usize) -> Self {
+        Self {
+            buf,
+            data: data.cast(),
+            capacity,
+            filled: 0,
+        }
+    }
+
+    /// Returns the size, in bytes, of the allocation.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of bytes that have been initialized.
+    pub fn filled(&self) -> usize {
+        self.filled
+    }
+
+    /// Returns the number of bytes that have not been initialized.
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.filled
+    }
+
+    /// Returns the initialized bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        if self.filled == 0 {
+            return &[];
+        }
+
+        // # Safety
+        // The first `filled` bytes are initialized
+        unsafe { slice::from_raw_parts(self.data.cast(), self.filled) }
+    }
+
+    /// Returns the initialized bytes mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        if self.filled == 0 {
+            return &mut [];
+        }
+
+        // # Safety
+        // The first `filled` bytes are initialized
+        unsafe { slice::from_raw_parts_mut(self.data.cast(), self.filled) }
+    }
+
+    /// Returns the entire allocation, including bytes that have been initialized.
+    ///
+    /// After writing a prefix of the slice, call
+    /// [`assume_init_to`](UninitBuffer::assume_init_to) to mark it as initialized. Use
+    /// [`spare_capacity_mut`](UninitBuffer::spare_capacity_mut) to safely write only the
+    /// bytes following the initialized bytes.
+    ///
+    /// # Safety
+    ///
+    /// The first [`filled`](UninitBuffer::filled) bytes must not be de-initialized, e.g.,
+    /// by writing [`MaybeUninit::uninit`] to them, unless
+    /// [`assume_init_to`](UninitBuffer::assume_init_to) is called with a shorter length
+    /// before the bytes are read again.
+    pub unsafe fn as_mut_uninit(&mut self) -> &mut [MaybeUninit<u8>] {
+        if self.capacity == 0 {
+            return &mut [];
+        }
+
+        // # Safety
+        // `MaybeUninit` may hold uninitialized memory and the caller upholds that the
+        // initialized bytes stay initialized
+        slice::from_raw_parts_mut(self.data, self.capacity)
+    }
+
+    /// Returns the bytes following the initialized bytes.
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        if self.filled == self.capacity {
+            return &mut [];
+        }
+
+        // # Safety
+        // `MaybeUninit` may hold uninitialized memory and `filled < capacity`
+        unsafe { slice::from_raw_parts_mut(self.data.add(self.filled), self.remaining()) }
+    }
+
+    /// Copies as much of `data` as fits after the initialized bytes, returning the
+    /// number of bytes copied.
+    pub fn write(&mut self, data: &[u8]) -> usize {
+        let n = data.len().min(self.remaining());
+
+        // # Safety
+        // `n` bytes are available after `filled` and `data` cannot overlap a buffer
+        // that is not yet visible outside of `self`
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), self.data.add(self.filled).cast(), n);
+        }
+
+        self.filled += n;
+
+        n
+    }
+
+    /// Marks the first `len` bytes of the buffer as initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds [`capacity`](UninitBuffer::capacity).
+    ///
+    /// # Safety
+    ///
+    /// The first `len` bytes of [`as_mut_uninit`](UninitBuffer::as_mut_uninit) must have
+    /// been initialized.
+    pub unsafe fn assume_init_to(&mut self, len: usize) {
+        assert!(
+            len <= self.capacity,
+            "Attempted to initialize past the end of an UninitBuffer"
+        );
+
+        self.filled = len;
+    }
+
+    /// Returns a `JsBuffer` containing the initialized bytes.
+    ///
+    /// The bytes past the initialized length are zero-filled, since they remain
+    /// reachable from JavaScript through the buffer's underlying `ArrayBuffer`.
+    pub fn finish<C: Context<'cx>>(self, cx: &mut C) -> JsResult<'cx, JsBuffer> {
+        let Self {
+            buf,
+            data,
+            capacity,
+            filled,
+        } = self;
+
+        if filled == capacity {
+            return Ok(buf);
+        }
+
+        // # Safety
+        // `filled < capacity`, so the tail is in bounds
+        unsafe {
+            ptr::write_bytes(data.add(filled), 0, capacity - filled);
+        }
+
+        let cx = cx.cx_mut();
+
+        #[cfg(feature = "napi-6")]
+        let subarray = crate::intrinsics::buffer_subarray(cx)?;
+        #[cfg(not(feature = "napi-6"))]
+        let subarray = buf.get::<JsFunction, _, _>(cx, "subarray")?;
+
+        subarray
+            .bind(cx)
+            .this(buf)?
+            .arg(0)?
+            .arg(filled as f64)?
+            .call()
+    }
+}
+
+impl fmt::Debug for UninitBuffer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UninitBuffer")
+            .field("capacity", &self.capacity)
+            .field("filled", &self.filled)
+            .finish()
+    }
+}
@@ -1,17 +1,23 @@
 //! Types and traits representing JavaScript error values.
 
-use std::panic::{catch_unwind, UnwindSafe};
+use std::{
+    panic::{catch_unwind, UnwindSafe},
+    ptr,
+};
 
 use crate::{
     context::{
         internal::{ContextInternal, Env},
-        Context, Cx,
+        Context, Cx, FunctionContext,
     },
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    result::{NeonResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw},
-    types::{build, private::ValueInternal, utf8::Utf8, Value},
+    types::{
+        build, extract::TryIntoJs, private::ValueInternal, utf8::Utf8, JsArray, JsFunction,
+        JsNumber, JsObject, JsString, JsValue, Value,
+    },
 };
 
 /// The type of JavaScript
@@ -111,6 +117,273 @@ impl JsError {
             true
         })
     }
+
+    /// Creates an instance of the [`SyntaxError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError) class.
+    pub fn syntax_error<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        #[cfg(feature = "napi-6")]
+        let constructor = crate::intrinsics::syntax_error(cx)?;
+        #[cfg(not(feature = "napi-6"))]
+        let constructor = cx.global::<JsFunction>("SyntaxError")?;
+
+        Self::with_type(cx, constructor, msg)
+    }
+
+    /// Creates an instance of the [`EvalError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/EvalError) class.
+    pub fn eval_error<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        #[cfg(feature = "napi-6")]
+        let constructor = crate::intrinsics::eval_error(cx)?;
+        #[cfg(not(feature = "napi-6"))]
+        let constructor = cx.global::<JsFunction>("EvalError")?;
+
+        Self::with_type(cx, constructor, msg)
+    }
+
+    /// Creates an instance of the [`URIError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/URIError) class.
+    pub fn uri_error<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        #[cfg(feature = "napi-6")]
+        let constructor = crate::intrinsics::uri_error(cx)?;
+        #[cfg(not(feature = "napi-6"))]
+        let constructor = cx.global::<JsFunction>("URIError")?;
+
+        Self::with_type(cx, constructor, msg)
+    }
+
+    /// Creates an error by calling `new constructor(msg)`.
+    ///
+    /// The constructor may be any subclass of `Error`, including classes defined in
+    /// JavaScript. Throws a `TypeError` if the constructed value is not an error.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn abort_error(mut cx: FunctionContext) -> JsResult<JsError> {
+    ///     let constructor: Handle<JsFunction> = cx.argument(0)?;
+    ///
+    ///     JsError::with_type(&mut cx, constructor, "The operation was aborted")
+    /// }
+    /// ```
+    pub fn with_type<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        constructor: Handle<JsFunction>,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let err: Handle<JsValue> = constructor
+            .bind(cx.cx_mut())
+            .arg(msg.as_ref())?
+            .construct()?;
+
+        err.downcast_or_throw(cx)
+    }
+
+    /// Returns the `stack` property of the error, or `None` if it is not a string.
+    ///
+    /// May throw an exception if `stack` is an accessor.
+    pub fn stack<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Option<String>> {
+        let stack: Handle<JsValue> = self.prop(cx.cx_mut(), "stack").get()?;
+
+        Ok(stack
+            .downcast::<JsString, _>(cx)
+            .ok()
+            .map(|stack| stack.value(cx)))
+    }
+
+    /// Replaces the `stack` property of the error, for example with a stack trace
+    /// from outside of JavaScript.
+    pub fn set_stack<'a, C: Context<'a>, S: AsRef<str>>(
+        &self,
+        cx: &mut C,
+        stack: S,
+    ) -> NeonResult<()> {
+        self.prop(cx.cx_mut(), "stack").set(stack.as_ref())?;
+        Ok(())
+    }
+}
+
+/// A frame of a JavaScript stack trace captured with
+/// [`Context::capture_stack_trace`](crate::context::Context::capture_stack_trace).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StackFrame {
+    /// Name of the function, or `None` for anonymous functions and top level code.
+    pub function_name: Option<String>,
+    /// Name of the file or URL of the script.
+    pub file_name: Option<String>,
+    /// One-based line number.
+    pub line: Option<u32>,
+    /// One-based column number.
+    pub column: Option<u32>,
+}
+
+// Captures the current stack with V8's
+// [stack trace API](https://v8.dev/docs/stack-trace-api). `Error.prepareStackTrace`
+// is temporarily replaced in order to receive the `CallSite` objects instead of a
+// formatted string.
+pub(crate) fn capture_stack_trace<'cx>(cx: &mut Cx<'cx>) -> NeonResult<Vec<StackFrame>> {
+    fn call_sites(mut cx: FunctionContext) -> JsResult<JsValue> {
+        cx.argument(1)
+    }
+
+    let error: Handle<JsFunction> = cx.global("Error")?;
+    let prepare: Handle<JsValue> = error.prop(cx, "prepareStackTrace").get()?;
+    let call_sites = JsFunction::new(cx, call_sites)?;
+
+    error.prop(cx, "prepareStackTrace").set(call_sites)?;
+
+    let frames = cx.try_catch(|cx| {
+        let err = cx.error("")?;
+        let stack = err.prop(cx, "stack").get::<Handle<JsValue>>()?;
+
+        match stack.downcast::<JsArray, _>(cx) {
+            Ok(stack) => stack_frames(cx, stack),
+            Err(_) => Ok(Vec::new()),
+        }
+    });
+
+    error.prop(cx, "prepareStackTrace").set(prepare)?;
+
+    frames.or_else(|err| cx.throw(err))
+}
+
+fn stack_frames<'cx>(cx: &mut Cx<'cx>, call_sites: Handle<JsArray>) -> NeonResult<Vec<StackFrame>> {
+    fn string<'cx>(cx: &mut Cx<'cx>, v: Handle<'cx, JsValue>) -> Option<String> {
+        let v = v.downcast::<JsString, _>(cx).ok()?;
+        Some(v.value(cx)).filter(|v| !v.is_empty())
+    }
+
+    fn number<'cx>(cx: &mut Cx<'cx>, v: Handle<'cx, JsValue>) -> Option<u32> {
+        let v = v.downcast::<JsNumber, _>(cx).ok()?;
+        Some(v.value(cx) as u32)
+    }
+
+    call_sites
+        .to_vec(cx)?
+        .into_iter()
+        .map(|call_site| {
+            let call_site = call_site.downcast_or_throw::<JsObject, _>(cx)?;
+            let function_name = call_site.method(cx, "getFunctionName")?.call()?;
+            let file_name = call_site.method(cx, "getFileName")?.call()?;
+            let line = call_site.method(cx, "getLineNumber")?.call()?;
+            let column = call_site.method(cx, "getColumnNumber")?.call()?;
+
+            Ok(StackFrame {
+                function_name: string(cx, function_name),
+                file_name: string(cx, file_name),
+                line: number(cx, line),
+                column: number(cx, column),
+            })
+        })
+        .collect()
+}
+
+/// Creates an error with additional properties, such as the `code` property expected
+/// on errors thrown by Node-style APIs.
+///
+/// An `ErrorBuilder` is created with
+/// [`Context::error_builder`](crate::context::Context::error_builder).
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// fn open(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///     cx.error_builder(format!("ENOENT: no such file or directory, open '{path}'"))
+///         .code("ENOENT")
+///         .prop("errno", -2)?
+///         .prop("syscall", "open")?
+///         .prop("path", path)?
+///         .throw()
+/// }
+/// ```
+pub struct ErrorBuilder<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    msg: String,
+    code: Option<String>,
+    constructor: Option<Handle<'cx, JsFunction>>,
+    props: Vec<(String, Handle<'cx, JsValue>)>,
+}
+
+impl<'a, 'cx> ErrorBuilder<'a, 'cx> {
+    pub(crate) fn new(cx: &'a mut Cx<'cx>, msg: String) -> Self {
+        Self {
+            cx,
+            msg,
+            code: None,
+            constructor: None,
+            props: Vec::new(),
+        }
+    }
+
+    /// Sets the `code` property of the error.
+    pub fn code<S: Into<String>>(&mut self, code: S) -> &mut Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Sets the class of the error. Defaults to `Error`.
+    ///
+    /// **See also:** [`JsError::with_type`]
+    pub fn constructor(&mut self, constructor: Handle<'cx, JsFunction>) -> &mut Self {
+        self.constructor = Some(constructor);
+        self
+    }
+
+    /// Adds a property with a value converted from Rust.
+    ///
+    /// May throw an exception while converting the value.
+    pub fn prop<V: TryIntoJs<'cx>>(&mut self, key: &str, value: V) -> NeonResult<&mut Self> {
+        let value = value.try_into_js(self.cx)?.upcast();
+
+        self.props.push((key.to_owned(), value));
+
+        Ok(self)
+    }
+
+    /// Creates the error.
+    pub fn build(&mut self) -> JsResult<'cx, JsError> {
+        let cx = &mut *self.cx;
+        let code = self.code.as_deref().map(|code| cx.string(code));
+        let err = match self.constructor {
+            Some(constructor) => {
+                let err = JsError::with_type(cx, constructor, &self.msg)?;
+
+                if let Some(code) = code {
+                    err.prop(cx, "code").set(code)?;
+                }
+
+                err
+            }
+            None => {
+                let msg = cx.string(&self.msg);
+                let code = code.map_or(ptr::null_mut(), |code| code.to_local());
+
+                build(cx.env(), |out| unsafe {
+                    sys::error::new_error_with_code(cx.env().to_raw(), out, code, msg.to_local());
+                    true
+                })?
+            }
+        };
+
+        for (key, value) in &self.props {
+            err.prop(cx, key.as_str()).set(*value)?;
+        }
+
+        Ok(err)
+    }
+
+    /// Creates and throws the error.
+    pub fn throw<T>(&mut self) -> NeonResult<T> {
+        let err = self.build()?;
+        self.cx.throw(err)
+    }
 }
 
 pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
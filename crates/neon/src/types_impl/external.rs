@@ -0,0 +1,202 @@
+use std::{any, ops::Deref};
+
+use crate::{
+    context::{
+        internal::{ContextInternal, Env},
+        Context, Cx,
+    },
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    sys::{self, external, raw},
+    types::{boxed::Finalize, private::ValueInternal, Value},
+};
+
+use self::private::JsExternalInner;
+
+/// Rust data that may be stored in a [`JsExternal`].
+///
+/// The [`TYPE_TAG`](ExternalType::TYPE_TAG) identifies the type to every native module
+/// that shares it, independently of how each module was compiled. Tags should be
+/// generated randomly, e.g., as a UUID, and the upper 64 bits must not be zero.
+///
+/// # Safety
+///
+/// Every module that uses the same tag must agree on the layout of the type. Types
+/// shared with other modules should be `#[repr(C)]`, or only be read behind a pointer
+/// that all of the modules agree on. Changing the layout of the type requires a new tag.
+pub unsafe trait ExternalType: 'static {
+    /// A 128-bit tag unique to this type.
+    const TYPE_TAG: u128;
+}
+
+/// A JavaScript external value owning Rust data that can be shared with other
+/// native modules.
+///
+/// Unlike a [`JsBox`](crate::types::JsBox), which can only be unwrapped by the module
+/// that created it, a `JsExternal<T>` is tagged with [`ExternalType::TYPE_TAG`]. Any
+/// module, including other Neon modules and addons written in C or C++, can check the
+/// tag with [`napi_check_object_type_tag`](https://nodejs.org/api/n-api.html#napi_check_object_type_tag)
+/// and read the data with `napi_get_value_external`, which points directly at the `T`.
+///
+/// Downcasting checks the tag, so a value created by another module or with another
+/// tag fails to downcast instead of being reinterpreted as a `T`.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::{ExternalType, JsExternal};
+///
+/// #[repr(C)]
+/// struct Counter {
+///     count: std::sync::atomic::AtomicU32,
+/// }
+///
+/// impl Finalize for Counter {}
+///
+/// // Safety: `Counter` is `#[repr(C)]` and the tag is only used for this layout
+/// unsafe impl ExternalType for Counter {
+///     const TYPE_TAG: u128 = 0x6c0e_14a9_2f3b_4d1e_9a57_0c8d_e2b1_7f43;
+/// }
+///
+/// fn counter_new(mut cx: FunctionContext) -> JsResult<JsExternal<Counter>> {
+///     let counter = Counter { count: Default::default() };
+///
+///     Ok(JsExternal::new(&mut cx, counter))
+/// }
+///
+/// fn counter_increment(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let counter = cx.argument::<JsExternal<Counter>>(0)?;
+///     let count = counter.count.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+///
+///     Ok(cx.number(count))
+/// }
+/// ```
+#[repr(transparent)]
+pub struct JsExternal<T: ExternalType>(JsExternalInner<T>);
+
+mod private {
+    pub struct JsExternalInner<T> {
+        pub(super) local: crate::sys::raw::Local,
+        // Safety: Only exposed as part of a `Handle` tied to a `Context` lifetime; see `JsBox`
+        pub(super) raw_data: *const T,
+    }
+}
+
+impl<T> Clone for JsExternalInner<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for JsExternalInner<T> {}
+
+impl<T> std::fmt::Debug for JsExternalInner<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "JsExternal<{}>", any::type_name::<T>())
+    }
+}
+
+impl<T: ExternalType> std::fmt::Debug for JsExternal<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+fn type_tag<T: ExternalType>() -> sys::TypeTag {
+    sys::TypeTag {
+        lower: T::TYPE_TAG as u64,
+        upper: (T::TYPE_TAG >> 64) as u64,
+    }
+}
+
+impl<T: ExternalType + Finalize> JsExternal<T> {
+    /// Constructs a new `JsExternal` containing `value`, tagged with `T::TYPE_TAG`.
+    ///
+    /// `Finalize::finalize` is called with the value immediately before it is garbage
+    /// collected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the upper 64 bits of the tag are zero.
+    pub fn new<'cx, C: Context<'cx>>(cx: &mut C, value: T) -> Handle<'cx, Self> {
+        fn finalizer<U: Finalize>(env: raw::Env, data: U) {
+            Cx::with_context(Env::from(env), move |mut cx| data.finalize(&mut cx));
+        }
+
+        let tag = type_tag::<T>();
+
+        // `napi_check_object_type_tag` never matches a tag with an upper value of zero
+        assert_ne!(
+            tag.upper, 0,
+            "the upper 64 bits of a type tag must not be zero"
+        );
+
+        let env = cx.env().to_raw();
+        let local = unsafe { external::create_tagged(env, value, &tag, finalizer::<T>) };
+
+        unsafe { Handle::new_internal(Self::from_local(cx.env(), local)) }
+    }
+}
+
+impl<'cx, T: ExternalType> Handle<'cx, JsExternal<T>> {
+    /// Gets a reference to the inner value of a [`JsExternal`], with the lifetime
+    /// _safely_ extended to `'cx`.
+    pub fn as_inner(&self) -> &'cx T {
+        // # Safety
+        // JS values associated with an in-scope `Context` *cannot* be garbage collected.
+        unsafe { &*self.0.raw_data }
+    }
+}
+
+impl<T: ExternalType> Deref for JsExternal<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // # Safety
+        // The data lives at least as long as the `JsExternal` is in scope and only
+        // immutable references can be obtained.
+        unsafe { &*self.0.raw_data }
+    }
+}
+
+unsafe impl<T: ExternalType> TransparentNoCopyWrapper for JsExternal<T> {
+    type Inner = JsExternalInner<T>;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl<T: ExternalType> ValueInternal for JsExternal<T> {
+    fn name() -> &'static str {
+        any::type_name::<Self>()
+    }
+
+    fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
+        Self::downcast(cx, other).is_some()
+    }
+
+    fn downcast<Other: Value>(cx: &mut Cx, other: &Other) -> Option<Self> {
+        let local = other.to_local();
+        let tag = type_tag::<T>();
+        let raw_data = unsafe { external::deref_tagged(cx.env().to_raw(), local, &tag)? };
+
+        Some(Self(JsExternalInner { local, raw_data }))
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0.local
+    }
+
+    unsafe fn from_local(env: Env, local: raw::Local) -> Self {
+        let raw_data = external::deref_tagged(env.to_raw(), local, &type_tag::<T>())
+            .expect("Failed to unwrap tagged napi_external");
+
+        Self(JsExternalInner { local, raw_data })
+    }
+}
+
+impl<T: ExternalType> Value for JsExternal<T> {}
+
+impl<T: ExternalType> Object for JsExternal<T> {}
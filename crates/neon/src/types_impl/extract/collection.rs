@@ -0,0 +1,245 @@
+use std::{collections::HashMap, error, fmt};
+
+#[cfg(feature = "napi-6")]
+use std::hash::{BuildHasher, Hash};
+
+use crate::{
+    context::{Context, Cx},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    types::{
+        extract::{private, TryFromJs, TryIntoJs, TypeExpected},
+        JsArray, JsError, JsObject, JsValue, Value,
+    },
+};
+
+/// Wrapper for converting between a [`Vec`] and a [`JsArray`]
+///
+/// A bare `Vec` of numbers converts to and from a typed array. `Array` instead
+/// converts each element with [`TryFromJs`] or [`TryIntoJs`], so it may hold any
+/// convertible type.
+///
+/// ```
+/// use neon::types::extract::Array;
+///
+/// #[neon::export]
+/// fn shout(Array(words): Array<String>) -> Array<String> {
+///     Array(words.into_iter().map(|w| w.to_uppercase()).collect())
+/// }
+/// ```
+pub struct Array<T>(pub Vec<T>);
+
+impl<'cx, T> TryFromJs<'cx> for Array<T>
+where
+    T: TryFromJs<'cx>,
+{
+    type Error = CollectionError<JsArray, T::Error>;
+
+    fn try_from_js(
+        cx: &mut Cx<'cx>,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, Self::Error>> {
+        let v = match v.downcast::<JsArray, _>(cx) {
+            Ok(v) => v,
+            Err(_) => return Ok(Err(CollectionError::Type(TypeExpected::new()))),
+        };
+
+        let mut values = Vec::new();
+
+        for (i, v) in v.to_vec(cx)?.into_iter().enumerate() {
+            match T::try_from_js(cx, v)? {
+                Ok(v) => values.push(v),
+                Err(error) => return Ok(Err(CollectionError::element(i, error))),
+            }
+        }
+
+        Ok(Ok(Array(values)))
+    }
+}
+
+impl<'cx, T> TryIntoJs<'cx> for Array<T>
+where
+    T: TryIntoJs<'cx>,
+{
+    type Value = JsArray;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        let values = self
+            .0
+            .into_iter()
+            .map(|v| v.try_into_js(cx).map(|v| v.upcast::<JsValue>()))
+            .collect::<NeonResult<Vec<_>>>()?;
+
+        cx.array_from_iter(values)
+    }
+}
+
+impl<T> private::Sealed for Array<T> {}
+
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+/// Extracts the own enumerable string keyed properties of an object
+impl<'cx, K, V, S> TryFromJs<'cx> for HashMap<K, V, S>
+where
+    K: TryFromJs<'cx> + Eq + Hash,
+    V: TryFromJs<'cx>,
+    S: BuildHasher + Default,
+{
+    type Error = CollectionError<JsObject, V::Error>;
+
+    fn try_from_js(
+        cx: &mut Cx<'cx>,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, Self::Error>> {
+        let obj = match v.downcast::<JsObject, _>(cx) {
+            Ok(v) => v,
+            Err(_) => return Ok(Err(CollectionError::Type(TypeExpected::new()))),
+        };
+
+        let mut map = HashMap::default();
+
+        for key in obj.get_own_property_names(cx)?.to_vec(cx)? {
+            let k = match K::try_from_js(cx, key)? {
+                Ok(k) => k,
+                Err(_) => {
+                    let key = key.to_string(cx)?.value(cx);
+
+                    return Ok(Err(CollectionError::Key { key }));
+                }
+            };
+            let v = obj.get_value(cx, key)?;
+
+            match V::try_from_js(cx, v)? {
+                Ok(v) => {
+                    map.insert(k, v);
+                }
+                Err(error) => {
+                    let key = key.to_string(cx)?.value(cx);
+
+                    return Ok(Err(CollectionError::element(key, error)));
+                }
+            }
+        }
+
+        Ok(Ok(map))
+    }
+}
+
+/// Creates an object with a property for each entry
+///
+/// The properties are defined rather than assigned, so a key such as `__proto__`
+/// becomes an own property instead of changing the prototype.
+impl<'cx, K, V, S> TryIntoJs<'cx> for HashMap<K, V, S>
+where
+    K: AsRef<str>,
+    V: TryIntoJs<'cx>,
+{
+    type Value = JsObject;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        let entries = self
+            .into_iter()
+            .map(|(k, v)| Ok((k, v.try_into_js(cx)?.upcast::<JsValue>())))
+            .collect::<NeonResult<Vec<_>>>()?;
+
+        let entries = entries
+            .iter()
+            .map(|(k, v)| (k.as_ref(), *v))
+            .collect::<Vec<_>>();
+
+        let obj = cx.empty_object();
+
+        obj.define_many(cx, &entries)?;
+
+        Ok(obj)
+    }
+}
+
+impl<K, V, S> private::Sealed for HashMap<K, V, S> {}
+
+/// Error returned when a collection or one of its elements is not the type expected
+pub enum CollectionError<T: Value, E> {
+    /// The value was not a collection of the expected type
+    Type(TypeExpected<T>),
+    /// The element at `key` could not be extracted
+    Element { key: String, error: E },
+    /// The key `key` could not be extracted
+    Key { key: String },
+}
+
+impl<T: Value, E> CollectionError<T, E> {
+    fn element(key: impl ToString, error: E) -> Self {
+        Self::Element {
+            key: key.to_string(),
+            error,
+        }
+    }
+}
+
+impl<T: Value, E: fmt::Display> fmt::Display for CollectionError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Type(err) => fmt::Display::fmt(err, f),
+            Self::Element { key, error } => write!(f, "{key}: {error}"),
+            Self::Key { key } => write!(f, "{key}: unexpected key"),
+        }
+    }
+}
+
+impl<T: Value, E: fmt::Debug> fmt::Debug for CollectionError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Type(err) => f.debug_tuple("Type").field(err).finish(),
+            Self::Element { key, error } => f
+                .debug_struct("Element")
+                .field("key", key)
+                .field("error", error)
+                .finish(),
+            Self::Key { key } => f.debug_struct("Key").field("key", key).finish(),
+        }
+    }
+}
+
+impl<T: Value, E: error::Error> error::Error for CollectionError<T, E> {}
+
+/// Element errors are converted to JavaScript and, if they are objects, annotated
+/// with a `key` property. Keys of nested collections are joined with `.`, e.g., `a.1`.
+/// Key errors are converted to a `TypeError` with a `key` property.
+impl<'cx, T, E> TryIntoJs<'cx> for CollectionError<T, E>
+where
+    T: Value,
+    E: TryIntoJs<'cx>,
+{
+    type Value = JsValue;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        match self {
+            Self::Type(err) => err.try_into_js(cx).map(|v| v.upcast()),
+            Self::Element { key, error } => {
+                let error = error.try_into_js(cx)?.upcast::<JsValue>();
+
+                if let Ok(obj) = error.downcast::<JsObject, _>(cx) {
+                    let inner: Option<String> = obj.prop(cx, "key").get()?;
+                    let key = match inner {
+                        Some(inner) => format!("{key}.{inner}"),
+                        None => key,
+                    };
+
+                    obj.prop(cx, "key").set(key)?;
+                }
+
+                Ok(error)
+            }
+            Self::Key { key } => {
+                let error = JsError::type_error(cx, format!("{key}: unexpected key"))?;
+
+                error.prop(cx, "key").set(key)?;
+
+                Ok(error.upcast())
+            }
+        }
+    }
+}
+
+impl<T: Value, E> private::Sealed for CollectionError<T, E> {}
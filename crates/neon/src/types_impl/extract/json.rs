@@ -17,13 +17,13 @@
 use std::{error, fmt};
 
 use crate::{
-    context::{Context, Cx},
+    context::{Context, Cx, FunctionContext},
     handle::Handle,
     object::Object,
     result::{JsResult, NeonResult},
     types::{
         extract::{private, TryFromJs, TryIntoJs},
-        JsError, JsFunction, JsObject, JsString, JsValue,
+        JsArray, JsError, JsFunction, JsObject, JsString, JsValue,
     },
 };
 
@@ -83,6 +83,55 @@ fn parse<'cx>(cx: &mut Cx<'cx>, s: &str) -> JsResult<'cx, JsValue> {
     json_parse(cx)?.call(cx, s, [s])
 }
 
+// `JSON.parse` reviver that replaces each parsed object with a null-prototype copy
+fn dict_reviver(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let v = cx.argument::<JsValue>(1)?;
+
+    let Ok(obj) = v.downcast::<JsObject, _>(&mut cx) else {
+        return Ok(v);
+    };
+
+    if v.is_a::<JsArray, _>(&mut cx) {
+        return Ok(v);
+    }
+
+    let dict = cx.empty_dict()?;
+
+    #[cfg(feature = "napi-6")]
+    let object = crate::intrinsics::object(&mut cx)?;
+    #[cfg(not(feature = "napi-6"))]
+    let object = cx.global::<JsFunction>("Object")?;
+
+    object
+        .method(&mut cx, "assign")?
+        .arg(dict)?
+        .arg(obj)?
+        .call()
+}
+
+#[cfg(not(feature = "napi-6"))]
+fn json_dict_reviver<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsFunction> {
+    JsFunction::new(cx, dict_reviver)
+}
+
+#[cfg(feature = "napi-6")]
+fn json_dict_reviver<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsFunction> {
+    static REVIVER: LocalKey<Root<JsFunction>> = LocalKey::new();
+
+    REVIVER
+        .get_or_try_init(cx, |cx| {
+            JsFunction::new(cx, dict_reviver).map(|f| f.root(cx))
+        })
+        .map(|f| f.to_inner(cx))
+}
+
+fn parse_dict<'cx>(cx: &mut Cx<'cx>, s: &str) -> JsResult<'cx, JsValue> {
+    let s = cx.string(s).upcast();
+    let reviver = json_dict_reviver(cx)?.upcast();
+
+    json_parse(cx)?.call(cx, s, [s, reviver])
+}
+
 /// Wrapper for converting between `T` and [`JsValue`](crate::types::JsValue) by
 /// serializing with JSON.
 pub struct Json<T>(pub T);
@@ -118,6 +167,61 @@ where
 
 impl<T> private::Sealed for Json<T> {}
 
+/// Wrapper for converting between `T` and [`JsValue`](crate::types::JsValue) by
+/// serializing with JSON, creating objects with a `null` prototype.
+///
+/// Behaves like [`Json`], except that every object produced when converting to
+/// JavaScript is created as if by `Object.create(null)`. This is useful for map-like
+/// data with keys from untrusted input, where inheriting from `Object.prototype`
+/// could allow keys like `__proto__` or `constructor` to be confused with built-ins.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use neon::types::extract::json::JsonDict;
+///
+/// #[neon::export]
+/// fn count_words(text: String) -> JsonDict<HashMap<String, u32>> {
+///     let mut counts = HashMap::new();
+///
+///     for word in text.split_whitespace() {
+///         *counts.entry(word.to_string()).or_default() += 1;
+///     }
+///
+///     JsonDict(counts)
+/// }
+/// ```
+pub struct JsonDict<T>(pub T);
+
+impl<'cx, T> TryFromJs<'cx> for JsonDict<T>
+where
+    for<'de> T: serde::de::Deserialize<'de>,
+{
+    type Error = Error;
+
+    fn try_from_js(
+        cx: &mut Cx<'cx>,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, Self::Error>> {
+        Ok(Json::try_from_js(cx, v)?.map(|Json(v)| JsonDict(v)))
+    }
+}
+
+impl<'cx, T> TryIntoJs<'cx> for JsonDict<T>
+where
+    T: serde::Serialize,
+{
+    type Value = JsValue;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        let s = serde_json::to_string(&self.0).or_else(|err| cx.throw_error(err.to_string()))?;
+
+        parse_dict(cx, &s)
+    }
+}
+
+impl<T> private::Sealed for JsonDict<T> {}
+
 /// Error returned when a value is invalid JSON
 pub struct Error(serde_json::Error);
 
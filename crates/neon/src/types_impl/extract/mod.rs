@@ -112,23 +112,34 @@ pub use self::{
         ArrayBuffer, BigInt64Array, BigUint64Array, Buffer, Float32Array, Float64Array, Int16Array,
         Int32Array, Int8Array, Uint16Array, Uint32Array, Uint8Array,
     },
+    collection::{Array, CollectionError},
     error::{Error, TypeExpected},
     with::With,
 };
 
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-pub use self::json::Json;
+pub use self::json::{Json, JsonDict};
 
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod json;
 
+#[cfg(feature = "opentelemetry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "opentelemetry")))]
+pub use self::trace::TraceContext;
+
+#[cfg(feature = "opentelemetry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "opentelemetry")))]
+pub mod trace;
+
 mod boxed;
 mod buffer;
+mod collection;
 mod container;
 mod either;
 mod error;
+mod path;
 mod private;
 mod try_from_js;
 mod try_into_js;
@@ -188,6 +199,8 @@ impl<'cx, T> private::FromArgsInternal<'cx> for T
 where
     T: TryFromJs<'cx>,
 {
+    const ARITY: usize = 1;
+
     fn from_args(cx: &mut FunctionContext<'cx>) -> NeonResult<Self> {
         let (v,) = private::FromArgsInternal::from_args(cx)?;
 
@@ -220,6 +233,8 @@ macro_rules! from_args_impl {
         where
             $($ty: TryFromJs<'cx>,)*
         {
+            const ARITY: usize = [$(stringify!($ty),)*].len();
+
             fn from_args(cx: &mut FunctionContext<'cx>) -> NeonResult<Self> {
                 let [$($ty,)*] = cx.argv();
 
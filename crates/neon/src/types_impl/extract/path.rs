@@ -0,0 +1,197 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    context::{Context, Cx},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    types::{
+        buffer::TypedArray,
+        extract::{private, Error, TryFromJs, TryIntoJs},
+        JsBuffer, JsFunction, JsObject, JsString, JsUint8Array, JsValue, Value,
+    },
+};
+
+/// Extracts a path from a string, a `Buffer` or other `Uint8Array`, or a `URL` with the
+/// `file:` protocol, following the rules of the Node `fs` module.
+///
+/// On Unix, the bytes of a `Buffer` and the percent-encoded bytes of a URL are used as
+/// is, so paths that are not valid UTF-8 are preserved. On Windows, they must be
+/// valid UTF-8.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::path::PathBuf;
+///
+/// #[neon::export]
+/// fn file_len(path: PathBuf) -> Result<f64, neon::types::extract::Error> {
+///     Ok(std::fs::metadata(path)?.len() as f64)
+/// }
+/// ```
+impl<'cx> TryFromJs<'cx> for PathBuf {
+    type Error = Error;
+
+    fn try_from_js(
+        cx: &mut Cx<'cx>,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, Self::Error>> {
+        if let Ok(s) = v.downcast::<JsString, _>(cx) {
+            return Ok(Ok(PathBuf::from(s.value(cx))));
+        }
+
+        if let Ok(buf) = v.downcast::<JsUint8Array, _>(cx) {
+            return Ok(from_bytes(buf.as_slice(cx).to_vec()));
+        }
+
+        let url = cx.global::<JsFunction>("URL")?;
+
+        if v.instance_of(cx, url)? {
+            let url = v.downcast_or_throw::<JsObject, _>(cx)?;
+
+            return file_url_to_path(cx, url);
+        }
+
+        Ok(Err(Error::type_error(
+            "expected a path string, Buffer, or file URL",
+        )))
+    }
+}
+
+impl private::Sealed for PathBuf {}
+
+/// Converts a path to a string, or to a `Buffer` if the path is not valid Unicode.
+impl<'cx> TryIntoJs<'cx> for PathBuf {
+    type Value = JsValue;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        self.as_path().try_into_js(cx)
+    }
+}
+
+/// Converts a path to a string, or to a `Buffer` if the path is not valid Unicode.
+impl<'cx> TryIntoJs<'cx> for &Path {
+    type Value = JsValue;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        if let Some(s) = self.to_str() {
+            return Ok(cx.string(s).upcast());
+        }
+
+        into_buffer(cx, self)
+    }
+}
+
+impl private::Sealed for &Path {}
+
+#[cfg(unix)]
+fn from_bytes(bytes: Vec<u8>) -> Result<PathBuf, Error> {
+    use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+    Ok(PathBuf::from(OsString::from_vec(bytes)))
+}
+
+#[cfg(not(unix))]
+fn from_bytes(bytes: Vec<u8>) -> Result<PathBuf, Error> {
+    String::from_utf8(bytes)
+        .map(PathBuf::from)
+        .map_err(|_| Error::type_error("path is not valid UTF-8"))
+}
+
+#[cfg(unix)]
+fn into_buffer<'cx>(cx: &mut Cx<'cx>, path: &Path) -> JsResult<'cx, JsValue> {
+    use std::os::unix::ffi::OsStrExt;
+
+    Ok(JsBuffer::from_slice(cx, path.as_os_str().as_bytes())?.upcast())
+}
+
+#[cfg(not(unix))]
+fn into_buffer<'cx>(cx: &mut Cx<'cx>, path: &Path) -> JsResult<'cx, JsValue> {
+    let path = path.to_string_lossy();
+
+    Ok(JsBuffer::from_slice(cx, path.as_bytes())?.upcast())
+}
+
+// Equivalent to `url.fileURLToPath` in Node
+fn file_url_to_path<'cx>(
+    cx: &mut Cx<'cx>,
+    url: Handle<'cx, JsObject>,
+) -> NeonResult<Result<PathBuf, Error>> {
+    let protocol: String = url.prop(cx, "protocol").get()?;
+    let hostname: String = url.prop(cx, "hostname").get()?;
+    let pathname: String = url.prop(cx, "pathname").get()?;
+
+    if protocol != "file:" {
+        return Ok(Err(Error::type_error("URL must be of scheme file")));
+    }
+
+    Ok(path_from_url_parts(&hostname, &pathname))
+}
+
+#[cfg(not(windows))]
+fn path_from_url_parts(hostname: &str, pathname: &str) -> Result<PathBuf, Error> {
+    if !hostname.is_empty() {
+        return Err(Error::type_error("file URL host must be empty"));
+    }
+
+    if pathname.to_ascii_lowercase().contains("%2f") {
+        return Err(Error::type_error(
+            "file URL path must not include encoded / characters",
+        ));
+    }
+
+    from_bytes(percent_decode(pathname))
+}
+
+#[cfg(windows)]
+fn path_from_url_parts(hostname: &str, pathname: &str) -> Result<PathBuf, Error> {
+    let lower = pathname.to_ascii_lowercase();
+
+    if lower.contains("%2f") || lower.contains("%5c") {
+        return Err(Error::type_error(
+            "file URL path must not include encoded \\ or / characters",
+        ));
+    }
+
+    let path = from_bytes(percent_decode(pathname))?;
+    let path = path.to_string_lossy().replace('/', "\\");
+
+    // UNC path, e.g., `file://server/share` is `\\server\share`
+    if !hostname.is_empty() {
+        return Ok(PathBuf::from(format!("\\\\{hostname}{path}")));
+    }
+
+    // Drive letter path, e.g., `file:///C:/dir` is `C:\dir`
+    let bytes = path.as_bytes();
+
+    if bytes.len() < 3 || !bytes[1].is_ascii_alphabetic() || bytes[2] != b':' {
+        return Err(Error::type_error("file URL path must be absolute"));
+    }
+
+    Ok(PathBuf::from(&path[1..]))
+}
+
+fn percent_decode(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                decoded.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                decoded.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    decoded
+}
@@ -18,6 +18,9 @@ use crate::{
 pub trait Sealed {}
 
 pub trait FromArgsInternal<'cx>: Sized {
+    /// Number of JavaScript arguments consumed by the extractor
+    const ARITY: usize;
+
     fn from_args(cx: &mut FunctionContext<'cx>) -> NeonResult<Self>;
 
     fn from_args_opt(cx: &mut FunctionContext<'cx>) -> NeonResult<Option<Self>>;
@@ -47,6 +50,9 @@ impl<O: Object> Sealed for Root<O> {}
 
 impl<T> Sealed for Option<T> {}
 
+#[cfg(feature = "napi-6")]
+impl Sealed for crate::util::Change<'_> {}
+
 impl<T, E> Sealed for Result<T, E> {}
 
 impl<'cx, T> Sealed for Box<T> where T: TryIntoJs<'cx> {}
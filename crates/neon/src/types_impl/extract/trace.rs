@@ -0,0 +1,156 @@
+//! Propagate [OpenTelemetry] trace context between JavaScript and Rust
+//!
+//! JavaScript tracing libraries pass [W3C Trace Context][w3c] in carrier objects,
+//! such as the `headers` of an HTTP request. The [`TraceContext`] extractor reads a
+//! carrier into an [`opentelemetry::Context`] with the globally registered
+//! [propagator](opentelemetry::global::set_text_map_propagator) and writes a context
+//! back into a new carrier object. Native work can then be recorded as a child span
+//! with [`in_span`].
+//!
+//! ```
+//! use neon::prelude::*;
+//! use neon::types::extract::trace::{self, TraceContext};
+//!
+//! #[neon::export]
+//! fn checksum(cx: &mut Cx, TraceContext(parent): TraceContext, data: String) -> NeonResult<f64> {
+//!     trace::in_span(cx, "checksum", &parent, |_| {
+//!         Ok(data.bytes().fold(0u8, |a, b| a ^ b) as f64)
+//!     })
+//! }
+//! ```
+//!
+//! Spans are recorded with the global tracer named `"neon"`. For asynchronous work, the
+//! context can be moved into the future and attached with
+//! [`FutureExt::with_context`](opentelemetry::trace::FutureExt::with_context).
+//!
+//! [OpenTelemetry]: https://opentelemetry.io
+//! [w3c]: https://www.w3.org/TR/trace-context/
+
+use std::{borrow::Cow, collections::HashMap};
+
+use opentelemetry::{
+    global,
+    trace::{Status, TraceContextExt, Tracer},
+    Context,
+};
+
+use crate::{
+    context::{Context as _, Cx},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    types::{
+        extract::{private, TryFromJs, TryIntoJs, TypeExpected},
+        JsObject, JsString, JsValue,
+    },
+};
+
+/// Name of the global tracer used by [`in_span`]
+const TRACER_NAME: &str = "neon";
+
+/// Extract or inject an OpenTelemetry [`Context`] with a JavaScript carrier object
+///
+/// Only own properties with string values are read from the carrier. Property names
+/// are compared case-insensitively, as they are in HTTP headers.
+pub struct TraceContext(pub Context);
+
+impl<'cx> TryFromJs<'cx> for TraceContext {
+    type Error = TypeExpected<JsObject>;
+
+    fn try_from_js(
+        cx: &mut Cx<'cx>,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, Self::Error>> {
+        let carrier = match v.downcast::<JsObject, _>(cx) {
+            Ok(carrier) => carrier,
+            Err(_) => return Ok(Err(TypeExpected::new())),
+        };
+
+        Ok(Ok(Self(extract(cx, carrier)?)))
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for TraceContext {
+    type Value = JsObject;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        let carrier = cx.empty_object();
+
+        inject(cx, &self.0, carrier)?;
+
+        Ok(carrier)
+    }
+}
+
+impl private::Sealed for TraceContext {}
+
+/// Reads the trace context from the properties of a JavaScript carrier object
+pub fn extract<'cx>(cx: &mut Cx<'cx>, carrier: Handle<'cx, JsObject>) -> NeonResult<Context> {
+    let keys = carrier.get_own_property_names(cx)?.to_vec(cx)?;
+    let mut fields = HashMap::with_capacity(keys.len());
+
+    for key in keys {
+        let Ok(key) = key.downcast::<JsString, _>(cx) else {
+            continue;
+        };
+
+        let value: Handle<JsValue> = carrier.prop(cx, key).get()?;
+
+        if let Ok(value) = value.downcast::<JsString, _>(cx) {
+            fields.insert(key.value(cx).to_lowercase(), value.value(cx));
+        }
+    }
+
+    Ok(global::get_text_map_propagator(|propagator| {
+        propagator.extract(&fields)
+    }))
+}
+
+/// Writes the trace context to properties of a JavaScript carrier object
+pub fn inject<'cx>(
+    cx: &mut Cx<'cx>,
+    context: &Context,
+    carrier: Handle<'cx, JsObject>,
+) -> NeonResult<()> {
+    let mut fields = HashMap::new();
+
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(context, &mut fields);
+    });
+
+    for (key, value) in fields {
+        carrier.prop(cx, key.as_str()).set(value)?;
+    }
+
+    Ok(())
+}
+
+/// Runs `f` in a new span that is a child of `parent`
+///
+/// The span is the current OpenTelemetry context while `f` runs, so any Rust
+/// instrumentation called by `f` is recorded as its descendant. If `f` throws, the
+/// span status is set to an error. The span ends when `f` returns.
+pub fn in_span<'cx, T, F>(
+    cx: &mut Cx<'cx>,
+    name: impl Into<Cow<'static, str>>,
+    parent: &Context,
+    f: F,
+) -> NeonResult<T>
+where
+    F: FnOnce(&mut Cx<'cx>) -> NeonResult<T>,
+{
+    let span = global::tracer(TRACER_NAME).start_with_context(name, parent);
+    let context = parent.with_span(span);
+    let res = {
+        let _guard = context.clone().attach();
+        f(cx)
+    };
+    let span = context.span();
+
+    if res.is_err() {
+        span.set_status(Status::error("JavaScript exception"));
+    }
+
+    span.end();
+    res
+}
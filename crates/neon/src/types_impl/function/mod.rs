@@ -34,6 +34,7 @@ pub struct BindOptions<'a, 'cx: 'a> {
     pub(crate) cx: &'a mut Cx<'cx>,
     pub(crate) callee: Handle<'cx, JsValue>,
     pub(crate) this: Option<Handle<'cx, JsValue>>,
+    pub(crate) new_target: Option<Handle<'cx, JsValue>>,
     pub(crate) args: private::ArgsVec<'cx>,
 }
 
@@ -45,6 +46,18 @@ impl<'a, 'cx: 'a> BindOptions<'a, 'cx> {
         Ok(self)
     }
 
+    /// Set the value of [`new.target`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/new.target)
+    /// for a constructor call. The constructed object is created with the prototype of
+    /// `new_target` instead of the callee's.
+    ///
+    /// Only used by [`construct()`](BindOptions::construct). Constructing throws a
+    /// `TypeError` if `new_target` is not a constructor.
+    pub fn new_target<T: TryIntoJs<'cx>>(&mut self, new_target: T) -> NeonResult<&mut Self> {
+        let v = new_target.try_into_js(self.cx)?;
+        self.new_target = Some(v.upcast());
+        Ok(self)
+    }
+
     /// Replaces the arguments list with the given arguments.
     pub fn args<A: TryIntoArguments<'cx>>(&mut self, a: A) -> NeonResult<&mut Self> {
         self.args = a.try_into_args_vec(self.cx)?;
@@ -90,10 +103,35 @@ impl<'a, 'cx: 'a> BindOptions<'a, 'cx> {
     /// Make the function call as a constructor. If the function returns without throwing, the
     /// result value is converted to a Rust value with `TryFromJs::from_js`.
     pub fn construct<R: TryFromJs<'cx>>(&mut self) -> NeonResult<R> {
-        let v: Handle<JsValue> = unsafe { self.callee.try_construct(self.cx, &self.args)? };
+        let v: Handle<JsValue> = match self.new_target {
+            Some(new_target) => self.reflect_construct(new_target)?,
+            None => unsafe { self.callee.try_construct(self.cx, &self.args)? },
+        };
+
         R::from_js(self.cx, v)
     }
 
+    // Node-API does not accept a `new.target` when constructing, so the call is
+    // forwarded to `Reflect.construct(callee, args, newTarget)`
+    fn reflect_construct(&mut self, new_target: Handle<'cx, JsValue>) -> JsResult<'cx, JsValue> {
+        #[cfg(feature = "napi-6")]
+        let construct = crate::intrinsics::reflect_construct(self.cx)?;
+        #[cfg(not(feature = "napi-6"))]
+        let construct: Handle<JsFunction> = self
+            .cx
+            .global::<JsObject>("Reflect")?
+            .prop(self.cx, "construct")
+            .get()?;
+        let args = self.cx.array_from_iter(self.args.iter().copied())?;
+
+        construct
+            .bind(self.cx)
+            .arg(self.callee)?
+            .arg(args)?
+            .arg(new_target)?
+            .call()
+    }
+
     /// Make the function call for side effect, discarding the result value. This method is
     /// preferable to [`call()`](BindOptions::call) when the result value isn't needed,
     /// since it doesn't require specifying a result type.
@@ -0,0 +1,192 @@
+use crate::{
+    context::{
+        internal::{ContextInternal, Env},
+        Context, Cx,
+    },
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types_impl::{private::ValueInternal, JsFunction, JsObject, JsUndefined, JsValue, Value},
+};
+
+/// The type of JavaScript
+/// [`MessagePort`](https://nodejs.org/api/worker_threads.html#class-messageport)
+/// objects.
+///
+/// A `MessagePort` is one end of a two-way channel for exchanging
+/// [structured clones](https://developer.mozilla.org/docs/Web/API/Web_Workers_API/Structured_clone_algorithm)
+/// of values with another port, which may be in a different worker thread. A pair of
+/// connected ports is created with [`JsMessagePort::channel`], or ports may be passed in
+/// from JavaScript, e.g., `parentPort` or a port received in a message.
+///
+/// Node-API does not provide direct support for message ports, so these operations
+/// use the built-in `MessageChannel` and `MessagePort` constructors. `MessagePort` is
+/// captured when the module is loaded, so replacing the global does not affect which
+/// values are recognized as ports.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsMessagePort;
+///
+/// // Replies to each message with its string representation
+/// fn serve(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let port = cx.argument::<JsMessagePort>(0)?;
+///     let reply = port.root(&mut cx);
+///
+///     port.on_message(&mut cx, move |cx, message| {
+///         let message = message.to_string(cx)?;
+///
+///         reply.to_inner(cx).post_message(cx, message)
+///     })?;
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsMessagePort(raw::Local);
+
+impl JsMessagePort {
+    /// Creates a pair of connected ports, equivalent to `new MessageChannel()`.
+    pub fn channel<'a, C: Context<'a>>(
+        cx: &mut C,
+    ) -> NeonResult<(Handle<'a, Self>, Handle<'a, Self>)> {
+        let cx = cx.cx_mut();
+        let channel = crate::intrinsics::message_channel(cx)?.construct(cx, [])?;
+        let port1 = channel.prop(cx, "port1").get()?;
+        let port2 = channel.prop(cx, "port2").get()?;
+
+        Ok((port1, port2))
+    }
+
+    /// Posts a structured clone of `message` to the other port.
+    pub fn post_message<'a, C, V>(&self, cx: &mut C, message: Handle<'a, V>) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        V: Value,
+    {
+        self.post(cx.cx_mut(), message.upcast(), None)
+    }
+
+    /// Posts a structured clone of `message` to the other port, transferring ownership
+    /// of the objects in `transfer`, e.g., `ArrayBuffer`s or other ports, instead of
+    /// copying them. Transferred objects are no longer usable by the sender.
+    pub fn post_message_with_transfer<'a, C, V>(
+        &self,
+        cx: &mut C,
+        message: Handle<'a, V>,
+        transfer: &[Handle<'a, JsObject>],
+    ) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        V: Value,
+    {
+        let cx = cx.cx_mut();
+        let transfer = cx.array_from_iter(transfer.iter().map(|v| v.upcast::<JsValue>()))?;
+
+        self.post(cx, message.upcast(), Some(transfer.upcast()))
+    }
+
+    fn post<'cx>(
+        &self,
+        cx: &mut Cx<'cx>,
+        message: Handle<'cx, JsValue>,
+        transfer: Option<Handle<'cx, JsValue>>,
+    ) -> NeonResult<()> {
+        let mut post_message = self.handle().method(cx, "postMessage")?;
+
+        post_message.arg(message)?;
+
+        if let Some(transfer) = transfer {
+            post_message.arg(transfer)?;
+        }
+
+        post_message.exec()
+    }
+
+    /// Calls `f` with each message received by the port.
+    ///
+    /// Listening for messages starts the port, which keeps the event loop alive until
+    /// the port is closed. An exception thrown by `f` is reported as an uncaught
+    /// exception.
+    pub fn on_message<'a, C, F>(&self, cx: &mut C, f: F) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        F: for<'cx> Fn(&mut Cx<'cx>, Handle<'cx, JsValue>) -> NeonResult<()> + 'static,
+    {
+        let cx = cx.cx_mut();
+        let listener = JsFunction::new(cx, move |mut cx| -> JsResult<JsUndefined> {
+            let message = cx.argument::<JsValue>(0)?;
+
+            f(&mut cx, message)?;
+
+            Ok(cx.undefined())
+        })?;
+
+        self.handle()
+            .method(cx, "on")?
+            .args(("message", listener))?
+            .exec()
+    }
+
+    /// Closes the port, disconnecting it from the other port.
+    pub fn close<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<()> {
+        self.handle().method(cx.cx_mut(), "close")?.exec()
+    }
+
+    fn handle<'cx>(&self) -> Handle<'cx, Self> {
+        Handle::new_internal(Self(self.0))
+    }
+}
+
+unsafe impl TransparentNoCopyWrapper for JsMessagePort {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsMessagePort {
+    fn name() -> &'static str {
+        "JsMessagePort"
+    }
+
+    fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
+        let env = cx.env().to_raw();
+
+        // Leave an exception that is already pending for the caller
+        if unsafe { sys::error::is_throwing(env) } {
+            return false;
+        }
+
+        // `instanceof` can call `Symbol.hasInstance`, which may throw. A type check
+        // cannot fail, so the exception is cleared and the value is not a port.
+        let is_port = match crate::intrinsics::message_port(cx) {
+            Ok(constructor) => unsafe {
+                sys::tag::instance_of(env, other.to_local(), constructor.to_local())
+            },
+            Err(_) => Err(sys::Status::PendingException),
+        };
+
+        is_port.unwrap_or_else(|_| {
+            unsafe { sys::error::clear_exception(env) };
+            false
+        })
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        Self(h)
+    }
+}
+
+impl Value for JsMessagePort {}
+
+impl Object for JsMessagePort {}
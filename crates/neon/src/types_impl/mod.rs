@@ -1,5 +1,6 @@
 // See types_docs.rs for top-level module API docs.
 
+pub mod atom;
 #[cfg(feature = "napi-6")]
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
 pub mod bigint;
@@ -8,9 +9,16 @@ pub mod buffer;
 #[cfg(feature = "napi-5")]
 pub(crate) mod date;
 pub(crate) mod error;
+#[cfg(feature = "napi-8")]
+pub(crate) mod external;
 pub mod extract;
 pub mod function;
+#[cfg(feature = "napi-6")]
+pub(crate) mod message_port;
+pub mod number;
 pub(crate) mod promise;
+#[cfg(feature = "napi-6")]
+pub(crate) mod proxy;
 
 pub(crate) mod private;
 pub(crate) mod utf8;
@@ -36,6 +44,7 @@ use crate::{
     result::{JsResult, NeonResult, ResultExt, Throw},
     sys::{self, raw},
     types::{
+        buffer::{Binary, TypedArray},
         function::{BindOptions, CallOptions, ConstructOptions},
         private::ValueInternal,
         utf8::Utf8,
@@ -49,13 +58,33 @@ pub use self::{
         JsInt16Array, JsInt32Array, JsInt8Array, JsTypedArray, JsUint16Array, JsUint32Array,
         JsUint8Array,
     },
-    error::JsError,
+    error::{ErrorBuilder, JsError, StackFrame},
     promise::{Deferred, JsPromise},
 };
 
+#[cfg(feature = "napi-4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+pub use self::promise::Responder;
+
 #[cfg(feature = "napi-5")]
 pub use self::date::{DateError, DateErrorKind, JsDate};
 
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::buffer::shared_array::JsSharedArrayBuffer;
+
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::message_port::JsMessagePort;
+
+#[cfg(feature = "napi-8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+pub use self::external::{ExternalType, JsExternal};
+
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub use self::proxy::{JsProxy, ProxyHandler};
+
 #[cfg(all(feature = "napi-5", feature = "futures"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
 pub use self::promise::JsFuture;
@@ -101,6 +130,40 @@ pub trait Value: ValueInternal {
         JsValue::new_internal(self.to_local())
     }
 
+    /// Tests whether this value is an instance of `constructor`, equivalent to the
+    /// JavaScript [`instanceof`][instanceof] operator.
+    ///
+    /// Throws a `TypeError` if `constructor` is not a function, or propagates the exception
+    /// if a custom `Symbol.hasInstance` method throws.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn is_map(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    /// let value: Handle<JsValue> = cx.argument(0)?;
+    /// let map = cx.global::<JsFunction>("Map")?;
+    /// let is_map = value.instance_of(&mut cx, map)?;
+    ///
+    /// Ok(cx.boolean(is_map))
+    /// # }
+    /// ```
+    ///
+    /// [instanceof]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/instanceof
+    fn instance_of<'cx, C, V>(&self, cx: &mut C, constructor: Handle<V>) -> NeonResult<bool>
+    where
+        C: Context<'cx>,
+        V: Value,
+    {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            match sys::tag::instance_of(env, self.to_local(), constructor.to_local()) {
+                Ok(result) => Ok(result),
+                Err(sys::Status::PendingException) => Err(Throw::new()),
+                _ => cx.throw_error("`instanceof` check failed"),
+            }
+        }
+    }
+
     #[cfg(feature = "sys")]
     #[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
     /// Get a raw reference to the wrapped Node-API value.
@@ -613,6 +676,291 @@ impl JsString {
         }
     }
 
+    /// Read this string as UTF-16 into `buf`, replacing its contents and reusing its
+    /// allocation.
+    pub(crate) fn read_utf16<'a, C: Context<'a>>(&self, cx: &mut C, buf: &mut Vec<u16>) {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let capacity = sys::string::utf16_len(env, self.to_local()) + 1;
+
+            buf.clear();
+            buf.reserve(capacity);
+
+            let len = sys::string::data_utf16(env, buf.as_mut_ptr(), capacity, self.to_local());
+            buf.set_len(len);
+        }
+    }
+
+    /// Convert this JavaScript string into a [`Vec<u8>`] encoded as
+    /// [Latin-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1).
+    ///
+    /// Each UTF-16 code unit is converted to a single byte, so this is only lossless for
+    /// strings whose characters are all in the range `U+0000..=U+00FF`. Code units outside
+    /// that range are truncated. For such strings, this is faster than
+    /// [`value`](JsString::value) since no UTF-8 encoding is required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn to_latin1(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let s = cx.string("café");
+    /// assert_eq!(s.to_latin1(&mut cx), b"caf\xe9");
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    pub fn to_latin1<'a, C: Context<'a>>(&self, cx: &mut C) -> Vec<u8> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let capacity = sys::string::latin1_len(env, self.to_local()) + 1;
+            let mut buffer: Vec<u8> = Vec::with_capacity(capacity);
+            let len = sys::string::data_latin1(env, buffer.as_mut_ptr(), capacity, self.to_local());
+            buffer.set_len(len);
+            buffer
+        }
+    }
+
+    /// Creates a new `JsString` value from UTF-16 code units.
+    ///
+    /// The code units are copied as-is; unlike converting through a Rust [`String`],
+    /// unpaired surrogates are preserved. This makes `from_utf16` the inverse of
+    /// [`to_utf16`](JsString::to_utf16).
+    ///
+    /// This method panics if the string is longer than the maximum string size allowed
+    /// by the JavaScript engine. See [`JsString::try_from_utf16`] for a non-panicking
+    /// alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn from_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    /// let raw = "hello 🥹".encode_utf16().collect::<Vec<_>>();
+    /// let s = JsString::from_utf16(&mut cx, &raw);
+    /// assert_eq!(s.value(&mut cx), "hello 🥹");
+    /// # Ok(s)
+    /// # }
+    /// ```
+    pub fn from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> Handle<'a, JsString> {
+        JsString::try_from_utf16(cx, val).unwrap()
+    }
+
+    /// Tries to create a new `JsString` value from UTF-16 code units.
+    ///
+    /// Returns `Err(StringOverflow)` if the string is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    pub fn try_from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> StringResult<'a> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+
+            if sys::string::new_utf16(&mut local, env, val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
+    /// Creates a new `JsString` value from
+    /// [Latin-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1) encoded bytes.
+    ///
+    /// Every byte is a valid Latin-1 character, so arbitrary binary data round-trips
+    /// through [`to_latin1`](JsString::to_latin1).
+    ///
+    /// This method panics if the string is longer than the maximum string size allowed
+    /// by the JavaScript engine. See [`JsString::try_from_latin1`] for a non-panicking
+    /// alternative.
+    pub fn from_latin1<'a, C: Context<'a>>(cx: &mut C, val: &[u8]) -> Handle<'a, JsString> {
+        JsString::try_from_latin1(cx, val).unwrap()
+    }
+
+    /// Tries to create a new `JsString` value from Latin-1 encoded bytes.
+    ///
+    /// Returns `Err(StringOverflow)` if the string is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    pub fn try_from_latin1<'a, C: Context<'a>>(cx: &mut C, val: &[u8]) -> StringResult<'a> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+
+            if sys::string::new_latin1(&mut local, env, val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
+    /// Creates a new `JsString` value that references a Rust string without copying it,
+    /// if the string is ASCII.
+    ///
+    /// JavaScript engines can only reference Latin-1 or UTF-16 data directly, so strings
+    /// containing other characters are copied, the same as [`JsString::try_new`]. Large
+    /// generated text, such as SQL, HTML, or JSON, is often entirely ASCII.
+    ///
+    /// `data` is dropped after the string is garbage collected. The engine may also
+    /// decide to copy a short string, in which case `data` is dropped immediately.
+    ///
+    /// Returns `Err(StringOverflow)` if the string is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn render(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let rows = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    ///     let html = (0..rows).map(|i| format!("<tr><td>{i}</td></tr>")).collect::<String>();
+    ///
+    ///     JsString::external(&mut cx, html).or_throw(&mut cx)
+    /// }
+    /// ```
+    #[cfg(feature = "napi-experimental")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-experimental")))]
+    pub fn external<'a, C, T>(cx: &mut C, data: T) -> StringResult<'a>
+    where
+        C: Context<'a>,
+        T: AsRef<str> + Send + 'static,
+    {
+        struct Ascii<T>(T);
+
+        impl<T: AsRef<str>> AsRef<[u8]> for Ascii<T> {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref().as_bytes()
+            }
+        }
+
+        if !data.as_ref().is_ascii() {
+            return JsString::try_new(cx, data);
+        }
+
+        JsString::external_latin1(cx, Ascii(data))
+    }
+
+    /// Creates a new `JsString` value that references
+    /// [Latin-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1) encoded bytes without
+    /// copying them.
+    ///
+    /// The same as [`JsString::external`], `data` is dropped after the string is garbage
+    /// collected or immediately if the engine copied it.
+    #[cfg(feature = "napi-experimental")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-experimental")))]
+    pub fn external_latin1<'a, C, T>(cx: &mut C, data: T) -> StringResult<'a>
+    where
+        C: Context<'a>,
+        T: AsRef<[u8]> + Send + 'static,
+    {
+        let env = cx.env().to_raw();
+        let len = data.as_ref().len();
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+
+            if sys::string::new_external_latin1(&mut local, env, data) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(len))
+            }
+        }
+    }
+
+    /// Creates a new `JsString` value that references UTF-16 code units without copying
+    /// them.
+    ///
+    /// The same as [`JsString::external`], `data` is dropped after the string is garbage
+    /// collected or immediately if the engine copied it.
+    #[cfg(feature = "napi-experimental")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-experimental")))]
+    pub fn external_utf16<'a, C, T>(cx: &mut C, data: T) -> StringResult<'a>
+    where
+        C: Context<'a>,
+        T: AsRef<[u16]> + Send + 'static,
+    {
+        let env = cx.env().to_raw();
+        let len = data.as_ref().len();
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+
+            if sys::string::new_external_utf16(&mut local, env, data) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(len))
+            }
+        }
+    }
+
+    /// Returns the portion of this string between the UTF-16 code unit indices `start`
+    /// (inclusive) and `end` (exclusive).
+    ///
+    /// This behaves like JavaScript's
+    /// [`String.prototype.substring`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substring):
+    /// indices past the end of the string are clamped and, if `start` is greater than `end`,
+    /// the two are swapped. The string contents are never copied into Rust.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn substring(mut cx: FunctionContext) -> JsResult<JsString> {
+    /// let s = cx.string("hello 🥹");
+    /// let hello = s.substring(&mut cx, 0, 5)?;
+    /// assert_eq!(hello.value(&mut cx), "hello");
+    /// # Ok(hello)
+    /// # }
+    /// ```
+    pub fn substring<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        start: usize,
+        end: usize,
+    ) -> JsResult<'a, JsString> {
+        let env = cx.env().to_raw();
+        let args = match unsafe { sys::string::substring_args(env, start, end) } {
+            Ok(args) => args,
+            Err(_) => return Err(unsafe { Throw::new() }),
+        };
+
+        string_method(cx, "substring", self.to_local(), &args)
+    }
+
+    /// Concatenates `parts` into a new string, equivalent to joining them with `+` in
+    /// JavaScript.
+    ///
+    /// The concatenation is performed by the JavaScript engine, so large strings are not
+    /// copied into Rust and back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn concat(mut cx: FunctionContext) -> JsResult<JsString> {
+    /// let greeting = cx.string("hello, ");
+    /// let name = cx.argument::<JsString>(0)?;
+    ///
+    /// JsString::concat(&mut cx, &[greeting, name])
+    /// # }
+    /// ```
+    pub fn concat<'a, C: Context<'a>>(
+        cx: &mut C,
+        parts: &[Handle<JsString>],
+    ) -> JsResult<'a, JsString> {
+        let mut result = cx.string("");
+
+        for chunk in parts.chunks(private::V8_ARGC_LIMIT) {
+            let rest = chunk.iter().map(|s| s.to_local()).collect::<Vec<_>>();
+
+            result = string_method(cx, "concat", result.to_local(), &rest)?;
+        }
+
+        Ok(result)
+    }
+
     /// Creates a new `JsString` value from a Rust string by copying its contents.
     ///
     /// This method panics if the string is longer than the maximum string size allowed
@@ -682,6 +1030,39 @@ impl JsString {
     }
 }
 
+// Calls the built-in `String.prototype` method `name` with `this` as the receiver.
+// The method is captured when the module is loaded, so user code cannot replace it.
+fn string_method<'a, C: Context<'a>>(
+    cx: &mut C,
+    name: &str,
+    this: raw::Local,
+    args: &[raw::Local],
+) -> JsResult<'a, JsString> {
+    #[cfg(feature = "napi-6")]
+    let method = match name {
+        "concat" => crate::intrinsics::string_concat(cx)?,
+        "substring" => crate::intrinsics::string_substring(cx)?,
+        _ => unreachable!("unknown String.prototype method"),
+    };
+    #[cfg(not(feature = "napi-6"))]
+    let method: Handle<JsFunction> = cx
+        .global::<JsFunction>("String")?
+        .prop(cx.cx_mut(), "prototype")
+        .get::<Handle<JsObject>>()?
+        .prop(cx.cx_mut(), name)
+        .get()?;
+
+    let env = cx.env();
+
+    match unsafe { sys::string::call_method(env.to_raw(), this, method.to_local(), args) } {
+        Ok(local) => Ok(Handle::new_internal(unsafe {
+            JsString::from_local(env, local)
+        })),
+        Err(sys::Status::PendingException) => Err(unsafe { Throw::new() }),
+        Err(_) => cx.throw_type_error(format!("String.prototype.{name} did not return a string")),
+    }
+}
+
 /// The type of JavaScript
 /// [number](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#primitive_values)
 /// primitives.
@@ -818,6 +1199,25 @@ impl JsObject {
         JsObject::new_internal(c.env())
     }
 
+    /// Creates a new empty object with a `null` prototype, equivalent to
+    /// `Object.create(null)`.
+    ///
+    /// Objects without a prototype do not inherit properties such as `toString` or
+    /// `__proto__` and are safer to use as dictionaries keyed by untrusted input.
+    ///
+    /// **See also:** [`Context::empty_dict`]
+    pub fn dict<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsObject> {
+        let cx = cx.cx_mut();
+        let null = cx.null();
+
+        #[cfg(feature = "napi-6")]
+        let object = crate::intrinsics::object(cx)?;
+        #[cfg(not(feature = "napi-6"))]
+        let object = cx.global::<JsFunction>("Object")?;
+
+        object.method(cx, "create")?.arg(null)?.call()
+    }
+
     pub(crate) fn new_internal<'a>(env: Env) -> Handle<'a, JsObject> {
         JsObject::build(|out| unsafe { sys::object::new(out, env.to_raw()) })
     }
@@ -900,6 +1300,151 @@ impl JsArray {
         }
     }
 
+    /// Constructs a new array containing `values`, equivalent to the JavaScript
+    /// expression `[...values]`.
+    ///
+    /// **See also:** [`Context::array_from_iter`]
+    pub fn from_slice<'a, C: Context<'a>, V: Value>(
+        cx: &mut C,
+        values: &[Handle<V>],
+    ) -> JsResult<'a, JsArray> {
+        cx.array_from_iter(values.iter().copied())
+    }
+
+    /// Constructs a new array of numbers from `values`.
+    ///
+    /// The numbers are copied into the array with a single call to `Array.from`,
+    /// which is significantly faster than setting each element for large arrays.
+    ///
+    /// **See also:** [`Context::array_from_f64s`]
+    pub fn from_f64s<'a, C: Context<'a>>(cx: &mut C, values: &[f64]) -> JsResult<'a, JsArray> {
+        let values = JsFloat64Array::from_slice(cx, values)?.upcast();
+
+        array_from_typed(cx.cx_mut(), values)
+    }
+
+    /// Constructs a new array of numbers from `values`.
+    ///
+    /// **See also:** [`JsArray::from_f64s`], [`Context::array_from_i32s`]
+    pub fn from_i32s<'a, C: Context<'a>>(cx: &mut C, values: &[i32]) -> JsResult<'a, JsArray> {
+        let values = JsInt32Array::from_slice(cx, values)?.upcast();
+
+        array_from_typed(cx.cx_mut(), values)
+    }
+
+    /// Copies the elements of the array into a new [`Vec`] of `f64`, by constructing a
+    /// `Float64Array` from it rather than with one property access per element.
+    ///
+    /// Elements are converted as if by the JavaScript `Number()` function, so elements
+    /// that are not numbers are coerced and holes become `NaN`. Coercing an object
+    /// may call its `valueOf` method, which may throw.
+    pub fn to_f64_vec<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Vec<f64>> {
+        let cx = cx.cx_mut();
+        let array = self.as_value(cx);
+        let values = typed_from_array::<f64>(cx, "Float64Array", array)?;
+
+        Ok(values.as_slice(cx).to_vec())
+    }
+
+    /// Copies the elements of the array into a new [`Vec`] of `i32`.
+    ///
+    /// Elements are converted as if by the JavaScript expression `x | 0`, so numbers
+    /// outside the range of `i32` wrap around and fractions are truncated. See
+    /// [`to_f64_vec`](JsArray::to_f64_vec) for the conversion of other values.
+    pub fn to_i32_vec<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Vec<i32>> {
+        let cx = cx.cx_mut();
+        let array = self.as_value(cx);
+        let values = typed_from_array::<i32>(cx, "Int32Array", array)?;
+
+        Ok(values.as_slice(cx).to_vec())
+    }
+
+    /// Appends `value` to the end of the array, like the JavaScript expression
+    /// [`this.push(value)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push).
+    /// Returns the new length of the array.
+    ///
+    /// The built-in `Array.prototype.push` is called, so the array's own `push`
+    /// property is never used, even if it has been replaced.
+    ///
+    /// Throws if the array cannot be extended, e.g., if it is frozen.
+    pub fn push<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        value: Handle<V>,
+    ) -> NeonResult<u32> {
+        self.push_locals(cx, &[value.to_local()])?;
+
+        Ok(self.len_inner(cx.env()))
+    }
+
+    // Appends `values` with the built-in `Array.prototype.push`, in as few calls as
+    // the engine's argument limit allows
+    pub(crate) fn push_locals<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        values: &[raw::Local],
+    ) -> NeonResult<()> {
+        #[cfg(feature = "napi-6")]
+        let push = crate::intrinsics::array_push(cx)?;
+        #[cfg(not(feature = "napi-6"))]
+        let push: Handle<JsFunction> = cx
+            .global::<JsFunction>("Array")?
+            .prop(cx.cx_mut(), "prototype")
+            .get::<Handle<JsObject>>()?
+            .prop(cx.cx_mut(), "push")
+            .get()?;
+
+        let env = cx.env().to_raw();
+
+        unsafe {
+            if sys::array::push(env, self.to_local(), push.to_local(), values) {
+                Ok(())
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    /// Calls `f` with the index and value of each element of the array, stopping at
+    /// the first error.
+    ///
+    /// Unlike [`to_vec`](JsArray::to_vec), the length is read once before iterating.
+    /// Elements appended by `f` are not visited, and elements removed by `f` are read
+    /// as `undefined`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let arr = cx.argument::<JsArray>(0)?;
+    ///     let mut sum = 0.0;
+    ///
+    ///     arr.try_for_each(&mut cx, |cx, _i, v| {
+    ///         sum += v.downcast_or_throw::<JsNumber, _>(cx)?.value(cx);
+    ///         Ok(())
+    ///     })?;
+    ///
+    ///     Ok(cx.number(sum))
+    /// }
+    /// ```
+    pub fn try_for_each<'a, C, F>(&self, cx: &mut C, mut f: F) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        F: FnMut(&mut C, u32, Handle<'a, JsValue>) -> NeonResult<()>,
+    {
+        let env = cx.env();
+        let array = self.to_local();
+
+        for i in 0..self.len_inner(env) {
+            let v = build(env, |out| unsafe {
+                sys::object::get_index(out, env.to_raw(), array, i)
+            })?;
+
+            f(cx, i, v)?;
+        }
+
+        Ok(())
+    }
+
     fn len_inner(&self, env: Env) -> u32 {
         unsafe { sys::array::len(env.to_raw(), self.to_local()) }
     }
@@ -920,6 +1465,37 @@ impl JsArray {
 
 impl Value for JsArray {}
 
+// Bulk conversions between arrays and typed arrays cross into the engine once,
+// instead of once per element
+fn array_from_typed<'cx>(cx: &mut Cx<'cx>, values: Handle<'cx, JsValue>) -> JsResult<'cx, JsArray> {
+    #[cfg(feature = "napi-6")]
+    let from = crate::intrinsics::array_from(cx)?;
+    #[cfg(not(feature = "napi-6"))]
+    let from: Handle<JsFunction> = cx.global::<JsFunction>("Array")?.prop(cx, "from").get()?;
+
+    from.bind(cx).arg(values)?.call()
+}
+
+fn typed_from_array<'cx, T: Binary>(
+    cx: &mut Cx<'cx>,
+    constructor: &str,
+    array: Handle<'cx, JsValue>,
+) -> JsResult<'cx, JsTypedArray<T>>
+where
+    JsTypedArray<T>: Value,
+{
+    #[cfg(feature = "napi-6")]
+    let constructor = match constructor {
+        "Float64Array" => crate::intrinsics::float64_array(cx)?,
+        "Int32Array" => crate::intrinsics::int32_array(cx)?,
+        _ => unreachable!("unknown typed array constructor"),
+    };
+    #[cfg(not(feature = "napi-6"))]
+    let constructor = cx.global::<JsFunction>(constructor)?;
+
+    constructor.bind(cx).arg(array)?.construct()
+}
+
 unsafe impl TransparentNoCopyWrapper for JsArray {
     type Inner = raw::Local;
 
@@ -1056,6 +1632,47 @@ impl JsFunction {
         Self::new_internal(cx, f, name)
     }
 
+    #[cfg(feature = "napi-5")]
+    /// Returns a new `JsFunction` implemented by `f`, a closure that may mutate its
+    /// captured state.
+    ///
+    /// Since `f` requires exclusive access to its state, it cannot be called while it is
+    /// already running. If the function is called reentrantly, for example by JavaScript
+    /// that `f` calls, the inner call throws an `Error` instead of calling `f`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn counter(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    ///     let mut count = 0;
+    ///
+    ///     JsFunction::new_mut(&mut cx, move |mut cx| {
+    ///         count += 1;
+    ///
+    ///         Ok(cx.number(count))
+    ///     })
+    /// }
+    /// ```
+    pub fn new_mut<'a, C, F, V>(cx: &mut C, f: F) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: FnMut(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        let name = any::type_name::<F>();
+        let f = std::cell::RefCell::new(f);
+
+        Self::new_internal(
+            cx,
+            move |mut cx| match f.try_borrow_mut() {
+                Ok(mut f) => f(cx).map(|v| v.upcast::<JsValue>()),
+                Err(_) => cx.throw_error(
+                    "Function created with `JsFunction::new_mut` was called reentrantly",
+                ),
+            },
+            name,
+        )
+    }
+
     #[cfg(not(feature = "napi-5"))]
     /// Returns a new `JsFunction` implemented by `f` with specified name
     pub fn with_name<'a, C, U>(
@@ -1081,11 +1698,62 @@ impl JsFunction {
         Self::new_internal(cx, f, name)
     }
 
+    // Returns a function with the same JavaScript `name` as `JsFunction::new`, which is
+    // identified to interceptors by the key it is exported as
+    pub(crate) fn new_export<'a, C, F, V>(cx: &mut C, key: &str, f: F) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        let name = any::type_name::<F>();
+
+        Self::new_with_call_name(cx, f, name, key)
+    }
+
     fn new_internal<'a, C, F, V>(cx: &mut C, f: F, name: &str) -> JsResult<'a, JsFunction>
     where
         C: Context<'a>,
         F: Fn(FunctionContext) -> JsResult<V> + 'static,
         V: Value,
+    {
+        Self::new_with_call_name(cx, f, name, name)
+    }
+
+    fn new_with_call_name<'a, C, F, V>(
+        cx: &mut C,
+        f: F,
+        name: &str,
+        call_name: &str,
+    ) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        let f = Self::callback(f, call_name);
+
+        unsafe {
+            if let Ok(raw) = sys::fun::new(cx.env().to_raw(), name, f) {
+                Ok(Handle::new_internal(JsFunction { raw }))
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    // Wraps `f` as a Node-API callback that calls it with a `FunctionContext`
+    #[cfg_attr(
+        not(any(feature = "napi-6", feature = "tracing")),
+        allow(unused_variables)
+    )]
+    pub(crate) fn callback<F, V>(
+        f: F,
+        name: &str,
+    ) -> impl Fn(raw::Env, sys::bindings::CallbackInfo) -> raw::Local + 'static
+    where
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
     {
         use std::panic::AssertUnwindSafe;
         use std::ptr;
@@ -1093,13 +1761,33 @@ impl JsFunction {
         use crate::context::CallbackInfo;
         use crate::types::error::convert_panics;
 
-        let f = move |env: raw::Env, info| {
+        #[cfg(feature = "napi-6")]
+        let call_info = crate::context::CallInfo::new(name);
+
+        #[cfg(feature = "tracing")]
+        let span_name: Box<str> = name.into();
+
+        move |env: raw::Env, info| {
             let env = env.into();
             let info = unsafe { CallbackInfo::new(info) };
 
+            #[cfg(feature = "tracing")]
+            let _span = crate::tracing::call(&span_name).entered();
+
             FunctionContext::with(env, &info, |cx| {
-                convert_panics(env, AssertUnwindSafe(|| f(cx)))
-                    .map(|v| v.to_local())
+                #[cfg(feature = "napi-6")]
+                let call = || {
+                    let body = |cx| f(cx).map(|v| v.upcast());
+
+                    crate::context::interceptor::dispatch(cx, &call_info, &body)
+                };
+
+                #[cfg(not(feature = "napi-6"))]
+                let call = || f(cx);
+
+                convert_panics(env, AssertUnwindSafe(call))
+                    // A deferred response replaces the returned value with its promise
+                    .map(|v| info.take_response().unwrap_or_else(|| v.to_local()))
                     // We do not have a Js Value to return, most likely due to an exception.
                     // If we are in a throwing state, constructing a Js Value would be invalid.
                     // While not explicitly written, the Node-API documentation includes many examples
@@ -1107,14 +1795,6 @@ impl JsFunction {
                     // https://nodejs.org/api/n-api.html#n_api_napi_create_function
                     .unwrap_or_else(|_: Throw| ptr::null_mut())
             })
-        };
-
-        unsafe {
-            if let Ok(raw) = sys::fun::new(cx.env().to_raw(), name, f) {
-                Ok(Handle::new_internal(JsFunction { raw }))
-            } else {
-                Err(Throw::new())
-            }
         }
     }
 }
@@ -1136,6 +1816,46 @@ impl JsFunction {
         unsafe { self.try_call(cx, this, args) }
     }
 
+    /// Calls this function with the elements of `args` spread as its arguments,
+    /// equivalent to the JavaScript expression `f.apply(this, args)`.
+    ///
+    /// The arguments are spread by the JavaScript engine, without copying each
+    /// element into Rust.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Equivalent to `(f, args) => f(...args)`
+    /// fn spread(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let f = cx.argument::<JsFunction>(0)?;
+    ///     let args = cx.argument::<JsArray>(1)?;
+    ///     let this = cx.undefined();
+    ///
+    ///     f.call_spread(&mut cx, this, args)
+    /// }
+    /// ```
+    pub fn call_spread<'a, 'b, C: Context<'a>, T: Value>(
+        &self,
+        cx: &mut C,
+        this: Handle<'b, T>,
+        args: Handle<'b, JsArray>,
+    ) -> JsResult<'a, JsValue> {
+        #[cfg(feature = "napi-6")]
+        let apply = crate::intrinsics::reflect_apply(cx)?;
+        #[cfg(not(feature = "napi-6"))]
+        let apply = cx
+            .global::<JsObject>("Reflect")?
+            .prop(cx.cx_mut(), "apply")
+            .get::<Handle<JsFunction>>()?;
+        let undefined = cx.undefined();
+        let args = [
+            JsValue::new_internal(self.to_local()),
+            JsValue::new_internal(this.to_local()),
+            JsValue::new_internal(args.to_local()),
+        ];
+
+        apply.call(cx, undefined, args)
+    }
+
     /// Calls this function for side effect, discarding its result.
     ///
     /// **See also:** [`JsFunction::bind`].
@@ -1153,6 +1873,40 @@ impl JsFunction {
         Ok(())
     }
 
+    /// Calls this function, catching a thrown exception.
+    ///
+    /// Returns `Err` with the exception if the function throws. Equivalent to calling
+    /// [`JsFunction::call`] inside of [`Context::try_catch`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Calls a callback, returning the error message if it throws
+    /// fn call_or_message(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let f = cx.argument::<JsFunction>(0)?;
+    ///     let this = cx.undefined();
+    ///
+    ///     match f.call_catching(&mut cx, this, []) {
+    ///         Ok(v) => Ok(v),
+    ///         Err(err) => match err.downcast::<JsError, _>(&mut cx) {
+    ///             Ok(err) => err.prop(&mut cx, "message").get(),
+    ///             Err(_) => Ok(err),
+    ///         },
+    ///     }
+    /// }
+    /// ```
+    pub fn call_catching<'a, 'b, C: Context<'a>, T, AS>(
+        &self,
+        cx: &mut C,
+        this: Handle<'b, T>,
+        args: AS,
+    ) -> Result<Handle<'a, JsValue>, Handle<'a, JsValue>>
+    where
+        T: Value,
+        AS: AsRef<[Handle<'b, JsValue>]>,
+    {
+        cx.try_catch(|cx| self.call(cx, this, args))
+    }
+
     /// Calls this function as a constructor.
     ///
     /// **See also:** [`JsFunction::bind`].
@@ -1193,6 +1947,7 @@ impl JsFunction {
             cx,
             callee,
             this: None,
+            new_target: None,
             args: smallvec![],
         }
     }
@@ -0,0 +1,159 @@
+//! Types for working with [`JsNumber`].
+//!
+//! A JavaScript number is a 64-bit float, which can only represent every integer in
+//! the range `-(2^53 - 1)..=2^53 - 1`, the
+//! [safe integers](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger).
+//! Converting a larger 64-bit integer with [`Context::number`] silently rounds it to
+//! the nearest representable number. The conversions in this module check the range
+//! instead.
+
+use std::{error, fmt};
+
+use crate::{
+    context::Context,
+    handle::Handle,
+    result::{NeonResult, ResultExt},
+    types::JsNumber,
+};
+
+#[cfg(feature = "napi-6")]
+use crate::types::{JsBigInt, JsValue};
+
+/// The largest integer that can be represented exactly by a [`JsNumber`],
+/// `Number.MAX_SAFE_INTEGER`.
+pub const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// The smallest integer that can be represented exactly by a [`JsNumber`],
+/// `Number.MIN_SAFE_INTEGER`.
+pub const MIN_SAFE_INTEGER: i64 = -MAX_SAFE_INTEGER;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Indicates a lossless conversion between a JavaScript number or `BigInt` and a
+/// Rust integer could not be performed.
+///
+/// Failures include:
+/// * A [`JsNumber`] that is not a safe integer
+/// * A Rust integer outside of the safe integer range
+/// * Negative sign on an unsigned int
+/// * Overflow or underflow of an int read from a `BigInt`
+pub struct RangeError<T>(pub(crate) T);
+
+impl<T> RangeError<T> {
+    /// Get the value that could not be converted. A value read from a `BigInt`
+    /// may be truncated, sign extended or wrapped.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Display for RangeError<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Loss of precision ({})", self.0)
+    }
+}
+
+impl<T> error::Error for RangeError<T> where T: fmt::Display + fmt::Debug {}
+
+impl<T, E> ResultExt<T> for Result<T, RangeError<E>>
+where
+    E: fmt::Display,
+{
+    fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
+        self.or_else(|err| cx.throw_range_error(err.to_string()))
+    }
+}
+
+impl JsNumber {
+    /// Creates a number from an [`i64`], failing if it is not a safe integer.
+    ///
+    /// **See also:** [`Context::number_from_i64`]
+    pub fn from_i64<'cx, C>(cx: &mut C, n: i64) -> Result<Handle<'cx, Self>, RangeError<i64>>
+    where
+        C: Context<'cx>,
+    {
+        if (MIN_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&n) {
+            Ok(JsNumber::new(cx, n as f64))
+        } else {
+            Err(RangeError(n))
+        }
+    }
+
+    /// Creates a number from a [`u64`], failing if it is not a safe integer.
+    ///
+    /// **See also:** [`Context::number_from_u64`]
+    pub fn from_u64<'cx, C>(cx: &mut C, n: u64) -> Result<Handle<'cx, Self>, RangeError<u64>>
+    where
+        C: Context<'cx>,
+    {
+        if n <= MAX_SAFE_INTEGER as u64 {
+            Ok(JsNumber::new(cx, n as f64))
+        } else {
+            Err(RangeError(n))
+        }
+    }
+
+    /// Reads an [`i64`] from a number.
+    ///
+    /// Fails if the number is not a safe integer, e.g., if it has a fractional part.
+    /// The error contains the value of the number.
+    pub fn to_i64<'cx, C>(&self, cx: &mut C) -> Result<i64, RangeError<f64>>
+    where
+        C: Context<'cx>,
+    {
+        let n = self.value(cx);
+
+        if is_safe_integer(n) {
+            Ok(n as i64)
+        } else {
+            Err(RangeError(n))
+        }
+    }
+
+    /// Reads a [`u64`] from a number.
+    ///
+    /// Fails if the number is negative or is not a safe integer. The error contains
+    /// the value of the number.
+    pub fn to_u64<'cx, C>(&self, cx: &mut C) -> Result<u64, RangeError<f64>>
+    where
+        C: Context<'cx>,
+    {
+        let n = self.value(cx);
+
+        if is_safe_integer(n) && n >= 0.0 {
+            Ok(n as u64)
+        } else {
+            Err(RangeError(n))
+        }
+    }
+}
+
+fn is_safe_integer(n: f64) -> bool {
+    n.trunc() == n && n.abs() <= MAX_SAFE_INTEGER as f64
+}
+
+#[cfg(feature = "napi-6")]
+/// Converts an [`i64`] to a number if it is a safe integer, or else to a `BigInt`.
+pub(crate) fn number_or_bigint_from_i64<'cx, C>(cx: &mut C, n: i64) -> Handle<'cx, JsValue>
+where
+    C: Context<'cx>,
+{
+    match JsNumber::from_i64(cx, n) {
+        Ok(n) => n.upcast(),
+        Err(_) => JsBigInt::from_i64(cx, n).upcast(),
+    }
+}
+
+#[cfg(feature = "napi-6")]
+/// Converts a [`u64`] to a number if it is a safe integer, or else to a `BigInt`.
+pub(crate) fn number_or_bigint_from_u64<'cx, C>(cx: &mut C, n: u64) -> Handle<'cx, JsValue>
+where
+    C: Context<'cx>,
+{
+    match JsNumber::from_u64(cx, n) {
+        Ok(n) => n.upcast(),
+        Err(_) => JsBigInt::from_u64(cx, n).upcast(),
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
 use super::JsValue;
 
 // Maximum number of function arguments in V8.
-const V8_ARGC_LIMIT: usize = 65535;
+pub(super) const V8_ARGC_LIMIT: usize = 65535;
 
 pub(crate) unsafe fn prepare_call<'a, 'b, C: Context<'a>>(
     cx: &mut C,
@@ -5,7 +5,11 @@ use crate::{
         internal::{ContextInternal, Env},
         Context,
     },
-    handle::{internal::TransparentNoCopyWrapper, Handle},
+    handle::{
+        internal::TransparentNoCopyWrapper,
+        root::{instance_id, InstanceId},
+        Handle,
+    },
     object::Object,
     result::JsResult,
     sys::{self, no_panic::FailureBoundary, raw},
@@ -16,6 +20,7 @@ use crate::{
 use crate::{
     context::Cx,
     event::{Channel, JoinHandle, SendError},
+    types::{extract::TryIntoJs, JsValue},
 };
 
 #[cfg(feature = "napi-6")]
@@ -24,11 +29,14 @@ use crate::{
     sys::tsfn::ThreadsafeFunction,
 };
 
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::{Kind, Tracker};
+
 #[cfg(all(feature = "napi-5", feature = "futures"))]
 use {
     crate::event::{JoinError, SendThrow},
     crate::result::NeonResult,
-    crate::types::{JsFunction, JsValue},
+    crate::types::JsFunction,
     std::future::Future,
     std::pin::Pin,
     std::sync::Mutex,
@@ -143,8 +151,11 @@ impl JsPromise {
         let (deferred, promise) = unsafe { sys::promise::create(cx.env().to_raw()) };
         let deferred = Deferred {
             internal: Some(NodeApiDeferred(deferred)),
+            instance_id: instance_id(cx),
             #[cfg(feature = "napi-6")]
             drop_queue: InstanceData::drop_queue(cx),
+            #[cfg(feature = "diagnostics")]
+            _tracker: Tracker::new(Kind::Deferred),
         };
 
         (deferred, Handle::new_internal(JsPromise(promise)))
@@ -281,13 +292,20 @@ impl Object for JsPromise {}
 /// cause a panic. On Node-API 6+, the associated [`JsPromise`] will be automatically
 /// rejected.
 ///
+/// A [`Deferred`] may be sent across threads, but may only be settled in the
+/// JavaScript environment that created it. Settling it with a [`Context`] from
+/// another instance of the module, such as a worker thread, will panic.
+///
 /// # Examples
 ///
 /// See [`JsPromise`], [`JsFuture`].
 pub struct Deferred {
     internal: Option<NodeApiDeferred>,
+    instance_id: InstanceId,
     #[cfg(feature = "napi-6")]
     drop_queue: Arc<ThreadsafeFunction<DropData>>,
+    #[cfg(feature = "diagnostics")]
+    _tracker: Tracker,
 }
 
 impl Deferred {
@@ -298,7 +316,7 @@ impl Deferred {
         C: Context<'a>,
     {
         unsafe {
-            sys::promise::resolve(cx.env().to_raw(), self.into_inner(), value.to_local());
+            sys::promise::resolve(cx.env().to_raw(), self.into_inner(cx), value.to_local());
         }
     }
 
@@ -309,7 +327,7 @@ impl Deferred {
         C: Context<'a>,
     {
         unsafe {
-            sys::promise::reject(cx.env().to_raw(), self.into_inner(), value.to_local());
+            sys::promise::reject(cx.env().to_raw(), self.into_inner(cx), value.to_local());
         }
     }
 
@@ -342,7 +360,8 @@ impl Deferred {
     /// Settle the [`JsPromise`] by sending a closure across a [`Channel`][crate::event::Channel]
     /// to be executed on the main JavaScript thread.
     ///
-    /// Panics if there is a libuv error.
+    /// Panics if there is a libuv error. If the queue of a bounded channel is full, the
+    /// closure is dropped as with [`Channel::send`][crate::event::Channel::send].
     ///
     /// ```
     /// # use neon::prelude::*;
@@ -360,28 +379,35 @@ impl Deferred {
         V: Value,
         F: FnOnce(Cx) -> JsResult<V> + Send + 'static,
     {
-        self.try_settle_with(channel, complete).unwrap()
+        channel.send(move |cx| {
+            self.try_catch_settle(cx, complete);
+            Ok(())
+        })
     }
 
-    pub(crate) fn try_catch_settle<'a, C, V, F>(self, cx: C, f: F)
+    pub(crate) fn try_catch_settle<'a, C, V, F>(self, mut cx: C, f: F)
     where
         C: Context<'a>,
         V: Value,
         F: FnOnce(C) -> JsResult<'a, V>,
     {
+        let deferred = self.into_inner(&mut cx);
+
         unsafe {
-            BOUNDARY.catch_failure(
-                cx.env().to_raw(),
-                Some(self.into_inner()),
-                move |_| match f(cx) {
-                    Ok(value) => value.to_local(),
-                    Err(_) => ptr::null_mut(),
-                },
-            );
+            BOUNDARY.catch_failure(cx.env().to_raw(), Some(deferred), move |_| match f(cx) {
+                Ok(value) => value.to_local(),
+                Err(_) => ptr::null_mut(),
+            });
         }
     }
 
-    pub(crate) fn into_inner(mut self) -> sys::Deferred {
+    // Settling a promise from another environment is undefined behavior. On failure,
+    // `internal` is left in place so that `Drop` can clean up in the correct environment.
+    pub(crate) fn into_inner<'a, C: Context<'a>>(mut self, cx: &mut C) -> sys::Deferred {
+        if self.instance_id != instance_id(cx) {
+            panic!("Attempted to settle a `neon::types::Deferred` from the wrong module");
+        }
+
         self.internal.take().unwrap().0
     }
 }
@@ -432,6 +458,123 @@ impl Drop for Deferred {
     }
 }
 
+#[cfg(feature = "napi-4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+/// Completes the response of a function call after the function has returned.
+///
+/// A `Responder` is created with
+/// [`FunctionContext::deferred_response`](crate::context::FunctionContext::deferred_response),
+/// which causes the function to return a pending [`JsPromise`]. The `Responder` may be
+/// sent to any thread and is consumed when it responds, so the promise is settled
+/// exactly once.
+///
+/// If a `Responder` is dropped without responding, the promise is rejected with an
+/// `Error` describing the leak.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// fn read_config(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let responder = cx.deferred_response()?;
+///
+///     std::thread::spawn(move || match std::fs::read_to_string(path) {
+///         Ok(contents) => responder.resolve(contents),
+///         Err(err) => responder.reject(err.to_string()),
+///     });
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+pub struct Responder {
+    // Only `None` after responding
+    deferred: Option<Deferred>,
+    channel: Channel,
+}
+
+#[cfg(feature = "napi-4")]
+impl Responder {
+    pub(crate) fn new(deferred: Deferred, channel: Channel) -> Self {
+        Self {
+            deferred: Some(deferred),
+            channel,
+        }
+    }
+
+    /// Settles the response with the result of a closure executed on the JavaScript
+    /// thread. The promise is resolved with the returned value or rejected with a
+    /// thrown exception.
+    ///
+    /// Panics if there is a libuv error.
+    pub fn respond<V, F>(self, complete: F) -> JoinHandle<()>
+    where
+        V: Value,
+        F: FnOnce(Cx) -> JsResult<V> + Send + 'static,
+    {
+        self.try_respond(complete).unwrap()
+    }
+
+    /// Settles the response with the result of a closure executed on the JavaScript
+    /// thread.
+    ///
+    /// Usage is identical to [`Responder::respond`]. Returns a [`SendError`] if sending
+    /// the closure to the JavaScript thread fails, in which case the promise is rejected
+    /// as if the `Responder` had been dropped.
+    pub fn try_respond<V, F>(mut self, complete: F) -> Result<JoinHandle<()>, SendError>
+    where
+        V: Value,
+        F: FnOnce(Cx) -> JsResult<V> + Send + 'static,
+    {
+        let deferred = self.deferred.take().unwrap();
+
+        deferred.try_settle_with(&self.channel, complete)
+    }
+
+    /// Resolves the response with a value converted to JavaScript.
+    ///
+    /// Panics if there is a libuv error.
+    pub fn resolve<T>(self, value: T) -> JoinHandle<()>
+    where
+        T: for<'cx> TryIntoJs<'cx> + Send + 'static,
+    {
+        self.respond(move |mut cx| Ok(value.try_into_js(&mut cx)?.upcast::<JsValue>()))
+    }
+
+    /// Rejects the response with a value converted to JavaScript.
+    ///
+    /// Panics if there is a libuv error.
+    pub fn reject<E>(self, err: E) -> JoinHandle<()>
+    where
+        E: for<'cx> TryIntoJs<'cx> + Send + 'static,
+    {
+        self.respond(move |mut cx| {
+            let err = err.try_into_js(&mut cx)?;
+
+            cx.throw::<_, Handle<JsValue>>(err)
+        })
+    }
+}
+
+#[cfg(feature = "napi-4")]
+impl Drop for Responder {
+    fn drop(&mut self) {
+        // If `None`, the response has already been sent
+        let Some(deferred) = self.deferred.take() else {
+            return;
+        };
+
+        // If the channel is closed, the `Deferred` handles the leak when it is dropped
+        let _ = self.channel.try_send(move |mut cx| {
+            let err = cx.error("`neon::types::Responder` was dropped without responding")?;
+
+            deferred.reject(&mut cx, err);
+
+            Ok(())
+        });
+    }
+}
+
 #[cfg(all(feature = "napi-5", feature = "futures"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
 /// A type of JavaScript
@@ -0,0 +1,244 @@
+use crate::{
+    context::{internal::Env, Context, Cx, FunctionContext},
+    handle::{internal::TransparentNoCopyWrapper, Handle, Root},
+    intrinsics,
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::raw,
+    thread::LocalKey,
+    types_impl::{private::ValueInternal, JsBoolean, JsFunction, JsObject, JsValue, Value},
+};
+
+/// The type of JavaScript
+/// [`Proxy`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Proxy)
+/// objects.
+///
+/// A proxy forwards operations on it to the _traps_ of a handler object, falling back
+/// to the target object for operations without a trap. Proxies are useful for
+/// implementing objects with lazily computed or virtual properties. A handler with
+/// traps implemented in Rust can be created with [`ProxyHandler`].
+///
+/// Node-API cannot distinguish proxies from other objects, so only proxies created with
+/// [`JsProxy::new`] by the same module instance can be downcast to `JsProxy`. Proxies
+/// created by JavaScript with `new Proxy(..)`, or by another module, are not recognized;
+/// they can only be downcast to the type of any other object or function, e.g.,
+/// [`JsObject`].
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::{JsProxy, ProxyHandler};
+///
+/// // An object where every property is the upper case of its name
+/// fn shout(mut cx: FunctionContext) -> JsResult<JsProxy> {
+///     let target = cx.empty_object();
+///     let handler = ProxyHandler::new(&mut cx)
+///         .get(|cx, _target, key| match key.downcast::<JsString, _>(cx) {
+///             Ok(key) => {
+///                 let key = key.value(cx).to_uppercase();
+///
+///                 Ok(cx.string(key).upcast())
+///             }
+///             Err(_) => Ok(cx.undefined().upcast()),
+///         })?
+///         .build();
+///
+///     JsProxy::new(&mut cx, target, handler)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsProxy(raw::Local);
+
+impl JsProxy {
+    /// Creates a proxy for `target` with the traps of `handler`, equivalent to
+    /// `new Proxy(target, handler)`.
+    pub fn new<'a, C, T, H>(cx: &mut C, target: Handle<T>, handler: Handle<H>) -> JsResult<'a, Self>
+    where
+        C: Context<'a>,
+        T: Object,
+        H: Object,
+    {
+        let cx = cx.cx_mut();
+        let args = [target.upcast::<JsValue>(), handler.upcast()];
+        let proxy = intrinsics::proxy(cx)?.construct(cx, args)?;
+        let proxies = proxies(cx)?;
+
+        intrinsics::weak_set_add(cx)?
+            .bind(cx)
+            .this(proxies)?
+            .arg(proxy)?
+            .exec()?;
+
+        Ok(Handle::new_internal(Self(proxy.to_local())))
+    }
+}
+
+unsafe impl TransparentNoCopyWrapper for JsProxy {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsProxy {
+    fn name() -> &'static str {
+        "JsProxy"
+    }
+
+    fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
+        if !JsObject::is_typeof(cx, other) && !JsFunction::is_typeof(cx, other) {
+            return false;
+        }
+
+        let value = JsValue::new_internal(other.to_local());
+
+        // `WeakSet.prototype.has` is the original built-in, so no user code runs; any
+        // failure, e.g., from a missing intrinsic, is caught and treated as not a proxy
+        cx.try_catch(|cx| {
+            let proxies = proxies(cx)?;
+
+            intrinsics::weak_set_has(cx)?
+                .bind(cx)
+                .this(proxies)?
+                .arg(value)?
+                .call::<Handle<JsBoolean>>()
+                .map(|has| has.value(cx))
+        })
+        .unwrap_or(false)
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        Self(h)
+    }
+}
+
+impl Value for JsProxy {}
+
+impl Object for JsProxy {}
+
+/// Builds a proxy handler object with traps implemented by Rust closures.
+///
+/// The `target` passed to each trap is the target of the proxy, which may also be a
+/// function, since every function is an object. Traps that are not set forward the
+/// operation to the target.
+pub struct ProxyHandler<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    handler: Handle<'cx, JsObject>,
+}
+
+impl<'a, 'cx> ProxyHandler<'a, 'cx> {
+    /// Creates a handler without any traps.
+    pub fn new(cx: &'a mut Cx<'cx>) -> Self {
+        let handler = cx.empty_object();
+
+        Self { cx, handler }
+    }
+
+    /// Sets the [`get`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy/get)
+    /// trap, called with the target and the property key when a property is read.
+    pub fn get<F>(&mut self, f: F) -> NeonResult<&mut Self>
+    where
+        F: for<'b> Fn(
+                &mut Cx<'b>,
+                Handle<'b, JsObject>,
+                Handle<'b, JsValue>,
+            ) -> JsResult<'b, JsValue>
+            + 'static,
+    {
+        self.trap("get", move |mut cx| {
+            let target = target(&mut cx)?;
+            let key = cx.argument::<JsValue>(1)?;
+
+            f(&mut cx, target, key)
+        })
+    }
+
+    /// Sets the [`set`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy/set)
+    /// trap, called with the target, the property key, and the new value when a property
+    /// is assigned.
+    ///
+    /// Returning `false` indicates that the assignment failed, which throws a `TypeError`
+    /// in strict mode code.
+    pub fn set<F>(&mut self, f: F) -> NeonResult<&mut Self>
+    where
+        F: for<'b> Fn(
+                &mut Cx<'b>,
+                Handle<'b, JsObject>,
+                Handle<'b, JsValue>,
+                Handle<'b, JsValue>,
+            ) -> NeonResult<bool>
+            + 'static,
+    {
+        self.trap("set", move |mut cx| {
+            let target = target(&mut cx)?;
+            let key = cx.argument::<JsValue>(1)?;
+            let value = cx.argument::<JsValue>(2)?;
+            let success = f(&mut cx, target, key, value)?;
+
+            Ok(cx.boolean(success))
+        })
+    }
+
+    /// Sets the [`has`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy/has)
+    /// trap, called with the target and the property key by the `in` operator.
+    pub fn has<F>(&mut self, f: F) -> NeonResult<&mut Self>
+    where
+        F: for<'b> Fn(&mut Cx<'b>, Handle<'b, JsObject>, Handle<'b, JsValue>) -> NeonResult<bool>
+            + 'static,
+    {
+        self.trap("has", move |mut cx| {
+            let target = target(&mut cx)?;
+            let key = cx.argument::<JsValue>(1)?;
+            let has = f(&mut cx, target, key)?;
+
+            Ok(cx.boolean(has))
+        })
+    }
+
+    /// Returns the handler object.
+    pub fn build(&mut self) -> Handle<'cx, JsObject> {
+        self.handler
+    }
+
+    fn trap<F, V>(&mut self, name: &str, f: F) -> NeonResult<&mut Self>
+    where
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        let trap = JsFunction::new(self.cx, f)?;
+
+        self.handler.prop(self.cx, name).set(trap)?;
+
+        Ok(self)
+    }
+}
+
+// Reads the target of a proxy passed to a trap, which may be a function
+fn target<'cx>(cx: &mut FunctionContext<'cx>) -> JsResult<'cx, JsObject> {
+    let target = cx.argument::<JsValue>(0)?;
+
+    match target.downcast::<JsFunction, _>(cx) {
+        Ok(f) => Ok(f.upcast()),
+        Err(_) => target.downcast_or_throw(cx),
+    }
+}
+
+// Proxies created by `JsProxy::new`, used to recognize them when downcasting
+fn proxies<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsObject> {
+    static PROXIES: LocalKey<Root<JsObject>> = LocalKey::new();
+
+    PROXIES
+        .get_or_try_init(cx, |cx| {
+            intrinsics::weak_set(cx)?
+                .construct(cx, [])
+                .map(|set| set.root(cx))
+        })
+        .map(|set| set.to_inner(cx))
+}
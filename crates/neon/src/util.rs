@@ -0,0 +1,294 @@
+//! Utilities for working with JavaScript values.
+
+use std::collections::HashSet;
+
+use crate::{
+    context::{internal::ContextInternal, Context, Cx},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{
+        build, extract::TryIntoJs, private::ValueInternal, JsArray, JsFunction, JsObject, JsString,
+        JsValue,
+    },
+};
+
+/// Maximum depth of nested objects compared by default.
+pub const DEFAULT_MAX_DEPTH: usize = 16;
+
+/// Options for [`diff`].
+#[derive(Clone, Copy, Debug)]
+pub struct DiffOptions {
+    max_depth: usize,
+}
+
+impl DiffOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum depth of nested objects that are compared property by property.
+    ///
+    /// Objects nested deeper than `max_depth` are compared by identity. The properties of
+    /// the objects passed to [`diff`] are always compared. Defaults to
+    /// [`DEFAULT_MAX_DEPTH`].
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+/// A property that differs between two objects compared with [`diff`].
+#[derive(Clone, Debug)]
+pub struct Change<'cx> {
+    /// Property keys leading from the compared objects to the changed value.
+    pub path: Vec<String>,
+    /// The value before the change, or `None` if the property was added.
+    pub before: Option<Handle<'cx, JsValue>>,
+    /// The value after the change, or `None` if the property was removed.
+    pub after: Option<Handle<'cx, JsValue>>,
+}
+
+impl<'cx> Change<'cx> {
+    /// Returns the kind of change.
+    pub fn kind(&self) -> ChangeKind {
+        match (self.before, self.after) {
+            (None, _) => ChangeKind::Added,
+            (_, None) => ChangeKind::Removed,
+            _ => ChangeKind::Modified,
+        }
+    }
+}
+
+/// Converts to an object with `path`, `before`, and `after` properties, where a missing
+/// value is `undefined`.
+impl<'cx> TryIntoJs<'cx> for Change<'cx> {
+    type Value = JsObject;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        let path = self
+            .path
+            .iter()
+            .map(|key| cx.string(key))
+            .collect::<Vec<_>>();
+        let path = cx.array_from_iter(path)?;
+
+        cx.object_builder()
+            .prop("path", path)?
+            .prop("before", self.before)?
+            .prop("after", self.after)?
+            .build()
+    }
+}
+
+/// The kind of a [`Change`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The property only exists after the change.
+    Added,
+    /// The property only exists before the change.
+    Removed,
+    /// The property exists on both sides with different values.
+    Modified,
+}
+
+/// Compares two snapshots of an object and returns the properties that differ.
+///
+/// Own enumerable string-keyed properties are compared with
+/// [`Handle::same_value`]. When both sides of a property are arrays, or both are
+/// objects other than arrays and functions, they are compared recursively, up to
+/// [`DiffOptions::max_depth`]. Any other values that are not the same are reported as
+/// a single change.
+///
+/// Each pair of objects is compared at most once. If the same pair is reached again,
+/// e.g., through a cycle or a shared object, it is reported as a single change instead
+/// of being compared again.
+///
+/// Removed and modified properties are listed in the order of the keys of `before`,
+/// followed by the added properties in the order of the keys of `after`.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::util::{self, ChangeKind, DiffOptions};
+///
+/// fn changed_paths(mut cx: FunctionContext) -> JsResult<JsArray> {
+///     let before = cx.argument::<JsObject>(0)?;
+///     let after = cx.argument::<JsObject>(1)?;
+///     let changes = util::diff(&mut cx, before, after, DiffOptions::new())?;
+///     let paths = changes
+///         .iter()
+///         .filter(|change| change.kind() == ChangeKind::Modified)
+///         .map(|change| cx.string(change.path.join(".")))
+///         .collect::<Vec<_>>();
+///
+///     cx.array_from_iter(paths)
+/// }
+/// ```
+pub fn diff<'cx, C: Context<'cx>>(
+    cx: &mut C,
+    before: Handle<JsObject>,
+    after: Handle<JsObject>,
+    options: DiffOptions,
+) -> NeonResult<Vec<Change<'cx>>> {
+    let mut differ = Differ {
+        cx: cx.cx_mut(),
+        max_depth: options.max_depth,
+        path: Vec::new(),
+        visited: Vec::new(),
+        changes: Vec::new(),
+    };
+
+    differ.objects(before, after, 1)?;
+
+    Ok(differ.changes)
+}
+
+struct Differ<'a, 'cx> {
+    cx: &'a mut Cx<'cx>,
+    max_depth: usize,
+    // Path of the object currently being compared
+    path: Vec<String>,
+    // Pairs of objects that have already been compared property by property
+    visited: Vec<(raw::Local, raw::Local)>,
+    changes: Vec<Change<'cx>>,
+}
+
+impl<'a, 'cx> Differ<'a, 'cx> {
+    fn objects(
+        &mut self,
+        before: Handle<JsObject>,
+        after: Handle<JsObject>,
+        depth: usize,
+    ) -> NeonResult<()> {
+        self.visited.push((before.to_local(), after.to_local()));
+
+        let before_keys = self.keys(before)?;
+        let after_keys = self.keys(after)?;
+        let before_set = before_keys.iter().collect::<HashSet<_>>();
+        let after_set = after_keys.iter().collect::<HashSet<_>>();
+
+        for key in &before_keys {
+            let b = before.prop(self.cx, key.as_str()).get()?;
+            let a = if after_set.contains(key) {
+                Some(after.prop(self.cx, key.as_str()).get()?)
+            } else {
+                None
+            };
+
+            self.path.push(key.clone());
+
+            match a {
+                Some(a) => self.values(b, a, depth)?,
+                None => self.push(Some(b), None),
+            }
+
+            self.path.pop();
+        }
+
+        for key in &after_keys {
+            if before_set.contains(key) {
+                continue;
+            }
+
+            let a = after.prop(self.cx, key.as_str()).get()?;
+
+            self.path.push(key.clone());
+            self.push(None, Some(a));
+            self.path.pop();
+        }
+
+        Ok(())
+    }
+
+    fn values(
+        &mut self,
+        before: Handle<'cx, JsValue>,
+        after: Handle<'cx, JsValue>,
+        depth: usize,
+    ) -> NeonResult<()> {
+        if before.same_value(self.cx, after) {
+            return Ok(());
+        }
+
+        if depth < self.max_depth {
+            if let Some((b, a)) = self.nested(before, after) {
+                // Cycles and shared objects are compared once, then reported by identity
+                if !self.is_visited(b, a) {
+                    return self.objects(b, a, depth + 1);
+                }
+            }
+        }
+
+        self.push(Some(before), Some(after));
+
+        Ok(())
+    }
+
+    // Returns both values as objects if they should be compared property by property
+    fn nested(
+        &mut self,
+        before: Handle<'cx, JsValue>,
+        after: Handle<'cx, JsValue>,
+    ) -> Option<(Handle<'cx, JsObject>, Handle<'cx, JsObject>)> {
+        let cx = &mut *self.cx;
+        let is_array = before.is_a::<JsArray, _>(cx);
+
+        if is_array != after.is_a::<JsArray, _>(cx)
+            || before.is_a::<JsFunction, _>(cx)
+            || after.is_a::<JsFunction, _>(cx)
+        {
+            return None;
+        }
+
+        let before = before.downcast::<JsObject, _>(cx).ok()?;
+        let after = after.downcast::<JsObject, _>(cx).ok()?;
+
+        Some((before, after))
+    }
+
+    fn is_visited(&mut self, before: Handle<JsObject>, after: Handle<JsObject>) -> bool {
+        let env = self.cx.env().to_raw();
+
+        self.visited.iter().any(|&(b, a)| unsafe {
+            sys::mem::strict_equals(env, b, before.to_local())
+                && sys::mem::strict_equals(env, a, after.to_local())
+        })
+    }
+
+    fn keys(&mut self, obj: Handle<JsObject>) -> NeonResult<Vec<String>> {
+        let env = self.cx.env();
+        let keys: Handle<JsArray> = build(env, |out| unsafe {
+            sys::object::get_own_enumerable_property_names(out, env.to_raw(), obj.to_local())
+        })?;
+
+        keys.to_vec(self.cx)?
+            .into_iter()
+            .map(|key| {
+                // Symbols are skipped and numbers are converted to strings
+                let key = key.downcast_or_throw::<JsString, _>(self.cx)?;
+
+                Ok(key.value(self.cx))
+            })
+            .collect()
+    }
+
+    fn push(&mut self, before: Option<Handle<'cx, JsValue>>, after: Option<Handle<'cx, JsValue>>) {
+        self.changes.push(Change {
+            path: self.path.clone(),
+            before,
+            after,
+        });
+    }
+}
@@ -0,0 +1,95 @@
+//! Information about the version of Node running the module.
+//!
+//! Unlike the `napi-*` features, which select the Node-API version a module is
+//! compiled against, these are checked at runtime. Modules can use them to take
+//! advantage of newer APIs when they are available.
+//!
+//! The version of Neon itself is available in [`neon::meta`](crate::meta).
+//!
+//! ```
+//! # use neon::prelude::*;
+//! fn describe_runtime(mut cx: FunctionContext) -> JsResult<JsString> {
+//!     let version = neon::version::node(&mut cx);
+//!     let description = format!(
+//!         "Node {} ({}) with Node-API {}",
+//!         version,
+//!         version.release(),
+//!         version.napi_version(),
+//!     );
+//!
+//!     Ok(cx.string(description))
+//! }
+//! ```
+
+use std::fmt;
+
+use semver::Version;
+
+use crate::{context::Context, sys};
+
+/// The version of the running Node process.
+///
+/// **See also:** [`Context::node_version`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NodeVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    release: &'static str,
+    napi_version: u32,
+}
+
+impl NodeVersion {
+    /// The Node major version, e.g., `20` for Node 20.11.1.
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    /// The Node minor version, e.g., `11` for Node 20.11.1.
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    /// The Node patch version, e.g., `1` for Node 20.11.1.
+    pub fn patch(&self) -> u32 {
+        self.patch
+    }
+
+    /// The release name, equivalent to `process.release.name`. This is `"node"` for
+    /// official Node releases.
+    pub fn release(&self) -> &'static str {
+        self.release
+    }
+
+    /// The highest Node-API version supported by the runtime, equivalent to
+    /// `process.versions.napi`.
+    pub fn napi_version(&self) -> u32 {
+        self.napi_version
+    }
+
+    /// Produces a `semver::Version` data structure representing the Node version.
+    pub fn version(&self) -> Version {
+        Version::new(self.major.into(), self.minor.into(), self.patch.into())
+    }
+}
+
+impl fmt::Display for NodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Returns the version of the running Node process.
+pub fn node<'cx, C: Context<'cx>>(cx: &mut C) -> NodeVersion {
+    let env = cx.env().to_raw();
+    let (major, minor, patch, release) = unsafe { sys::version::node_version(env) };
+    let napi_version = unsafe { sys::version::napi_version(env) };
+
+    NodeVersion {
+        major,
+        minor,
+        patch,
+        release,
+        napi_version,
+    }
+}
@@ -0,0 +1,235 @@
+// Generated by `neon::testing::bench::driver`
+//
+// Usage: node <driver> [--save-baseline <file>] [--baseline <file>]
+
+// Minimal benchmark harness following Criterion's linear sampling. It is included in
+// every driver generated by `neon::testing::bench::driver`.
+//
+// A routine has the same contract as Criterion's `iter_custom`: it is called with
+// a number of iterations and returns the elapsed time in nanoseconds, or a promise
+// of it. This allows timing to happen on either side of the boundary. `iter` and
+// `iterAsync` build routines timed in JavaScript.
+//
+// Sample `i` of `n` runs `i * d` iterations, where `d` is chosen from the warm up so
+// the samples fill the measurement time. The estimate is the slope of elapsed time
+// against iterations, with a bootstrapped 95% confidence interval.
+
+const fs = require("fs");
+
+const defaults = {
+  warmUpTime: 1e9,
+  measurementTime: 2e9,
+  sampleSize: 50,
+  resamples: 1000,
+  // Changes smaller than this are reported as noise
+  noiseThreshold: 0.05,
+};
+
+function iter(f) {
+  return (iters) => {
+    const start = process.hrtime.bigint();
+
+    for (let i = 0; i < iters; i++) {
+      f();
+    }
+
+    return Number(process.hrtime.bigint() - start);
+  };
+}
+
+function iterAsync(f) {
+  return async (iters) => {
+    const start = process.hrtime.bigint();
+
+    for (let i = 0; i < iters; i++) {
+      await f();
+    }
+
+    return Number(process.hrtime.bigint() - start);
+  };
+}
+
+function slope(xs, ys) {
+  let xy = 0;
+  let xx = 0;
+
+  for (let i = 0; i < xs.length; i++) {
+    xy += xs[i] * ys[i];
+    xx += xs[i] * xs[i];
+  }
+
+  return xy / xx;
+}
+
+function estimate(xs, ys, resamples) {
+  const slopes = [];
+
+  for (let r = 0; r < resamples; r++) {
+    const rx = [];
+    const ry = [];
+
+    for (let i = 0; i < xs.length; i++) {
+      const j = Math.floor(Math.random() * xs.length);
+
+      rx.push(xs[j]);
+      ry.push(ys[j]);
+    }
+
+    slopes.push(slope(rx, ry));
+  }
+
+  slopes.sort((a, b) => a - b);
+
+  return {
+    lower: slopes[Math.floor(resamples * 0.025)],
+    point: slope(xs, ys),
+    upper: slopes[Math.ceil(resamples * 0.975) - 1],
+  };
+}
+
+function format(ns) {
+  const units = [
+    [1e9, "s"],
+    [1e6, "ms"],
+    [1e3, "µs"],
+  ];
+
+  for (const [scale, unit] of units) {
+    if (ns >= scale) {
+      return `${(ns / scale).toFixed(4)} ${unit}`;
+    }
+  }
+
+  return `${ns.toFixed(4)} ns`;
+}
+
+function compare(name, current, baseline, noiseThreshold) {
+  const old = baseline && baseline[name];
+
+  if (!old) {
+    return null;
+  }
+
+  const change = current.point / old.point - 1;
+
+  if (Math.abs(change) < noiseThreshold) {
+    return { change, verdict: "No change in performance detected." };
+  }
+
+  if (current.lower > old.upper) {
+    return { change, verdict: "Performance has regressed.", regressed: true };
+  }
+
+  if (current.upper < old.lower) {
+    return { change, verdict: "Performance has improved." };
+  }
+
+  return { change, verdict: "Change within noise threshold." };
+}
+
+async function measure(routine, config) {
+  // Warm up, doubling the iterations until the warm up time is spent
+  let iters = 1;
+  let warmUpIters = 0;
+  let warmUpTime = 0;
+
+  while (warmUpTime < config.warmUpTime) {
+    warmUpTime += await routine(iters);
+    warmUpIters += iters;
+    iters *= 2;
+  }
+
+  const perIter = warmUpTime / warmUpIters;
+  const n = config.sampleSize;
+  const total = (n * (n + 1)) / 2;
+  const d = Math.max(1, Math.ceil(config.measurementTime / perIter / total));
+  const xs = [];
+  const ys = [];
+
+  for (let i = 1; i <= n; i++) {
+    xs.push(i * d);
+    ys.push(await routine(i * d));
+  }
+
+  return estimate(xs, ys, config.resamples);
+}
+
+// Runs each `[name, routine]` pair and prints Criterion style estimates. With
+// `--save-baseline <file>`, estimates are written to `file`. With `--baseline <file>`,
+// they are compared to the saved ones and the process fails if any regressed.
+async function run(benches, options = {}) {
+  const config = { ...defaults, ...options };
+  const args = process.argv.slice(2);
+  const flag = (name) => {
+    const i = args.indexOf(name);
+
+    return i < 0 ? null : args[i + 1];
+  };
+
+  const savePath = flag("--save-baseline");
+  const baselinePath = flag("--baseline");
+  const baseline = baselinePath
+    ? JSON.parse(fs.readFileSync(baselinePath, "utf8"))
+    : null;
+  const results = {};
+
+  for (const [name, routine] of benches) {
+    const result = await measure(routine, config);
+    const { lower, point, upper } = result;
+
+    results[name] = result;
+
+    console.log(
+      `${name.padEnd(24)} time:   [${format(lower)} ${format(point)} ${format(
+        upper
+      )}]`
+    );
+
+    const comparison = compare(name, result, baseline, config.noiseThreshold);
+
+    if (comparison) {
+      const sign = comparison.change < 0 ? "" : "+";
+      const percent = (comparison.change * 100).toFixed(2);
+
+      console.log(`${"".padEnd(24)} change: ${sign}${percent}%`);
+      console.log(`${"".padEnd(24)} ${comparison.verdict}`);
+
+      if (comparison.regressed) {
+        process.exitCode = 1;
+      }
+    }
+  }
+
+  if (savePath) {
+    fs.writeFileSync(savePath, JSON.stringify(results, null, 2) + "\n");
+  }
+
+  return results;
+}
+
+module.exports = { iter, iterAsync, run };
+
+if (require.main === module) {
+  const bench = require("..").__neon_bench;
+  const obj = { value: 1 };
+  const buf = Buffer.alloc(1024, 1);
+
+  run([
+    ["noop", iter(() => bench.args(1, "", false))],
+    ["argument conversion", iter(() => bench.args(1, "hello", true))],
+    ["property get", iter(() => bench.getProp(obj))],
+    ["property set", iter(() => bench.setProp(obj, 2))],
+    ["buffer borrow", iter(() => bench.bufferBorrow(buf))],
+    ["buffer copy", iter(() => bench.bufferCopy(buf))],
+    [
+      "channel roundtrip",
+      bench.channelRoundtrip && iterAsync(() => bench.channelRoundtrip()),
+    ],
+    ["call js from rust", (iters) => bench.callJs(() => {}, iters)],
+    ["call js, 3 args", (iters) => bench.callJsArgs(() => {}, iters, 3)],
+    ["call js, 9 args", (iters) => bench.callJsArgs(() => {}, iters, 9)],
+  ].filter(([, routine]) => routine)).catch((err) => {
+    console.error(err);
+    process.exitCode = 1;
+  });
+}
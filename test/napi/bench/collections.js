@@ -0,0 +1,33 @@
+// Compares building large arrays and objects in batches with building them one
+// property at a time.
+//
+// Usage: node bench/collections.js [len]
+
+const addon = require("..");
+
+const len = Number(process.argv[2] ?? 10000);
+const iterations = 200;
+
+function bench(name, f) {
+  // Warm up
+  for (let i = 0; i < 10; i++) {
+    f(len);
+  }
+
+  const start = process.hrtime.bigint();
+
+  for (let i = 0; i < iterations; i++) {
+    f(len);
+  }
+
+  const elapsed = Number(process.hrtime.bigint() - start) / 1e6;
+
+  console.log(`${name}: ${(elapsed / iterations).toFixed(3)}ms`);
+}
+
+console.log(`Building ${len} elements, average of ${iterations} iterations`);
+
+bench("array set each", addon.return_js_array_set_each);
+bench("array from iter", addon.return_js_array_from_iter);
+bench("object set each", addon.return_object_set_each_with_len);
+bench("object builder", addon.return_object_builder_with_len);
@@ -21,4 +21,148 @@ describe("JsArray", function () {
   it("returns undefined when accessing outside JsArray bounds", function () {
     assert.strictEqual(addon.read_js_array([]), undefined);
   });
+
+  it("returns a JsArray built from an iterator", function () {
+    assert.deepEqual(addon.return_js_array_from_iter(0), []);
+    assert.deepEqual(addon.return_js_array_from_iter(5), [0, 1, 2, 3, 4]);
+  });
+
+  it("returns a large JsArray built from an iterator", function () {
+    const len = 20000;
+    const array = addon.return_js_array_from_iter(len);
+
+    assert.strictEqual(array.length, len);
+    assert.deepEqual(array, addon.return_js_array_set_each(len));
+  });
+
+  it("returns a JsArray built from a slice", function () {
+    assert.deepEqual(addon.return_js_array_from_slice(), [1, "two", true]);
+  });
+
+  it("pushes onto a JsArray", function () {
+    const array = [1];
+
+    assert.strictEqual(addon.push_js_array(array, 2), 2);
+    assert.deepEqual(array, [1, 2]);
+  });
+
+  it("pushes onto a JsArray with its own push property", function () {
+    const array = [1];
+
+    array.push = 1;
+
+    assert.strictEqual(addon.push_js_array(array, 2), 2);
+    assert.deepEqual([...array], [1, 2]);
+  });
+
+  it("pushes onto a JsArray when Array.prototype.push is replaced", function () {
+    const push = Array.prototype.push;
+
+    Array.prototype.push = () => 42;
+
+    try {
+      const array = [1];
+
+      assert.strictEqual(addon.push_js_array(array, 2), 2);
+      assert.deepEqual([...array], [1, 2]);
+    } finally {
+      Array.prototype.push = push;
+    }
+  });
+
+  it("throws when pushing onto a frozen JsArray", function () {
+    assert.throws(() => addon.push_js_array(Object.freeze([]), 1), TypeError);
+  });
+
+  it("iterates over a JsArray", function () {
+    assert.deepEqual(addon.copy_js_array([]), []);
+    assert.deepEqual(addon.copy_js_array([1, "a", null]), [1, "a", null]);
+    assert.deepEqual(addon.copy_js_array([, 1]), [undefined, 1]);
+    assert.strictEqual(addon.sum_js_array([1, 2, 3]), 6);
+  });
+
+  it("reads the length once when iterating over a JsArray", function () {
+    const grows = [1, 2, 3];
+    const shrinks = [1, 2, 3];
+
+    Object.defineProperty(grows, 0, {
+      get() {
+        grows.push(4);
+        return 1;
+      },
+    });
+    Object.defineProperty(shrinks, 0, {
+      get() {
+        shrinks.length = 1;
+        return 1;
+      },
+    });
+
+    assert.deepEqual(addon.copy_js_array(grows), [1, 2, 3]);
+    assert.deepEqual(addon.copy_js_array(shrinks), [1, undefined, undefined]);
+  });
+
+  it("propagates exceptions when iterating over a JsArray", function () {
+    const array = [1];
+
+    Object.defineProperty(array, 0, {
+      get() {
+        throw new Error("getter");
+      },
+    });
+
+    assert.throws(() => addon.copy_js_array(array), /getter/);
+  });
+
+  it("converts arrays of f64 in bulk", function () {
+    assert.deepEqual(addon.double_f64_array([]), []);
+    assert.deepEqual(addon.double_f64_array([1, -2.5, 1e300]), [2, -5, 2e300]);
+    assert.isTrue(Array.isArray(addon.double_f64_array([1])));
+  });
+
+  it("converts large arrays of f64 in bulk", function () {
+    const array = Array.from({ length: 100000 }, (_, i) => i / 2);
+
+    assert.deepEqual(addon.double_f64_array(array), array.map((n) => n * 2));
+  });
+
+  it("coerces elements when converting arrays of f64", function () {
+    const result = addon.double_f64_array(["3", null, , { valueOf: () => 4 }]);
+
+    assert.deepEqual(result.slice(0, 2), [6, 0]);
+    assert.isNaN(result[2]);
+    assert.strictEqual(result[3], 8);
+    assert.throws(
+      () =>
+        addon.double_f64_array([
+          {
+            valueOf() {
+              throw new Error("valueOf");
+            },
+          },
+        ]),
+      /valueOf/
+    );
+  });
+
+  it("converts arrays of i32 in bulk", function () {
+    assert.deepEqual(addon.double_i32_array([]), []);
+    assert.deepEqual(addon.double_i32_array([1, -2, 2.9]), [2, -4, 4]);
+    assert.deepEqual(addon.double_i32_array([2 ** 31]), [0]);
+  });
+
+  it("converts arrays in bulk when typed array globals are replaced", function () {
+    const { Float64Array, Int32Array } = globalThis;
+
+    globalThis.Float64Array = globalThis.Int32Array = function () {
+      throw new Error("replaced");
+    };
+
+    try {
+      assert.deepEqual(addon.double_f64_array([1, 2.5]), [2, 5]);
+      assert.deepEqual(addon.double_i32_array([1, 2]), [2, 4]);
+    } finally {
+      Object.assign(globalThis, { Float64Array, Int32Array });
+    }
+  });
 });
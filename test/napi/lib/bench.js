@@ -0,0 +1,71 @@
+const addon = require("..");
+const assert = require("chai").assert;
+const fs = require("fs");
+const path = require("path");
+
+describe("neon::testing::bench", () => {
+  const bench = addon.__neon_bench;
+
+  it("converts arguments", () => {
+    assert.strictEqual(bench.args(1, "hello", true), 6);
+    assert.strictEqual(bench.args(1, "hello", false), 1);
+  });
+
+  it("gets and sets properties", () => {
+    const obj = { value: 1 };
+
+    assert.strictEqual(bench.getProp(obj), 1);
+    bench.setProp(obj, 2);
+    assert.strictEqual(obj.value, 2);
+  });
+
+  it("borrows and copies buffers", () => {
+    const buf = Buffer.from([1, 2, 3]);
+
+    assert.strictEqual(bench.bufferBorrow(buf), 6);
+    assert.strictEqual(bench.bufferCopy(buf), 6);
+  });
+
+  it("round trips through a channel", async () => {
+    assert.strictEqual(await bench.channelRoundtrip(), undefined);
+  });
+
+  it("times calls into JavaScript", () => {
+    let count = 0;
+    const elapsed = bench.callJs(() => count++, 3);
+
+    assert.strictEqual(count, 3);
+    assert.isTrue(elapsed >= 0);
+  });
+
+  it("times calls into JavaScript with arguments", () => {
+    const rows = [];
+    const elapsed = bench.callJsArgs((...args) => rows.push(args), 2, 9);
+
+    assert.deepEqual(rows, [
+      [0, 1, 2, 3, 4, 5, 6, 7, 8],
+      [1, 2, 3, 4, 5, 6, 7, 8, 9],
+    ]);
+    assert.isTrue(elapsed >= 0);
+  });
+
+  it("generates bench/boundary.js", () => {
+    const driver = path.join(__dirname, "..", "bench", "boundary.js");
+
+    // Regenerate with `addon.benchDriver("..")` after changing the driver
+    assert.strictEqual(
+      fs.readFileSync(driver, "utf8"),
+      addon.benchDriver("..")
+    );
+  });
+
+  it("exports the harness from a driver", () => {
+    const { iter, iterAsync, run } = require("../bench/boundary.js");
+    let count = 0;
+
+    assert.isFunction(iterAsync);
+    assert.isFunction(run);
+    assert.isTrue(iter(() => count++)(3) >= 0);
+    assert.strictEqual(count, 3);
+  });
+});
@@ -40,6 +40,13 @@ describe("boxed", function () {
     assert.strictEqual(greeting, "Hello, World!");
   });
 
+  it("can downcast to the same type", function () {
+    const person = addon.person_new("World");
+
+    assert.strictEqual(addon.person_greet_downcast(person), "Hello, World!");
+    assert.throws(() => addon.person_greet_downcast({}), /failed to downcast/);
+  });
+
   it("can call methods wrapped in a RefCell", function () {
     const person = new RefPerson("World");
     const greeting = person.greet();
@@ -72,4 +79,25 @@ describe("boxed", function () {
 
     assert.throws(() => addon.person_greet(unit), /failed to downcast/);
   });
+
+  it("can unwrap a tagged external", function () {
+    const counter = addon.external_counter_new(42);
+
+    assert.strictEqual(addon.external_counter_get(counter), 42);
+  });
+
+  it("should check the type tag of externals", function () {
+    const counter = addon.external_counter_new(42);
+
+    assert.throws(
+      () => addon.external_other_counter_get(counter),
+      /failed to downcast.*JsExternal.*OtherCounter/
+    );
+    assert.throws(() => addon.person_greet(counter), /failed to downcast/);
+    assert.throws(
+      () => addon.external_counter_get(addon.external_unit()),
+      /failed to downcast/
+    );
+    assert.throws(() => addon.external_counter_get({}), /failed to downcast/);
+  });
 });
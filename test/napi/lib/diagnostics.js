@@ -0,0 +1,59 @@
+const assert = require("chai").assert;
+
+const addon = require("..");
+
+describe("diagnostics", function () {
+  afterEach(function () {
+    addon.captureBacktraces(false);
+    addon.releaseRoots();
+  });
+
+  it("should count live roots", function () {
+    const { roots } = addon.diagnosticsReport();
+
+    addon.holdRoots({}, 3);
+    assert.strictEqual(addon.diagnosticsReport().roots, roots + 3);
+
+    addon.releaseRoots();
+    assert.strictEqual(addon.diagnosticsReport().roots, roots);
+  });
+
+  it("should count unsettled deferreds", function () {
+    const [before, during, after] = addon.countDeferreds();
+
+    assert.strictEqual(during, before + 1);
+    assert.strictEqual(after, before);
+  });
+
+  it("should count active borrows", function () {
+    const borrows = addon.diagnosticsReport().borrows;
+
+    assert.strictEqual(
+      addon.countBorrows(new Uint8Array(4), new Uint8Array(4)),
+      borrows + 2
+    );
+    assert.strictEqual(addon.diagnosticsReport().borrows, borrows);
+  });
+
+  it("should capture backtraces of live handles", function () {
+    // Other tests, e.g., in workers, may hold handles that are still live, so
+    // only the handles held by this test are counted
+    const heldRoots = () =>
+      addon
+        .diagnosticsReport()
+        .backtraces.filter(({ backtrace }) => /hold_roots/.test(backtrace));
+
+    const before = heldRoots().length;
+
+    addon.captureBacktraces(true);
+    addon.holdRoots({}, 1);
+
+    const backtraces = heldRoots();
+
+    assert.lengthOf(backtraces, before + 1);
+    assert.isTrue(backtraces.every(({ kind }) => kind === "root"));
+
+    addon.releaseRoots();
+    assert.lengthOf(heldRoots(), before);
+  });
+});
@@ -28,12 +28,190 @@ describe("errors", function () {
     assert.strictEqual(err.message, msg);
   });
 
+  it("should be able to create syntax, eval, and URI errors", function () {
+    const errors = [
+      [addon.new_syntax_error("syntax"), SyntaxError, "syntax"],
+      [addon.new_eval_error("eval"), EvalError, "eval"],
+      [addon.new_uri_error("uri"), URIError, "uri"],
+    ];
+
+    for (const [err, type, msg] of errors) {
+      assert.instanceOf(err, type);
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, msg);
+    }
+  });
+
+  it("should create syntax, eval, and URI errors after the globals are replaced", function () {
+    const { SyntaxError, EvalError, URIError } = globalThis;
+
+    // Cache the constructors before replacing them
+    addon.new_syntax_error("syntax");
+    addon.new_eval_error("eval");
+    addon.new_uri_error("uri");
+
+    globalThis.SyntaxError = globalThis.EvalError = globalThis.URIError = Error;
+
+    try {
+      assert.instanceOf(addon.new_syntax_error("syntax"), SyntaxError);
+      assert.instanceOf(addon.new_eval_error("eval"), EvalError);
+      assert.instanceOf(addon.new_uri_error("uri"), URIError);
+    } finally {
+      Object.assign(globalThis, { SyntaxError, EvalError, URIError });
+    }
+  });
+
+  it("should be able to create an error with a custom type", function () {
+    class AbortError extends Error {
+      name = "AbortError";
+    }
+
+    const err = addon.new_error_with_type(AbortError, "aborted");
+
+    assert.instanceOf(err, AbortError);
+    assert.strictEqual(err.name, "AbortError");
+    assert.strictEqual(err.message, "aborted");
+    assert.throws(() => addon.new_error_with_type(Object, "oops"), TypeError);
+  });
+
+  it("should be able to throw an error with a code", function () {
+    try {
+      addon.throw_error_with_code("missing.txt");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(
+        err.message,
+        "ENOENT: no such file or directory, open 'missing.txt'"
+      );
+      assert.strictEqual(err.code, "ENOENT");
+      assert.strictEqual(err.errno, -2);
+      assert.strictEqual(err.syscall, "open");
+      assert.strictEqual(err.path, "missing.txt");
+      return;
+    }
+
+    assert.fail("expected an error to be thrown");
+  });
+
+  it("should be able to build an error with a custom type", function () {
+    const err = addon.new_error_from_builder(TypeError, "ERR_INVALID_ARG_TYPE");
+
+    assert.instanceOf(err, TypeError);
+    assert.strictEqual(err.message, "Oh, no!");
+    assert.strictEqual(err.code, "ERR_INVALID_ARG_TYPE");
+  });
+
+  it("should be able to throw Rust errors", function () {
+    assert.strictEqual(addon.parse_port("8080"), 8080);
+
+    try {
+      addon.parse_port("http");
+      assert.fail("expected an error to be thrown");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, "invalid digit found in string");
+      assert.strictEqual(err.code, undefined);
+    }
+
+    try {
+      addon.parse_port("80");
+      assert.fail("expected an error to be thrown");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, "port 80 is reserved");
+      assert.strictEqual(err.code, "ERR_INVALID_PORT");
+    }
+  });
+
+  it("should be able to capture a stack trace", function () {
+    function outer() {
+      return inner();
+    }
+
+    function inner() {
+      return addon.capture_stack_trace();
+    }
+
+    const prepareStackTrace = Error.prepareStackTrace;
+    const frames = outer();
+
+    assert.strictEqual(frames[0].functionName, "inner");
+    assert.strictEqual(frames[0].fileName, __filename);
+    assert.isAbove(frames[0].line, 0);
+    assert.isAbove(frames[0].column, 0);
+    assert.strictEqual(frames[1].functionName, "outer");
+    assert.isBelow(frames[1].line, frames[0].line);
+    assert.strictEqual(Error.prepareStackTrace, prepareStackTrace);
+  });
+
+  it("should be able to get and set the stack of an error", function () {
+    const err = new Error("Oh, no!");
+
+    assert.strictEqual(addon.get_error_stack(err), err.stack);
+
+    const custom = addon.new_error_with_stack("Error: Oh, no!\n    at native");
+
+    assert.strictEqual(custom.stack, "Error: Oh, no!\n    at native");
+    assert.strictEqual(addon.get_error_stack(custom), custom.stack);
+
+    delete custom.stack;
+
+    assert.isUndefined(addon.get_error_stack(custom));
+  });
+
   it("should be able to throw an error", function () {
     const msg = "Out of Bounds";
 
     assert.throws(() => addon.throw_error(msg), msg);
   });
 
+  describe("with an error decorator", function () {
+    before(() => addon.set_decorate_errors(true));
+    after(() => addon.set_decorate_errors(false));
+
+    it("should decorate thrown errors", function () {
+      try {
+        addon.throw_error("Out of Bounds");
+      } catch (err) {
+        assert.strictEqual(err.message, "Out of Bounds");
+        assert.strictEqual(err.decorated, true);
+        return;
+      }
+
+      assert.fail("expected an error to be thrown");
+    });
+
+    it("should not decorate errors that are only created", function () {
+      const err = addon.new_error("Oh, no!");
+
+      assert.isUndefined(err.decorated);
+    });
+
+    it("should throw the exception thrown by the decorator", function () {
+      try {
+        addon.throw_error("throw from decorator");
+      } catch (err) {
+        assert.strictEqual(err.message, "thrown by decorator");
+        assert.isUndefined(err.decorated);
+        return;
+      }
+
+      assert.fail("expected an error to be thrown");
+    });
+
+    it("should decorate errors thrown with a code", function () {
+      try {
+        addon.throw_error_with_code("missing.txt");
+      } catch (err) {
+        assert.strictEqual(err.code, "ENOENT");
+        assert.strictEqual(err.decorated, true);
+        return;
+      }
+
+      assert.fail("expected an error to be thrown");
+    });
+  });
+
   it("should be able to stringify a downcast error", function () {
     let msg = addon.downcast_error();
     assert.strictEqual(msg, "failed to downcast string to number");
@@ -30,6 +30,29 @@ function functions() {
     assert.strictEqual(addon.renamedAdd(1, 2), 3);
   });
 
+  it("add - strict", () => {
+    assert.strictEqual(addon.strictAdd(1, 2), 3);
+    assert.strictEqual(addon.strictAddWithCx(1, 2), 3);
+    assert.strictEqual(addon.strictNoArgs(), undefined);
+
+    assert.throws(
+      () => addon.strictAdd(1),
+      TypeError,
+      "expected 2 arguments, but received 1"
+    );
+    assert.throws(
+      () => addon.strictAdd(1, 2, 3),
+      TypeError,
+      "expected 2 arguments, but received 3"
+    );
+    assert.throws(() => addon.strictAddWithCx(1, 2, 3), TypeError);
+    assert.throws(
+      () => addon.strictNoArgs(undefined),
+      TypeError,
+      "expected 0 arguments, but received 1"
+    );
+  });
+
   it("add - task", async () => {
     const p1 = addon.addTask(1, 2);
     const p2 = addon.renamedAddTask(1, 2);
@@ -75,6 +75,15 @@ describe("Extractors", () => {
     assert.strictEqual(addon.extract_json_sum([8, 16, 18]), 42);
   });
 
+  it("JSON with null prototype objects", () => {
+    const dict = addon.extractJsonDict(["a", "__proto__", "constructor"]);
+
+    assert.strictEqual(Object.getPrototypeOf(dict), null);
+    assert.deepStrictEqual(Object.keys(dict).sort(), ["__proto__", "a", "constructor"]);
+    assert.strictEqual(dict.__proto__, 1);
+    assert.strictEqual(dict.constructor, 2);
+  });
+
   it("Either", () => {
     assert.strictEqual(addon.extractEither("hello"), "String: hello");
     assert.strictEqual(addon.extractEither(42), "Number: 42");
@@ -90,4 +99,124 @@ describe("Extractors", () => {
       }
     );
   });
+
+  it("TraceContext", () => {
+    const traceparent =
+      "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    assert.strictEqual(
+      addon.extractTraceContext({ traceparent }).traceparent,
+      traceparent
+    );
+    assert.strictEqual(
+      addon.extractTraceContext({ Traceparent: traceparent }).traceparent,
+      traceparent
+    );
+    assert.deepStrictEqual(addon.extractTraceContext({}), {});
+    assert.throws(() => addon.extractTraceContext("traceparent"), TypeError);
+  });
+
+  it("TraceContext in a child span", () => {
+    const traceId = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const parentId = "00f067aa0ba902b7";
+    const traceparent = `00-${traceId}-${parentId}-01`;
+    const child = addon.traceInSpan({ traceparent }, false);
+    const [, childTraceId, childId] = child.traceparent.split("-");
+
+    assert.strictEqual(childTraceId, traceId);
+    assert.notStrictEqual(childId, parentId);
+    assert.throws(() => addon.traceInSpan({ traceparent }, true), /span failed/);
+  });
+
+  it("Path", () => {
+    const { pathToFileURL } = require("url");
+
+    assert.strictEqual(addon.extractPath("/tmp/a b"), "/tmp/a b");
+    assert.strictEqual(addon.extractPath(Buffer.from("/tmp/a")), "/tmp/a");
+    assert.strictEqual(
+      addon.extractPath(new Uint8Array(Buffer.from("/tmp/a"))),
+      "/tmp/a"
+    );
+    assert.strictEqual(
+      addon.extractPath(new URL("file:///tmp/a%20b/%E2%9C%93")),
+      "/tmp/a b/✓"
+    );
+    assert.strictEqual(
+      addon.extractPath(pathToFileURL("/tmp/50% #1")),
+      "/tmp/50% #1"
+    );
+
+    assert.throws(() => addon.extractPath(1), TypeError);
+    assert.throws(
+      () => addon.extractPath(new URL("http://a/b")),
+      /scheme file/
+    );
+    assert.throws(() => addon.extractPath(new URL("file://host/a")), /host/);
+    assert.throws(() => addon.extractPath(new URL("file:///a%2fb")), /encoded/);
+  });
+
+  it("Array", () => {
+    assert.deepStrictEqual(addon.extractArray([1, null, 2]), [
+      "Some(1.0)",
+      "None",
+      "Some(2.0)",
+    ]);
+
+    assert.throws(() => addon.extractArray({}), {
+      name: "TypeError",
+      message: "expected Array",
+    });
+    assert.throws(() => addon.extractArray([1, "two"]), {
+      name: "TypeError",
+      key: "1",
+    });
+  });
+
+  it("HashMap", () => {
+    const proto = { inherited: ["a"] };
+    const obj = Object.assign(Object.create(proto), {
+      a: ["x", "y"],
+      b: [],
+    });
+
+    assert.deepStrictEqual(addon.extractHashMap(obj), { a: 2, b: 0 });
+    assert.throws(() => addon.extractHashMap(1), {
+      name: "TypeError",
+      message: "expected object",
+    });
+    assert.throws(() => addon.extractHashMap({ a: ["x"], b: ["y", 2] }), {
+      name: "TypeError",
+      key: "b.1",
+    });
+    assert.throws(() => addon.extractHashMapBoolKeys({ a: 1 }), {
+      name: "TypeError",
+      key: "a",
+    });
+  });
+
+  it("HashMap with a __proto__ key", () => {
+    const obj = JSON.parse('{ "__proto__": ["x"] }');
+    const result = addon.extractHashMap(obj);
+
+    assert.strictEqual(Object.getPrototypeOf(result), Object.prototype);
+    assert.deepStrictEqual(Object.keys(result), ["__proto__"]);
+    assert.strictEqual(
+      Object.getOwnPropertyDescriptor(result, "__proto__").value,
+      1
+    );
+  });
+
+  if (process.platform !== "win32") {
+    it("Path with invalid UTF-8", () => {
+      const invalid = Buffer.from([0x2f, 0x74, 0xff]);
+      const result = addon.extractPath(invalid);
+
+      assert.ok(Buffer.isBuffer(result));
+      assert.deepStrictEqual(result, invalid);
+      assert.deepStrictEqual(
+        addon.extractPath(new URL("file:///t%FF")),
+        invalid
+      );
+    });
+  }
 });
@@ -255,6 +255,27 @@ describe("JsFunction", function () {
     assert.equal(addon.is_argument_zero_some.call(null, ["a", "b"]), true);
   });
 
+  it("treats missing and undefined arguments as absent", function () {
+    assert.strictEqual(addon.optional_argument_zero_string(), null);
+    assert.strictEqual(addon.optional_argument_zero_string(undefined), null);
+    assert.strictEqual(addon.optional_argument_zero_string("a"), "a");
+    assert.throws(() => addon.optional_argument_zero_string(null), TypeError);
+    assert.throws(() => addon.optional_argument_zero_string(17), TypeError);
+  });
+
+  it("defaults missing and undefined arguments", function () {
+    assert.strictEqual(addon.argument_zero_or_default(), 42);
+    assert.strictEqual(addon.argument_zero_or_default(undefined), 42);
+    assert.strictEqual(addon.argument_zero_or_default(7), 7);
+    assert.throws(() => addon.argument_zero_or_default("7"), TypeError);
+  });
+
+  it("can treat undefined arguments as present", function () {
+    assert.strictEqual(addon.argument_zero_or_missing(), "missing");
+    assert.strictEqual(addon.argument_zero_or_missing(undefined), undefined);
+    assert.strictEqual(addon.argument_zero_or_missing(null), null);
+  });
+
   it("correctly casts an argument via cx.arguments", function () {
     assert.equal(addon.require_argument_zero_string("foobar"), "foobar");
     assert.throws(function () {
@@ -303,6 +324,75 @@ describe("JsFunction", function () {
     );
   });
 
+  it("spreads an array as arguments", function () {
+    const obj = { n: 1 };
+    const f = function (...args) {
+      return [this, ...args];
+    };
+    const g = () => {
+      throw new Error("spread");
+    };
+
+    assert.deepEqual(addon.call_spread(f, obj, [2, "three"]), [
+      obj,
+      2,
+      "three",
+    ]);
+    assert.strictEqual(addon.call_spread(Math.max, null, []), -Infinity);
+    assert.throws(() => addon.call_spread(g, null, []), /spread/);
+  });
+
+  it("spreads arguments when Reflect.apply is replaced", function () {
+    const apply = Reflect.apply;
+
+    Reflect.apply = () => "patched";
+
+    try {
+      assert.deepEqual(
+        addon.call_spread((...args) => args, null, [1, 2]),
+        [1, 2]
+      );
+    } finally {
+      Reflect.apply = apply;
+    }
+  });
+
+  it("collects rest arguments", function () {
+    const f = (...args) => args;
+
+    assert.deepEqual(addon.call_with_rest(f, 1, "two", null), [1, "two", null]);
+    assert.deepEqual(addon.call_with_rest(f), []);
+  });
+
+  it("supports deeply nested calls between JavaScript and Rust", function () {
+    const f = (depth) => addon.reenter(f, depth);
+
+    assert.strictEqual(addon.reenter(f, 100), 100);
+  });
+
+  it("propagates exceptions through nested calls", function () {
+    const err = new Error("deep");
+    const f = (depth) => {
+      if (depth === 0) {
+        throw err;
+      }
+
+      return addon.reenter(f, depth);
+    };
+
+    assert.throws(() => addon.reenter(f, 10), /deep/);
+    assert.strictEqual(
+      addon.reenter_catching(() => addon.reenter(f, 10)),
+      err
+    );
+    assert.strictEqual(
+      addon.reenter_catching(() =>
+        addon.reenter_catching(() => addon.reenter(f, 10))
+      ),
+      err
+    );
+  });
+
   it("gets a regular value with cx.try_catch", function () {
     assert.equal(
       addon.call_and_catch(() => {
@@ -312,6 +402,21 @@ describe("JsFunction", function () {
     );
   });
 
+  it("catches exceptions with JsFunction::call_catching", function () {
+    const err = new Error("oops");
+
+    assert.deepEqual(
+      addon.call_catching(() => 42),
+      { threw: false, value: 42 }
+    );
+    assert.deepEqual(
+      addon.call_catching(() => {
+        throw err;
+      }),
+      { threw: true, value: err }
+    );
+  });
+
   it("can return Rust type from cx.try_catch", function () {
     const n = Math.random();
     assert.strictEqual(addon.get_number_or_default(n), n);
@@ -345,6 +450,48 @@ describe("JsFunction", function () {
     assert.equal(new addon.is_construct().wasConstructed, true);
   });
 
+  it("gets new.target", function () {
+    class Derived extends addon.get_new_target {}
+
+    assert.strictEqual(addon.get_new_target(), undefined);
+    assert.strictEqual(new addon.get_new_target(), addon.get_new_target);
+    assert.strictEqual(
+      Reflect.construct(addon.get_new_target, [], Derived),
+      Derived
+    );
+  });
+
+  it("forwards new.target to a constructor", function () {
+    class MyError extends addon.construct_error_with_new_target {}
+
+    const err = new MyError("oops");
+
+    assert.instanceOf(err, MyError);
+    assert.strictEqual(Object.prototype.toString.call(err), "[object Error]");
+    assert.strictEqual(err.message, "oops");
+    assert.throws(
+      () => addon.construct_error_with_new_target("oops"),
+      TypeError,
+      /must be called with `new`/
+    );
+  });
+
+  it("constructs with an explicit new.target", function () {
+    function Base(n) {
+      this.n = n;
+      this.target = new.target;
+    }
+
+    class Derived {}
+
+    const obj = addon.construct_with_new_target(Base, Derived);
+
+    assert.strictEqual(obj.n, 42);
+    assert.strictEqual(obj.target, Derived);
+    assert.strictEqual(Object.getPrototypeOf(obj), Derived.prototype);
+    assert.throws(() => addon.construct_with_new_target(Base, {}), TypeError);
+  });
+
   it("should be able to call a function from a closure", function () {
     assert.strictEqual(addon.count_called() + 1, addon.count_called());
   });
@@ -364,4 +511,97 @@ describe("JsFunction", function () {
       global.gc();
     }
   );
+
+  it("wraps calls with interceptors", function () {
+    assert.strictEqual(addon.interceptedDouble(1), 4);
+  });
+
+  it("identifies exported functions to interceptors by export key", function () {
+    assert.strictEqual(addon.interceptedExportFunction(1), 4);
+    assert.notStrictEqual(
+      addon.interceptedExportFunction.name,
+      "interceptedExportFunction"
+    );
+  });
+
+  it("allows an interceptor to skip the call", function () {
+    assert.strictEqual(addon.interceptedGuarded("secret", 42), 42);
+    assert.throws(() => addon.interceptedGuarded("guess", 42), /Access denied/);
+  });
+
+  it("allows an interceptor to map errors", function () {
+    assert.throws(
+      () => addon.interceptedThrow(),
+      (err) =>
+        err instanceof TypeError &&
+        err.message === "Intercepted Error: failure"
+    );
+  });
+
+  it("runs interceptors in the order they were added", function () {
+    const log = [];
+
+    addon.interceptedOrder(log);
+
+    assert.deepEqual(log, ["outer", "inner", "function"]);
+  });
+
+  it("provides the current context to synchronous FFI callbacks", function () {
+    const items = [];
+    const sum = addon.sum_with_c_callbacks((i) => {
+      items.push(i);
+      return i * 2;
+    }, 4);
+
+    assert.strictEqual(sum, 12);
+    assert.deepEqual(items, [0, 1, 2, 3]);
+  });
+
+  it("propagates exceptions thrown in FFI callbacks", function () {
+    const items = [];
+
+    assert.throws(
+      () =>
+        addon.sum_with_c_callbacks((i) => {
+          items.push(i);
+
+          if (i === 1) {
+            throw new Error("callback failed");
+          }
+
+          return i;
+        }, 4),
+      /callback failed/
+    );
+    assert.deepEqual(items, [0, 1]);
+  });
+
+  it("only provides the current context on the JavaScript thread", function () {
+    assert.deepEqual(addon.has_current_context_on_thread(), [true, false]);
+  });
+
+  it("creates a function from a closure with mutable state", function () {
+    const counts = [];
+    const counter = addon.counter_with_callback((n) => counts.push(n));
+
+    assert.strictEqual(counter(), 1);
+    assert.strictEqual(counter(), 2);
+    assert.deepEqual(counts, [1, 2]);
+  });
+
+  it("throws when a mutable closure is called reentrantly", function () {
+    let inner;
+    const counter = addon.counter_with_callback(() => {
+      try {
+        counter();
+      } catch (err) {
+        inner = err;
+      }
+    });
+
+    assert.strictEqual(counter(), 1);
+    assert.instanceOf(inner, Error);
+    assert.match(inner.message, /called reentrantly/);
+    assert.strictEqual(counter(), 2);
+  });
 });
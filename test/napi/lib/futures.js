@@ -56,6 +56,16 @@ describe("Futures", () => {
     });
   });
 
+  describe("spawn", () => {
+    it("should resolve with the output of the future", async () => {
+      assert.strictEqual(await addon.spawn_double(21), 42);
+    });
+
+    it("should reject with an exception thrown while settling", async () => {
+      await assertRejects(() => addon.spawn_double(Infinity), RangeError);
+    });
+  });
+
   describe("Exported Async Functions", () => {
     it("should be able to call `async fn`", async () => {
       assert.strictEqual(await addon.asyncFnAdd(1, 2), 3);
@@ -107,5 +117,14 @@ describe("Futures", () => {
         process.off("async_with_events", eventHandler);
       }
     });
+
+    it("should be able to hold buffer contents across an await", async () => {
+      const buf = Buffer.from([1, 2, 3, 4]);
+      const sum = addon.asyncSumBytes(buf);
+
+      buf.fill(0);
+
+      assert.strictEqual(await sum, 10);
+    });
   });
 });
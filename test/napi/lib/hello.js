@@ -20,6 +20,46 @@ describe("hello", function () {
     assert.strictEqual(addon.two, 2.1);
   });
 
+  it("should create lazy exports on first access", function () {
+    const before = Object.getOwnPropertyDescriptor(addon, "lazyGreeting");
+
+    assert.isFunction(before.get);
+    assert.isTrue(before.enumerable);
+    assert.isTrue(before.configurable);
+    assert.strictEqual(addon.lazyGreeting, "Hello, Lazy!");
+    assert.deepEqual(Object.getOwnPropertyDescriptor(addon, "lazyGreeting"), {
+      value: "Hello, Lazy!",
+      writable: true,
+      enumerable: true,
+      configurable: true,
+    });
+  });
+
+  it("should not use the global Object.defineProperty for lazy exports", function () {
+    const defineProperty = Object.defineProperty;
+
+    Object.defineProperty = () => {
+      throw new Error("Object.defineProperty was called");
+    };
+
+    try {
+      assert.strictEqual(addon.lazyIntrinsic, "Hello, Intrinsic!");
+    } finally {
+      Object.defineProperty = defineProperty;
+    }
+
+    assert.strictEqual(
+      Object.getOwnPropertyDescriptor(addon, "lazyIntrinsic").value,
+      "Hello, Intrinsic!"
+    );
+  });
+
+  it("should retry lazy exports that throw", function () {
+    assert.throws(() => addon.lazyRetry, /first attempt fails/);
+    assert.strictEqual(addon.lazyRetry, 2);
+    assert.strictEqual(addon.lazyRetry, 2);
+  });
+
   it("should be able to create JS objects in rust", function () {
     assert.deepEqual(addon.rustCreated, {
       0: 1,
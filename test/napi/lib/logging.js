@@ -0,0 +1,78 @@
+const assert = require("chai").assert;
+const diagnostics_channel = require("diagnostics_channel");
+
+const addon = require("..");
+
+describe("logging", function () {
+  const channel = diagnostics_channel.channel("napi-tests:log");
+
+  before(function () {
+    addon.initLogger(channel);
+  });
+
+  function nextRecord() {
+    return new Promise((resolve) => {
+      function onMessage(record) {
+        channel.unsubscribe(onMessage);
+        resolve(record);
+      }
+
+      channel.subscribe(onMessage);
+    });
+  }
+
+  function nextConsole(method) {
+    const original = console[method];
+
+    return new Promise((resolve) => {
+      console[method] = (...args) => {
+        console[method] = original;
+        resolve(args);
+      };
+    });
+  }
+
+  it("publishes records logged from another thread", async function () {
+    const record = nextRecord();
+
+    addon.logFromThread("warn", "Hello from a thread!");
+
+    const { level, target, message, file, line } = await record;
+
+    assert.strictEqual(level, "warn");
+    assert.strictEqual(target, "napi_tests::js::logging");
+    assert.strictEqual(message, "Hello from a thread!");
+    assert.match(file, /logging\.rs$/);
+    assert.isAbove(line, 0);
+  });
+
+  it("filters records by level", async function () {
+    const record = nextRecord();
+
+    addon.logFromThread("trace", "Filtered");
+    addon.logFromThread("debug", "Not filtered");
+
+    assert.strictEqual((await record).message, "Not filtered");
+  });
+
+  it("can only be installed once", function () {
+    assert.throws(() => addon.initLogger(channel), Error);
+  });
+
+  it("writes records to the console", async function () {
+    const args = nextConsole("error");
+
+    addon.logToConsole("error", "Oh, no!");
+
+    assert.deepEqual(await args, ["napi_tests: Oh, no!"]);
+  });
+
+  it("filters console records by level", async function () {
+    const args = nextConsole("debug");
+
+    addon.logToConsole("trace", "Filtered");
+    addon.logToConsole("debug", "Details");
+
+    assert.deepEqual(await args, ["napi_tests: Details"]);
+  });
+});
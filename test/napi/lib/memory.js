@@ -0,0 +1,21 @@
+const assert = require("chai").assert;
+
+const addon = require("..");
+
+describe("memory", function () {
+  it("should return heap statistics", function () {
+    const stats = addon.heapStatistics();
+
+    assert.isAbove(stats.rss, 0);
+    assert.isAbove(stats.heapUsed, 0);
+    assert.isAtLeast(stats.heapTotal, stats.heapUsed);
+    assert.isAtLeast(stats.external, stats.arrayBuffers);
+  });
+
+  it("should adjust external memory", function () {
+    const total = addon.adjustExternalMemory(0);
+
+    assert.strictEqual(addon.adjustExternalMemory(1024), total + 1024);
+    assert.strictEqual(addon.adjustExternalMemory(-1024), total);
+  });
+});
@@ -56,4 +56,63 @@ describe("JsNumber", function () {
       assert.equal(addon.accept_and_return_negative_js_number(-55), -55);
     });
   });
+
+  describe("safe integers", function () {
+    it("creates numbers from safe i64 and u64", function () {
+      assert.strictEqual(
+        addon.number_from_i64("-9007199254740991"),
+        -(2 ** 53) + 1
+      );
+      assert.strictEqual(addon.number_from_i64("42"), 42);
+      assert.strictEqual(
+        addon.number_from_u64("9007199254740991"),
+        2 ** 53 - 1
+      );
+    });
+
+    it("throws when creating numbers from unsafe i64 and u64", function () {
+      assert.throws(
+        () => addon.number_from_i64("9007199254740992"),
+        RangeError,
+        /Loss of precision \(9007199254740992\)/
+      );
+      assert.throws(
+        () => addon.number_from_i64("-9007199254740992"),
+        RangeError
+      );
+      assert.throws(
+        () => addon.number_from_u64("18446744073709551615"),
+        RangeError
+      );
+    });
+
+    it("falls back to BigInt for unsafe integers", function () {
+      assert.strictEqual(addon.number_or_bigint_from_i64("-1"), -1);
+      assert.strictEqual(
+        addon.number_or_bigint_from_i64("-9007199254740993"),
+        -9007199254740993n
+      );
+      assert.strictEqual(
+        addon.number_or_bigint_from_u64("18446744073709551615"),
+        18446744073709551615n
+      );
+    });
+
+    it("reads safe integers from numbers", function () {
+      assert.strictEqual(
+        addon.number_to_i64(-(2 ** 53) + 1),
+        "-9007199254740991"
+      );
+      assert.strictEqual(addon.number_to_u64(2 ** 53 - 1), "9007199254740991");
+      assert.strictEqual(addon.number_to_u64(-0), "0");
+    });
+
+    it("throws when reading unsafe integers from numbers", function () {
+      assert.throws(() => addon.number_to_i64(1.5), RangeError, /1.5/);
+      assert.throws(() => addon.number_to_i64(2 ** 53), RangeError);
+      assert.throws(() => addon.number_to_i64(NaN), RangeError);
+      assert.throws(() => addon.number_to_i64(Infinity), RangeError);
+      assert.throws(() => addon.number_to_u64(-1), RangeError);
+    });
+  });
 });
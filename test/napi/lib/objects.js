@@ -10,6 +10,14 @@ describe("JsObject", function () {
     assert.deepEqual({}, addon.return_js_object());
   });
 
+  it("return a JsObject with a null prototype", function () {
+    var dict = addon.return_js_dict();
+
+    assert.strictEqual(Object.getPrototypeOf(dict), null);
+    assert.strictEqual(dict.__proto__, "value");
+    assert.isUndefined(dict.toString);
+  });
+
   it("return a JsObject with a number key value pair", function () {
     assert.deepEqual({ number: 9000 }, addon.return_js_object_with_number());
   });
@@ -167,4 +175,461 @@ describe("JsObject", function () {
       addon.call_non_method_with_prop(obj);
     }, /not a function/);
   });
+
+  it("returns an object built with ObjectBuilder", function () {
+    const obj = addon.return_object_builder();
+
+    assert.deepEqual(obj, {
+      name: "neon",
+      version: 2,
+      "nul\0key": true,
+      "": undefined,
+    });
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, "name"), {
+      value: "neon",
+      writable: true,
+      enumerable: true,
+      configurable: true,
+    });
+  });
+
+  it("returns a large object built with ObjectBuilder", function () {
+    const len = 10000;
+
+    assert.deepEqual(
+      addon.return_object_builder_with_len(len),
+      addon.return_object_set_each_with_len(len)
+    );
+  });
+
+  it("gets many properties", function () {
+    const obj = {
+      a: 1,
+      get b() {
+        return "b";
+      },
+      "nul\0key": true,
+    };
+
+    assert.deepEqual(addon.get_many_props(obj), [1, "b", true, undefined]);
+    assert.throws(
+      () =>
+        addon.get_many_props({
+          get a() {
+            throw new Error("getter");
+          },
+        }),
+      /getter/
+    );
+  });
+
+  it("defines many properties", function () {
+    const obj = { a: 0 };
+
+    addon.define_many_props(obj);
+
+    assert.deepEqual(obj, { a: 1, b: "b", "nul\0key": true });
+    assert.throws(() => addon.define_many_props(Object.freeze({})), TypeError);
+  });
+
+  it("defines many properties without calling setters", function () {
+    const obj = {
+      set a(v) {
+        throw new Error("setter called");
+      },
+    };
+
+    addon.define_many_props(obj);
+
+    assert.strictEqual(obj.a, 1);
+  });
+
+  it("diffs two objects", function () {
+    const fn = () => {};
+    const shared = { a: 1 };
+    const before = {
+      same: 1,
+      removed: true,
+      changed: "a",
+      nan: NaN,
+      nested: { x: 1, y: [1, 2] },
+      shared,
+      fn,
+      replaced: { a: 1 },
+    };
+    const after = {
+      same: 1,
+      changed: "b",
+      nan: NaN,
+      nested: { x: 1, y: [1, 3, 4] },
+      shared,
+      fn,
+      replaced: [1],
+      added: null,
+    };
+
+    assert.deepEqual(addon.diff_objects(before, after), [
+      { path: ["removed"], before: true, after: undefined },
+      { path: ["changed"], before: "a", after: "b" },
+      { path: ["nested", "y", "1"], before: 2, after: 3 },
+      { path: ["nested", "y", "2"], before: undefined, after: 4 },
+      { path: ["replaced"], before: { a: 1 }, after: [1] },
+      { path: ["added"], before: undefined, after: null },
+    ]);
+  });
+
+  it("diffs two objects up to a maximum depth", function () {
+    const before = { a: { b: { c: 1 } } };
+    const after = { a: { b: { c: 2 } } };
+
+    assert.deepEqual(addon.diff_objects(before, after, 1), [
+      { path: ["a"], before: before.a, after: after.a },
+    ]);
+    assert.deepEqual(addon.diff_objects(before, after, 2), [
+      { path: ["a", "b"], before: before.a.b, after: after.a.b },
+    ]);
+    assert.deepEqual(addon.diff_objects(before, after, 3), [
+      { path: ["a", "b", "c"], before: 1, after: 2 },
+    ]);
+  });
+
+  it("diffs objects with cycles", function () {
+    const before = { n: 1 };
+    const after = { n: 2 };
+
+    before.self = before;
+    after.self = after;
+
+    assert.deepEqual(addon.diff_objects(before, after), [
+      { path: ["n"], before: 1, after: 2 },
+      { path: ["self"], before, after },
+    ]);
+  });
+
+  it("diffs shared objects once", function () {
+    let before = { n: 1 };
+    let after = { n: 2 };
+
+    // Each level refers to the previous one twice, so recursing into every
+    // reference would compare 2 ** 20 objects
+    for (let i = 0; i < 20; i++) {
+      before = { a: before, b: before };
+      after = { a: after, b: after };
+    }
+
+    const changes = addon.diff_objects(before, after, 32);
+
+    // Each `b` repeats the `a` beside it, so it is reported without recursing
+    assert.strictEqual(changes.length, 21);
+    assert.deepEqual(changes[0].path, [..."a".repeat(20), "n"]);
+    assert.deepEqual(changes[1].path, [..."a".repeat(19), "b"]);
+    assert.deepEqual(changes[20].path, ["b"]);
+  });
+
+  it("wraps an object created in JavaScript", function () {
+    const obj = { a: 1 };
+
+    addon.wrap_object(obj, "tag");
+
+    assert.strictEqual(addon.unwrap_object(obj), "tag");
+    assert.deepEqual(Object.keys(obj), ["a"]);
+  });
+
+  it("wraps a frozen object", function () {
+    const obj = Object.freeze({});
+
+    addon.wrap_object(obj, "frozen");
+
+    assert.strictEqual(addon.unwrap_object(obj), "frozen");
+  });
+
+  it("throws when wrapping an object twice", function () {
+    const obj = {};
+
+    addon.wrap_object(obj, "first");
+
+    assert.throws(() => addon.wrap_object(obj, "second"), /already wrapped/);
+    assert.strictEqual(addon.unwrap_object(obj), "first");
+  });
+
+  it("wraps an object when WeakMap methods are replaced", function () {
+    const { get, set, has } = WeakMap.prototype;
+    const obj = {};
+
+    WeakMap.prototype.get = () => undefined;
+    WeakMap.prototype.set = () => {
+      throw new Error("replaced");
+    };
+    WeakMap.prototype.has = () => true;
+
+    try {
+      addon.wrap_object(obj, "tag");
+
+      assert.strictEqual(addon.unwrap_object(obj), "tag");
+    } finally {
+      Object.assign(WeakMap.prototype, { get, set, has });
+    }
+  });
+
+  it("accesses the internals of a wrapped this", function () {
+    class Counter {
+      constructor() {
+        addon.wrap_counter.call(this);
+      }
+    }
+
+    Counter.prototype.count = addon.counter_count;
+    Counter.prototype.increment = addon.counter_increment;
+
+    const counter = new Counter();
+
+    assert.strictEqual(counter.increment(), 1);
+    assert.strictEqual(counter.increment(), 2);
+    assert.strictEqual(counter.count(), 2);
+
+    assert.throws(() => addon.counter_count.call({}), TypeError);
+    assert.throws(
+      () => counter.increment(() => counter.increment()),
+      /already borrowed/
+    );
+    assert.throws(
+      () => counter.increment(() => counter.count()),
+      /already mutably borrowed/
+    );
+  });
+
+  it("does not unwrap objects that were not wrapped", function () {
+    assert.strictEqual(addon.unwrap_object({}), undefined);
+  });
+
+  it("does not unwrap a value of a different type", function () {
+    const obj = {};
+
+    addon.wrap_object(obj, "tag");
+
+    assert.strictEqual(addon.unwrap_object_as_string(obj), undefined);
+  });
+
+  it("finalizes the wrapped value when the object is collected", async function () {
+    let finalized = null;
+
+    (() => {
+      addon.wrap_object({}, "collected", (name) => {
+        finalized = name;
+      });
+    })();
+
+    for (let i = 0; i < 10 && finalized === null; i++) {
+      global.gc();
+      await new Promise((resolve) => setTimeout(resolve, 10));
+    }
+
+    assert.strictEqual(finalized, "collected");
+  });
+
+  describe("get_property_names", function () {
+    const sym = Symbol("sym");
+    const parent = { inherited: 1 };
+    const obj = Object.create(parent);
+
+    obj.own = 1;
+    obj[0] = 1;
+    obj[sym] = 1;
+    Object.defineProperty(obj, "hidden", { value: 1, enumerable: false });
+
+    function names(options) {
+      return addon.get_property_names(obj, {
+        symbols: false,
+        nonEnumerable: false,
+        prototypes: false,
+        ...options,
+      });
+    }
+
+    it("returns own enumerable string keys by default", function () {
+      assert.deepEqual(names({}), Object.keys(obj));
+    });
+
+    it("includes symbols", function () {
+      assert.deepEqual(names({ symbols: true }), ["0", "own", sym]);
+    });
+
+    it("includes non-enumerable properties", function () {
+      assert.deepEqual(
+        names({ symbols: true, nonEnumerable: true }),
+        Reflect.ownKeys(obj)
+      );
+    });
+
+    it("includes prototype properties", function () {
+      const keys = [];
+
+      for (const key in obj) {
+        keys.push(key);
+      }
+
+      assert.deepEqual(names({ prototypes: true }), keys);
+    });
+  });
+
+  it("checks for own properties", function () {
+    const sym = Symbol("sym");
+    const obj = Object.create({ inherited: 1 });
+
+    obj.own = 1;
+    obj[1] = 1;
+    obj[sym] = 1;
+
+    assert.isTrue(addon.has_own_property(obj, "own"));
+    assert.isTrue(addon.has_own_property(obj, sym));
+    assert.isTrue(addon.has_own_property(obj, 1));
+    assert.isFalse(addon.has_own_property(obj, "inherited"));
+    assert.isTrue(addon.has_own_property_str(obj, "own"));
+    assert.isFalse(addon.has_own_property_str(obj, "missing"));
+    assert.isTrue(addon.has_own_property_index(obj, 1));
+    assert.isFalse(addon.has_own_property_index(obj, 0));
+  });
+
+  it("deletes properties", function () {
+    const sym = Symbol("sym");
+    const obj = { a: 1, b: 2, [sym]: 3 };
+    const arr = [1, 2, 3];
+
+    assert.isTrue(addon.delete_property(obj, sym));
+    assert.isTrue(addon.delete_property_str(obj, "a"));
+    assert.isTrue(addon.delete_property(obj, "missing"));
+    assert.deepEqual(Reflect.ownKeys(obj), ["b"]);
+
+    assert.isTrue(addon.delete_property_index(arr, 1));
+    assert.isFalse(1 in arr);
+    assert.strictEqual(arr.length, 3);
+  });
+
+  it("does not delete non-configurable properties", function () {
+    const obj = Object.freeze({ a: 1 });
+
+    assert.isFalse(addon.delete_property_str(obj, "a"));
+    assert.strictEqual(obj.a, 1);
+  });
+
+  it("propagates exceptions from proxy traps", function () {
+    const obj = new Proxy(
+      {},
+      {
+        deleteProperty() {
+          throw new Error("delete trap");
+        },
+        getOwnPropertyDescriptor() {
+          throw new Error("descriptor trap");
+        },
+      }
+    );
+
+    assert.throws(() => addon.delete_property_str(obj, "a"), /delete trap/);
+    assert.throws(() => addon.delete_property_index(obj, 0), /delete trap/);
+    assert.throws(
+      () => addon.has_own_property_str(obj, "a"),
+      /descriptor trap/
+    );
+  });
+
+  it("sets globals", function () {
+    addon.set_global("neonTestGlobal", 42);
+    assert.strictEqual(globalThis.neonTestGlobal, 42);
+    delete globalThis.neonTestGlobal;
+  });
+
+  it("caches intrinsics", function () {
+    const expected = [Array, Object, Promise, Error, JSON, Symbol, process];
+
+    assert.deepEqual(addon.get_intrinsics(), expected);
+
+    const original = globalThis.Array;
+
+    try {
+      globalThis.Array = function () {};
+      assert.strictEqual(addon.get_intrinsics()[0], original);
+    } finally {
+      globalThis.Array = original;
+    }
+  });
+
+  it("reads process.env", function () {
+    process.env.NEON_TEST_PROCESS_ENV = "hello";
+
+    try {
+      assert.strictEqual(
+        addon.get_process_env("NEON_TEST_PROCESS_ENV"),
+        "hello"
+      );
+    } finally {
+      delete process.env.NEON_TEST_PROCESS_ENV;
+    }
+
+    assert.isUndefined(addon.get_process_env("NEON_TEST_PROCESS_ENV"));
+  });
+
+  it("reads process.argv", function () {
+    assert.deepEqual(addon.get_process_argv(), process.argv);
+  });
+
+  it("requires built-in modules", function () {
+    assert.strictEqual(addon.require_module("node:path"), require("path"));
+    assert.strictEqual(addon.require_module("path"), require("path"));
+    assert.throws(
+      () => addon.require_module("../package.json"),
+      /Cannot find built-in module/
+    );
+  });
+
+  it("requires modules relative to a file", function () {
+    assert.strictEqual(
+      addon.require_module_from(__filename, "../package.json"),
+      require("../package.json")
+    );
+    assert.strictEqual(
+      addon.require_module_from(__filename, "chai"),
+      require("chai")
+    );
+    assert.throws(
+      () => addon.require_module_from(__filename, "./does-not-exist"),
+      /Cannot find module/
+    );
+  });
+
+  it("validates options with a schema", function () {
+    assert.deepEqual(addon.validate_options({ id: 1 }), [
+      1,
+      undefined,
+      undefined,
+    ]);
+    assert.deepEqual(
+      addon.validate_options({ id: 1, name: "a", retry: { attempts: 3 } }),
+      [1, "a", 3]
+    );
+    assert.deepEqual(addon.validate_options({ id: 1, name: null }), [
+      1,
+      undefined,
+      undefined,
+    ]);
+
+    assert.throws(() => addon.validate_options(1), "expected object");
+
+    try {
+      addon.validate_options({ name: 2, retry: { attempts: "3" } });
+      assert.fail("expected validation to throw");
+    } catch (err) {
+      assert.instanceOf(err, TypeError);
+      assert.strictEqual(
+        err.message,
+        "invalid options: id: missing; name: expected string; " +
+          "retry.attempts: expected number"
+      );
+      assert.deepEqual(err.errors, [
+        "id: missing",
+        "name: expected string",
+        "retry.attempts: expected number",
+      ]);
+    }
+  });
 });
@@ -0,0 +1,90 @@
+const addon = require("..");
+const { assert } = require("chai");
+const { spawnSync } = require("child_process");
+const path = require("path");
+
+// The permission model was stabilized with a new flag in Node 22
+const [major] = process.versions.node.split(".").map(Number);
+const PERMISSION_FLAG =
+  major >= 22 ? "--permission" : "--experimental-permission";
+
+// Evaluates `script` with `addon` in scope, in a process with restricted permissions
+function restricted(flags, script) {
+  const addonPath = path.resolve(__dirname, "..");
+  const { status, stdout, stderr } = spawnSync(
+    process.execPath,
+    [
+      PERMISSION_FLAG,
+      "--allow-addons",
+      "--allow-fs-read=*",
+      ...flags,
+      "-e",
+      `const addon = require(${JSON.stringify(addonPath)});
+       console.log(JSON.stringify(${script}));`,
+    ],
+    { encoding: "utf8" }
+  );
+
+  assert.strictEqual(status, 0, stderr);
+
+  return JSON.parse(stdout);
+}
+
+describe("Permissions", function () {
+  this.timeout(30000);
+
+  it("should grant everything when the permission model is disabled", function () {
+    assert.strictEqual(addon.permissionsEnabled(), false);
+    assert.strictEqual(addon.hasFsRead(__filename), true);
+    assert.strictEqual(addon.hasFsWrite(__filename), true);
+    assert.strictEqual(addon.hasChildProcess(), true);
+    assert.doesNotThrow(() => addon.requireFsWrite(__filename));
+  });
+
+  it("should query restricted permissions", function () {
+    const result = restricted(
+      ["--allow-fs-write=/tmp/*"],
+      `[
+        addon.permissionsEnabled(),
+        addon.hasFsRead("/etc/hosts"),
+        addon.hasFsWrite("/tmp/neon.txt"),
+        addon.hasFsWrite("/etc/hosts"),
+        addon.hasChildProcess(),
+      ]`
+    );
+
+    assert.deepEqual(result, [true, true, true, false, false]);
+  });
+
+  if (process.platform !== "win32") {
+    it("should not grant paths that are not valid Unicode", function () {
+      const result = restricted(
+        ["--allow-fs-write=/tmp/*"],
+        `[
+          addon.hasFsWrite(Buffer.from("/tmp/neon.txt")),
+          addon.hasFsWrite(Buffer.from([0x2f, 0x74, 0x6d, 0x70, 0x2f, 0xff])),
+        ]`
+      );
+
+      assert.deepEqual(result, [true, false]);
+    });
+  }
+
+  it("should throw an actionable error when a permission is missing", function () {
+    const err = restricted(
+      [],
+      `(() => {
+        try {
+          addon.requireFsWrite("/etc/hosts");
+        } catch (err) {
+          return { ...err, message: err.message };
+        }
+      })()`
+    );
+
+    assert.strictEqual(err.code, "ERR_ACCESS_DENIED");
+    assert.strictEqual(err.permission, "fs.write");
+    assert.strictEqual(err.resource, "/etc/hosts");
+    assert.include(err.message, "--allow-fs-write");
+  });
+});
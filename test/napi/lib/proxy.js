@@ -0,0 +1,73 @@
+const assert = require("chai").assert;
+
+const addon = require("..");
+
+describe("JsProxy", function () {
+  it("calls traps implemented in Rust", function () {
+    const obj = addon.virtualObject("virtual:");
+
+    assert.strictEqual(obj.name, "virtual:name");
+    assert.isUndefined(obj[Symbol.iterator]);
+
+    obj.name = "real";
+    assert.strictEqual(obj.name, "real");
+
+    assert.isTrue("anything" in obj);
+    assert.isFalse("_hidden" in obj);
+  });
+
+  it("fails assignments rejected by the set trap", function () {
+    "use strict";
+
+    const obj = addon.virtualObject("");
+
+    assert.throws(() => {
+      obj.readonly = 1;
+    }, TypeError);
+    assert.strictEqual(obj.readonly, "readonly");
+  });
+
+  it("creates a proxy with a JavaScript handler", function () {
+    const target = { a: 1 };
+    const proxy = addon.newProxy(target, {
+      get(target, key) {
+        return key in target ? target[key] : 0;
+      },
+    });
+
+    assert.strictEqual(proxy.a, 1);
+    assert.strictEqual(proxy.b, 0);
+  });
+
+  it("passes a function target to traps", function () {
+    const proxy = addon.functionProxy(function greet(name) {
+      return `hello, ${name}`;
+    });
+
+    assert.strictEqual(proxy.name, "greet");
+    assert.strictEqual(proxy("world"), "hello, world");
+  });
+
+  it("recognizes proxies even if WeakSet is replaced", function () {
+    const { has } = WeakSet.prototype;
+
+    WeakSet.prototype.has = () => {
+      throw new Error("should not be called");
+    };
+
+    try {
+      assert.isTrue(addon.isProxy(addon.virtualObject("")));
+      assert.isFalse(addon.isProxy({}));
+    } finally {
+      WeakSet.prototype.has = has;
+    }
+  });
+
+  it("recognizes proxies created by the module", function () {
+    assert.isTrue(addon.isProxy(addon.virtualObject("")));
+    assert.isTrue(addon.isProxy(addon.newProxy({}, {})));
+    assert.isFalse(addon.isProxy({}));
+    assert.isFalse(addon.isProxy(new Proxy({}, {})));
+    assert.isFalse(addon.isProxy(1));
+  });
+});
@@ -17,6 +17,102 @@ describe("JsString", function () {
       assert.equal(addon.return_length_utf16("hello 🥹"), 8);
     });
   });
+  describe("UTF-16", function () {
+    it("should create a string from UTF-16 code units", function () {
+      const units = new Uint16Array([0x68, 0x69, 0xd83e, 0xdd79]);
+      assert.equal(addon.string_from_utf16(units), "hi🥹");
+    });
+    it("should preserve lone surrogates", function () {
+      const s = "a\ud800b\udc00";
+      const units = addon.string_to_utf16(s);
+      assert.deepEqual(Array.from(units), [0x61, 0xd800, 0x62, 0xdc00]);
+      assert.equal(addon.string_from_utf16(units), s);
+    });
+  });
+  describe("Latin-1", function () {
+    it("should round-trip every byte value", function () {
+      const bytes = Buffer.from(Array.from({ length: 256 }, (_, i) => i));
+      const s = addon.string_from_latin1(bytes);
+      assert.equal(s, bytes.toString("latin1"));
+      assert.deepEqual(addon.string_to_latin1(s), bytes);
+    });
+    it("should convert a string to Latin-1 bytes", function () {
+      assert.deepEqual(
+        addon.string_to_latin1("café"),
+        Buffer.from([0x63, 0x61, 0x66, 0xe9])
+      );
+    });
+  });
+  describe("concat", function () {
+    it("should concatenate strings", function () {
+      assert.equal(addon.concat_strings(["hello", ", ", "🥹"]), "hello, 🥹");
+    });
+    it("should return an empty string with no parts", function () {
+      assert.equal(addon.concat_strings([]), "");
+    });
+    it("should concatenate more parts than the argument limit", function () {
+      const parts = Array.from({ length: 70000 }, (_, i) => String(i % 10));
+      assert.equal(addon.concat_strings(parts), parts.join(""));
+    });
+  });
+  describe("substring", function () {
+    it("should slice by UTF-16 code units", function () {
+      assert.equal(addon.substring("hello 🥹", 0, 5), "hello");
+      assert.equal(addon.substring("hello 🥹", 6, 8), "🥹");
+    });
+    it("should clamp and swap indices", function () {
+      assert.equal(addon.substring("hello", 3, 100), "lo");
+      assert.equal(addon.substring("hello", 3, 1), "el");
+    });
+    it("should ignore replaced String.prototype methods", function () {
+      const { substring, concat } = String.prototype;
+
+      String.prototype.substring = () => 42;
+      String.prototype.concat = () => 42;
+
+      try {
+        assert.equal(addon.substring("hello", 0, 2), "he");
+        assert.equal(addon.concat_strings(["a", "b"]), "ab");
+      } finally {
+        String.prototype.substring = substring;
+        String.prototype.concat = concat;
+      }
+    });
+  });
+  describe("AtomTable", function () {
+    it("should assign the same atom to equal strings", function () {
+      assert.deepEqual(addon.intern_strings(["a", "b", "a", "🥹", "b"]), [
+        [0, "a"],
+        [1, "b"],
+        [0, "a"],
+        [2, "🥹"],
+        [1, "b"],
+      ]);
+    });
+    it("should keep strings with unpaired surrogates distinct", function () {
+      const atoms = addon.intern_strings(["\ud800", "\udc00"]);
+      assert.deepEqual(atoms.map(([atom]) => atom), [0, 1]);
+    });
+  });
+  describe("external", function () {
+    it("should create a string from ASCII text", function () {
+      const s = "SELECT * FROM t WHERE id = 1;".repeat(100);
+      assert.equal(addon.external_string(s), s);
+      assert.equal(addon.external_string(""), "");
+    });
+    it("should copy text that is not ASCII", function () {
+      assert.equal(addon.external_string("hello 🥹"), "hello 🥹");
+    });
+    it("should create a string from Latin-1 bytes", function () {
+      const bytes = Buffer.from(Array.from({ length: 256 }, (_, i) => i));
+      const s = addon.external_string_latin1(bytes);
+      assert.equal(s, bytes.toString("latin1"));
+    });
+    it("should create a string from UTF-16 code units", function () {
+      const units = new Uint16Array([0x68, 0x69, 0xd83e, 0xdd79]);
+      assert.equal(addon.external_string_utf16(units), "hi🥹");
+    });
+  });
   describe("run_as_script", function () {
     it("should return the evaluated value", function () {
       assert.equal(addon.run_string_as_script("6 * 7"), 42);
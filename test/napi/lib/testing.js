@@ -0,0 +1,52 @@
+const addon = require("..");
+const { assert } = require("chai");
+const { spawnSync } = require("child_process");
+const fs = require("fs");
+const os = require("os");
+const path = require("path");
+
+describe("neon::testing", function () {
+  describe("registered tests", function () {
+    for (const [name, test] of Object.entries(addon.__neon_tests)) {
+      it(name, () => test());
+    }
+  });
+
+  it("should throw an AssertionError when values are not strictly equal", function () {
+    const err = addon.assertStrictEqFailure();
+
+    assert.instanceOf(err, Error);
+    assert.strictEqual(err.name, "AssertionError");
+    assert.strictEqual(err.code, "ERR_ASSERTION");
+    assert.include(err.message, '1 !== "1"');
+  });
+
+  it("should throw an AssertionError when an exception is missing", function () {
+    const err = addon.assertThrowsFailure();
+
+    assert.strictEqual(err.name, "AssertionError");
+    assert.match(err.message, /Missing expected exception/);
+  });
+
+  it("should generate a driver that runs the tests", function () {
+    this.timeout(30000);
+
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "neon-testing-"));
+    const driver = path.join(dir, "exports.test.js");
+
+    try {
+      addon.writeTestDriver(driver, path.resolve(__dirname, ".."));
+
+      const { status, stdout } = spawnSync(
+        process.execPath,
+        ["--test", driver],
+        { encoding: "utf8" }
+      );
+
+      assert.strictEqual(status, 0, stdout);
+      assert.match(stdout, /passes_assert_strict_eq/);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
@@ -1,3 +1,6 @@
+const { AsyncLocalStorage, createHook } = require("async_hooks");
+const { EventEmitter } = require("events");
+
 const addon = require("..");
 const assert = require("chai").assert;
 
@@ -29,10 +32,83 @@ const assert = require("chai").assert;
     assert.strictEqual(expected, result);
   });
 
+  it("can upgrade a weak root while the object is alive", function () {
+    const expected = {};
+    const weak = addon.weak_root(expected);
+
+    assert.strictEqual(addon.upgrade_weak_root(weak), expected);
+  });
+
+  it("does not keep the object of a weak root alive", async function () {
+    const weak = (() => addon.weak_root({}))();
+
+    // Wait for the current job to complete before collecting
+    await new Promise((resolve) => setImmediate(resolve));
+    global.gc();
+
+    assert.isUndefined(addon.upgrade_weak_root(weak));
+  });
+
+  it("can drop a weak root on another thread", function (cb) {
+    addon.drop_weak_root_from_thread({}, cb);
+  });
+
   it("should be able to callback from another thread", function (cb) {
     addon.thread_callback(cb);
   });
 
+  it("should callback in the async context of the caller", async function () {
+    const storage = new AsyncLocalStorage();
+    const callback = (store) =>
+      storage.run(store, () => {
+        return new Promise((resolve) => {
+          addon.thread_callback(() => resolve(storage.getStore()));
+        });
+      });
+
+    assert.deepEqual(await Promise.all([callback("a"), callback("b")]), [
+      "a",
+      "b",
+    ]);
+  });
+
+  it("should name async resources", async function () {
+    const types = [];
+    const hook = createHook({ init: (id, type) => types.push(type) }).enable();
+
+    try {
+      await new Promise((resolve) => addon.named_resources_callback(resolve));
+    } finally {
+      hook.disable();
+    }
+
+    assert.include(types, "neon-test:task");
+    assert.include(types, "neon-test:channel");
+  });
+
+  it("should share an async context between channels in a call", async function () {
+    const types = [];
+    const hook = createHook({ init: (id, type) => types.push(type) }).enable();
+    const callback = (n) =>
+      new Promise((resolve) => {
+        let count = 0;
+
+        addon.channels_callback(n, () => ++count === n && resolve());
+      });
+
+    try {
+      await callback(3);
+      await callback(2);
+    } finally {
+      hook.disable();
+    }
+
+    assert.lengthOf(
+      types.filter((type) => type === "neon channel"),
+      2
+    );
+  });
+
   it("should be able to callback from multiple threads", function (cb) {
     const n = 4;
     const set = new Set([...new Array(n)].map((_, i) => i));
@@ -395,6 +471,198 @@ const assert = require("chai").assert;
     addon.task_custom_panic(msg);
   });
 
+  it("should report progress from a task", async function () {
+    const progress = [];
+    const count = await addon.task_with_progress(5, (n) => progress.push(n));
+
+    assert.strictEqual(count, 5);
+
+    // Progress is delivered asynchronously and may arrive after completion
+    while (progress.length < 5) {
+      await new Promise((resolve) => setTimeout(resolve, 1));
+    }
+
+    assert.deepEqual(progress, [1, 2, 3, 4, 5]);
+  });
+
+  it("should be able to cancel a task", async function () {
+    const [promise, cancel] = addon.cancellable_task();
+
+    setTimeout(cancel, 10);
+
+    assert.strictEqual(await promise, true);
+  });
+
+  it("should emit events from another thread", async function () {
+    const emitter = new EventEmitter();
+    const data = [];
+
+    emitter.on("data", (n) => data.push(n));
+
+    const end = new Promise((resolve) => emitter.once("end", resolve));
+
+    addon.emit_from_thread(emitter, 1000);
+
+    assert.strictEqual(await end, undefined);
+    assert.deepEqual(data, Array.from({ length: 1000 }, (_, i) => i));
+  });
+
+  it("should iterate values sent from threads", async function () {
+    const values = [];
+
+    for await (const n of addon.stream_from_threads(1000)) {
+      values.push(n);
+    }
+
+    values.sort((a, b) => a - b);
+
+    assert.deepEqual(values, Array.from({ length: 1000 }, (_, i) => i));
+  });
+
+  it("should reject next when converting a value throws", async function () {
+    const stream = addon.stream_convert_throw();
+
+    assert.deepEqual(await stream.next(), { value: 0, done: false });
+
+    try {
+      await stream.next();
+
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, "Odd: 1");
+    }
+
+    assert.deepEqual(await stream.next(), { value: 2, done: false });
+    assert.strictEqual(
+      (await stream.next().catch((err) => err)).message,
+      "Odd: 3"
+    );
+    assert.deepEqual(await stream.next(), { value: undefined, done: true });
+  });
+
+  it("should stop sending when iteration ends early", async function () {
+    const [stream, sent] = addon.stream_until_closed();
+
+    for await (const n of stream) {
+      if (n === 5) {
+        break;
+      }
+    }
+
+    assert.isAtLeast(await sent, 6);
+    assert.deepEqual(await stream.next(), { value: undefined, done: true });
+  });
+
+  it("should run next ticks and microtasks before sends", function (cb) {
+    const log = [];
+
+    setImmediate(() => {
+      const { queueMicrotask } = globalThis;
+      const { nextTick } = process;
+
+      // The built-in functions are used even if the globals are replaced
+      globalThis.queueMicrotask = () => log.push("replaced");
+      process.nextTick = () => log.push("replaced");
+
+      try {
+        addon.channel_ordering(log);
+      } finally {
+        globalThis.queueMicrotask = queueMicrotask;
+        process.nextTick = nextTick;
+      }
+
+      Promise.resolve().then(() => log.push("promise"));
+      log.push("sync");
+
+      setTimeout(() => {
+        assert.deepEqual(log, [
+          "sync",
+          "next_tick",
+          "microtask",
+          "promise",
+          "send",
+        ]);
+        cb();
+      }, 10);
+    });
+  });
+
+  it("should fail to send when a bounded channel is full", function () {
+    assert.deepEqual(addon.bounded_channel_try_send(), [
+      true,
+      false,
+      true,
+      true,
+    ]);
+  });
+
+  it("should wait for room in a bounded channel", function (cb) {
+    const received = [];
+
+    addon.bounded_channel_send_blocking(16, (i) => {
+      received.push(i);
+
+      if (received.length === 16) {
+        assert.deepEqual(received, Array.from({ length: 16 }, (_, i) => i));
+        cb();
+      }
+    });
+  });
+
+  it("should only treat threads that loaded the addon as JavaScript threads", function () {
+    assert.deepEqual(addon.is_js_thread(), [true, false]);
+  });
+
+  it("should settle a promise with a channel", async function () {
+    assert.strictEqual(await addon.channel_settle_with(16), 4);
+
+    try {
+      await addon.channel_settle_with(-1);
+
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, RangeError);
+      assert.match(err.message, /non-negative/);
+    }
+  });
+
+  it("should run a task on a thread pool", async function () {
+    assert.match(await addon.task_on_pool(), /^neon-pool-\d+$/);
+  });
+
+  it("should complete a task on a thread pool with a callback", function (cb) {
+    addon.task_on_pool_and_then(21, (n) => {
+      assert.strictEqual(n, 42);
+      cb();
+    });
+  });
+
+  it("should complete a task in the async context of the caller", async function () {
+    const storage = new AsyncLocalStorage();
+    const store = await storage.run("task", () => {
+      return new Promise((resolve) => {
+        addon.task_on_pool_and_then(21, () => resolve(storage.getStore()));
+      });
+    });
+
+    assert.strictEqual(store, "task");
+  });
+
+  it("should reject when a task on a thread pool panics", async function () {
+    const msg = "Oh, no!";
+
+    try {
+      await addon.task_on_pool_panic(msg);
+
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.instanceOf(err.panic, Error);
+      assert.strictEqual(err.panic.message, msg);
+    }
+  });
+
   it("should be able to reject a promise in a task", async function () {
     const msg = "Rejected!";
 
@@ -502,3 +770,43 @@ const assert = require("chai").assert;
     }
   });
 });
+
+describe("Deferred response", function () {
+  it("should return a promise resolved from another thread", async function () {
+    const result = addon.deferred_response_resolve(21);
+
+    assert.instanceOf(result, Promise);
+    assert.strictEqual(await result, 42);
+  });
+
+  it("should reject with an exception thrown while responding", async function () {
+    const msg = "Rejected!";
+
+    try {
+      await addon.deferred_response_reject(msg);
+
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, msg);
+    }
+  });
+
+  it("should reject when the responder is dropped", async function () {
+    try {
+      await addon.deferred_response_leak();
+
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.match(err.message, /dropped without responding/);
+    }
+  });
+
+  it("should throw when declaring a second response", function () {
+    assert.throws(
+      () => addon.deferred_response_twice(),
+      /already declared/
+    );
+  });
+});
@@ -0,0 +1,25 @@
+const assert = require("chai").assert;
+
+const addon = require("..");
+
+describe("tracing", function () {
+  before(function () {
+    addon.startTracing();
+  });
+
+  beforeEach(function () {
+    addon.takeSpans();
+  });
+
+  it("records a span for each function call", function () {
+    addon.add1(1);
+
+    assert.deepEqual(addon.takeSpans(), ["call:add1", "call:takeSpans"]);
+  });
+
+  it("records a span for each task", async function () {
+    await addon.sum(new Float64Array([1, 2, 3]));
+
+    assert.deepEqual(addon.takeSpans().slice(0, 2), ["call:sum", "task"]);
+  });
+});
@@ -83,6 +83,33 @@ describe("Typed arrays", function () {
     assert.throws(() => addon.copy_typed_array(a, b));
   });
 
+  it("splits a mutable borrow into disjoint borrows", function () {
+    const arr = new Uint32Array([1, 2, 3, 4, 5, 6]);
+
+    addon.mirror_typed_array(arr);
+    assert.deepEqual(Array.from(arr), [1, 2, 3, 1, 2, 3]);
+  });
+
+  it("releases the rest of a borrow when mapping it to a part", function () {
+    const buf = new ArrayBuffer(16);
+    const arr = new Uint32Array(buf);
+
+    assert.isTrue(addon.borrow_second_half(arr, new Uint32Array(buf, 0, 2)));
+    assert.isFalse(addon.borrow_second_half(arr, new Uint32Array(buf, 4, 2)));
+    assert.isFalse(addon.borrow_second_half(arr, new Uint32Array(buf, 8, 1)));
+  });
+
+  it("upgrades a borrow only if no other borrow overlaps", function () {
+    const buf = new ArrayBuffer(16);
+    const arr = new Uint32Array(buf, 0, 2);
+
+    arr.fill(1);
+    assert.isFalse(addon.upgrade_borrow(arr, new Uint32Array(buf, 4, 2)));
+    assert.deepEqual(Array.from(arr), [1, 1]);
+    assert.isTrue(addon.upgrade_borrow(arr, new Uint32Array(buf, 8, 2)));
+    assert.deepEqual(Array.from(arr), [0, 0]);
+  });
+
   it("gets a 16-byte, zeroed ArrayBuffer", function () {
     var b = addon.return_array_buffer();
     assert.equal(b.byteLength, 16);
@@ -147,6 +174,46 @@ describe("Typed arrays", function () {
     assert.ok(b.length === 16);
   });
 
+  it("concatenates Buffers into an uninitialized Buffer", function () {
+    var b = addon.concat_uninit_buffer(
+      Buffer.from("hello, "),
+      Buffer.from("world")
+    );
+    assert.instanceOf(b, Buffer);
+    assert.strictEqual(b.toString(), "hello, world");
+  });
+
+  it("truncates a partially initialized Buffer and zero-fills the rest", function () {
+    var b = addon.return_partial_uninit_buffer("neon", 64);
+    assert.instanceOf(b, Buffer);
+    assert.strictEqual(b.toString(), "neon");
+
+    var rest = new Uint8Array(b.buffer, b.byteOffset + b.length, 60);
+    assert.ok(rest.every((x) => x === 0));
+  });
+
+  it("truncates a partially initialized Buffer when subarray is replaced", function () {
+    var subarray = Buffer.prototype.subarray;
+
+    Buffer.prototype.subarray = function () {
+      return this;
+    };
+
+    try {
+      var b = addon.return_partial_uninit_buffer("neon", 64);
+      assert.strictEqual(b.length, 4);
+      assert.strictEqual(b.toString(), "neon");
+    } finally {
+      Buffer.prototype.subarray = subarray;
+    }
+  });
+
+  it("initializes a Buffer with MaybeUninit", function () {
+    var b = addon.fill_uninit_buffer(7, 8);
+    assert.ok(b.equals(Buffer.alloc(8, 7)));
+    assert.deepEqual(addon.fill_uninit_buffer(7, 0), Buffer.alloc(0));
+  });
+
   it("gets a 16-byte, zeroed Buffer", function () {
     var b = addon.return_buffer();
     assert.ok(b.equals(Buffer.alloc(16)));
@@ -160,6 +227,34 @@ describe("Typed arrays", function () {
     }
   });
 
+  it("gets a Buffer initialized from a slice", function () {
+    var b = addon.return_buffer_from_slice(16);
+    assert.instanceOf(b, Buffer);
+    assert.deepEqual(Array.from(b), Array.from({ length: 16 }, (_, i) => i));
+    assert.strictEqual(addon.return_buffer_from_slice(0).length, 0);
+    assert.strictEqual(addon.return_array_buffer_from_slice(0).byteLength, 0);
+  });
+
+  it("copies a slice into a typed array", function () {
+    var a = new Uint32Array(4);
+    addon.copy_into_uint32_array(a, 1, [7, 8, 9]);
+    assert.deepEqual(Array.from(a), [0, 7, 8, 9]);
+
+    assert.throws(
+      () => addon.copy_into_uint32_array(a, 2, [1, 2, 3]),
+      RangeError
+    );
+    assert.deepEqual(Array.from(a), [0, 7, 8, 9]);
+  });
+
+  it("copies a typed array into a slice", function () {
+    var a = new Uint32Array([1, 2, 3, 4]);
+    assert.deepEqual(addon.copy_out_of_uint32_array(a, 1, 2), [2, 3]);
+    assert.deepEqual(addon.copy_out_of_uint32_array(a, 4, 0), []);
+
+    assert.throws(() => addon.copy_out_of_uint32_array(a, 3, 2), RangeError);
+  });
+
   it("gets an external Buffer", function () {
     var expected = "String to copy";
     var buf = addon.return_external_buffer(expected);
@@ -174,6 +269,131 @@ describe("Typed arrays", function () {
     assert.strictEqual(Buffer.from(buf).toString(), expected);
   });
 
+  it("gets an external ArrayBuffer shared with Rust", function () {
+    var expected = "String to share";
+    var buf = addon.return_shared_external_array_buffer(expected);
+    assert.instanceOf(buf, ArrayBuffer);
+    assert.strictEqual(Buffer.from(buf).toString(), expected);
+  });
+
+  it("gets a new SharedArrayBuffer", function () {
+    var buf = addon.return_shared_array_buffer(16);
+    assert.instanceOf(buf, SharedArrayBuffer);
+    assert.strictEqual(buf.byteLength, 16);
+    assert.deepEqual([...new Uint8Array(buf)], new Array(16).fill(0));
+  });
+
+  it("correctly reads SharedArrayBuffer size", function () {
+    assert.strictEqual(
+      addon.get_shared_array_buffer_size(new SharedArrayBuffer(42)),
+      42
+    );
+    assert.strictEqual(
+      addon.get_shared_array_buffer_size(new SharedArrayBuffer(0)),
+      0
+    );
+    assert.throws(
+      () => addon.get_shared_array_buffer_size(new ArrayBuffer(42)),
+      TypeError
+    );
+  });
+
+  it("leaves a pending exception when checking for a SharedArrayBuffer", function () {
+    assert.throws(
+      () =>
+        addon.check_shared_array_buffer_while_throwing(new SharedArrayBuffer(4)),
+      /pending/
+    );
+  });
+
+  it("does not recognize a forged SharedArrayBuffer", function () {
+    const forged = Object.create(SharedArrayBuffer.prototype);
+    const proxy = new Proxy(new SharedArrayBuffer(4), {});
+
+    class Impostor {
+      static [Symbol.hasInstance]() {
+        return true;
+      }
+    }
+
+    const original = globalThis.SharedArrayBuffer;
+
+    globalThis.SharedArrayBuffer = Impostor;
+
+    try {
+      for (const value of [forged, proxy, new ArrayBuffer(4), {}]) {
+        assert.throws(
+          () => addon.fill_shared_array_buffer_in_thread(value),
+          TypeError
+        );
+      }
+
+      assert.strictEqual(
+        addon.get_shared_array_buffer_size(new original(8)),
+        8
+      );
+    } finally {
+      globalThis.SharedArrayBuffer = original;
+    }
+  });
+
+  it("writes to a SharedArrayBuffer from another thread", function () {
+    var buf = new SharedArrayBuffer(4);
+    addon.fill_shared_array_buffer_in_thread(buf);
+    assert.deepEqual([...new Uint8Array(buf)], [0, 1, 2, 3]);
+  });
+
+  it("does not treat a SharedArrayBuffer as an ArrayBuffer", function () {
+    assert.throws(
+      () => addon.read_array_buffer_with_borrow(new SharedArrayBuffer(4), 0),
+      TypeError
+    );
+  });
+
+  it("gets zero-filled Buffers from a pool", function () {
+    var buf = addon.return_pooled_buffer(8);
+    assert.instanceOf(buf, Buffer);
+    assert.deepEqual([...buf], new Array(8).fill(0));
+    buf.fill(0xff);
+
+    var large = addon.return_pooled_buffer(32);
+    assert.strictEqual(large.length, 32);
+    assert.deepEqual([...large], new Array(32).fill(0));
+  });
+
+  it("copies data into Buffers from a pool", function () {
+    assert.strictEqual(
+      addon.return_pooled_buffer_from_string("pooled").toString(),
+      "pooled"
+    );
+    assert.strictEqual(
+      addon.return_pooled_buffer_from_string("a".repeat(32)).toString(),
+      "a".repeat(32)
+    );
+  });
+
+  (global.gc ? it : it.skip)(
+    "recycles pooled Buffers when they are collected",
+    function (cb) {
+      (() => {
+        for (var i = 0; i < 4; i++) {
+          addon.return_pooled_buffer(16).fill(0xff);
+        }
+      })();
+
+      global.gc();
+
+      setImmediate(() => {
+        assert.strictEqual(addon.get_buffer_pool_idle(), 2);
+        assert.deepEqual(
+          [...addon.return_pooled_buffer(16)],
+          new Array(16).fill(0)
+        );
+        cb();
+      });
+    }
+  );
+
   it("gets a typed array constructed from an ArrayBuffer", function () {
     var b = new ArrayBuffer(64);
     var i8 = addon.return_int8array_from_arraybuffer(b);
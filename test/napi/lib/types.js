@@ -90,4 +90,57 @@ describe("type checks", function () {
     assert(!addon.strict_equals(o1, o2));
     assert(!addon.strict_equals(o1, 17));
   });
+
+  it("same_value", function () {
+    assert(addon.same_value(17, 17));
+    assert(addon.same_value(NaN, NaN));
+    assert(addon.same_value(-0, -0));
+    assert(!addon.same_value(0, -0));
+    assert(!addon.same_value(17, "17"));
+    let o1 = {};
+    assert(addon.same_value(o1, o1));
+    assert(!addon.same_value(o1, {}));
+  });
+
+  it("same_value_zero", function () {
+    assert(addon.same_value_zero(17, 17));
+    assert(addon.same_value_zero(NaN, NaN));
+    assert(addon.same_value_zero(0, -0));
+    assert(!addon.same_value_zero(NaN, 17));
+    assert(!addon.same_value_zero(17, "17"));
+    let o1 = {};
+    assert(addon.same_value_zero(o1, o1));
+    assert(!addon.same_value_zero(o1, {}));
+  });
+
+  it("instance_of", function () {
+    class Base {}
+    class Derived extends Base {}
+
+    assert(addon.instance_of(new Derived(), Derived));
+    assert(addon.instance_of(new Derived(), Base));
+    assert(addon.instance_of([], Object));
+    assert(!addon.instance_of(new Base(), Derived));
+    assert(!addon.instance_of(17, Number));
+    assert(!addon.instance_of(Object.create(null), Object));
+  });
+
+  it("instance_of with custom Symbol.hasInstance", function () {
+    class Even {
+      static [Symbol.hasInstance](n) {
+        return n % 2 === 0;
+      }
+    }
+
+    class Throws {
+      static [Symbol.hasInstance]() {
+        throw new Error("hasInstance failed");
+      }
+    }
+
+    assert(addon.instance_of(2, Even));
+    assert(!addon.instance_of(3, Even));
+    assert.throws(() => addon.instance_of({}, Throws), /hasInstance failed/);
+    assert.throws(() => addon.instance_of({}, {}), TypeError);
+  });
 });
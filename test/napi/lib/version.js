@@ -0,0 +1,33 @@
+const assert = require("chai").assert;
+
+const addon = require("..");
+
+describe("version", function () {
+  it("reports the running Node version", function () {
+    const version = addon.nodeVersion();
+    const [major, minor, patch] = process.versions.node.split(".").map(Number);
+
+    assert.deepEqual(version, {
+      major,
+      minor,
+      patch,
+      release: process.release.name,
+      napi: Number(process.versions.napi),
+      version: process.versions.node,
+    });
+  });
+
+  it("exports module metadata", function () {
+    const manifest = require("../package.json");
+    const metadata = addon.__neon;
+
+    assert.strictEqual(metadata.name, manifest.name);
+    assert.strictEqual(metadata.version, manifest.version);
+    assert.strictEqual(metadata.gitHash, undefined);
+    assert.strictEqual(typeof metadata.debug, "boolean");
+    assert.strictEqual(typeof metadata.arch, "string");
+    assert.strictEqual(typeof metadata.os, "string");
+    assert.match(metadata.neonVersion, /^\d+\.\d+\.\d+/);
+    assert.isTrue(metadata.napiVersion <= Number(process.versions.napi));
+  });
+});
@@ -7,6 +7,8 @@ const {
   workerData,
 } = require("worker_threads");
 
+const { once } = require("events");
+
 const addon = require("..");
 
 // Receive a message, try that method and return the error message
@@ -33,6 +35,28 @@ if (!isMainThread) {
         case "get_or_init_clone":
           addon.get_or_init_clone(() => ({}));
           break;
+        case "settle_deferred":
+          addon.settle_deferred(42);
+          break;
+        case "register_exit_hook":
+          addon.register_exit_hook();
+          addon.register_exit_hook();
+          parentPort.postMessage("registered");
+          return;
+        case "register_panicking_exit_hook":
+          addon.register_panicking_exit_hook();
+          parentPort.postMessage("registered");
+          return;
+        case "spawn_managed_thread":
+          addon.spawn_managed_thread();
+          parentPort.postMessage("spawned");
+          return;
+        case "get_store_count":
+          {
+            let count = addon.increment_store_count();
+            parentPort.postMessage(count);
+          }
+          break;
         case "get_thread_id":
           {
             let id = addon.get_or_init_thread_id(NaN);
@@ -99,6 +123,14 @@ describe("Worker / Root Tagging Tests", () => {
       );
       assert.strictEqual(addon.get_or_init_clone(), o);
     });
+
+    it("should be able to settle a stashed `Deferred`", async () => {
+      const promise = addon.stash_deferred();
+
+      addon.settle_deferred(42);
+
+      assert.strictEqual(await promise, 42);
+    });
   });
 
   // Note: These tests require that the previous set of tests have run or else they will fail
@@ -135,6 +167,152 @@ describe("Worker / Root Tagging Tests", () => {
 
       worker.postMessage("get_or_init_clone");
     });
+
+    it("should fail to settle a `Deferred` from another module", async () => {
+      // The `Deferred` is dropped in the worker and rejected in this module
+      const rejected = addon.stash_deferred().then(
+        () => assert.fail("Expected promise to reject"),
+        (err) => assert.ok(/dropped without being settled/.test(err.message))
+      );
+
+      const worker = new Worker(__filename);
+      const message = new Promise((resolve) => worker.once("message", resolve));
+
+      worker.postMessage("settle_deferred");
+
+      assert.ok(/wrong module/.test(await message));
+      await rejected;
+    });
+  });
+});
+
+describe("Exit hooks", () => {
+  it("should call each exit hook when a worker terminates", async () => {
+    const calls = addon.exit_hook_calls();
+    const worker = new Worker(__filename);
+    const registered = new Promise((resolve) =>
+      worker.once("message", resolve)
+    );
+
+    worker.postMessage("register_exit_hook");
+    assert.strictEqual(await registered, "registered");
+    assert.strictEqual(addon.exit_hook_calls(), calls);
+
+    await worker.terminate();
+
+    assert.strictEqual(addon.exit_hook_calls(), calls + 2);
+  });
+
+  it("should call the remaining exit hooks when one panics", async () => {
+    const calls = addon.exit_hook_calls();
+    const worker = new Worker(__filename);
+    const registered = new Promise((resolve) =>
+      worker.once("message", resolve)
+    );
+
+    worker.postMessage("register_panicking_exit_hook");
+    assert.strictEqual(await registered, "registered");
+
+    await worker.terminate();
+
+    assert.strictEqual(addon.exit_hook_calls(), calls + 1);
+  });
+});
+
+describe("Managed threads", () => {
+  it("should stop and join managed threads when a worker terminates", async () => {
+    const stopped = addon.stopped_threads();
+    const worker = new Worker(__filename);
+    const spawned = new Promise((resolve) => worker.once("message", resolve));
+
+    worker.postMessage("spawn_managed_thread");
+    assert.strictEqual(await spawned, "spawned");
+    assert.strictEqual(addon.stopped_threads(), stopped);
+
+    await worker.terminate();
+
+    assert.strictEqual(addon.stopped_threads(), stopped + 1);
+  });
+
+  it("should be able to stop and join a managed thread", () => {
+    assert.strictEqual(addon.stop_and_join_thread(), 42);
+  });
+
+  it("should stop tracking a managed thread once it returns", async () => {
+    const dropped = addon.dropped_thread_results();
+
+    addon.spawn_finished_thread();
+
+    while (addon.dropped_thread_results() === dropped) {
+      await new Promise((resolve) => setTimeout(resolve, 1));
+    }
+  });
+});
+
+describe("MessagePort", () => {
+  it("should receive and post messages from Rust", async () => {
+    const { port1, port2 } = new MessageChannel();
+
+    addon.message_port_double(port1);
+    port2.postMessage(21);
+
+    const [doubled] = await once(port2, "message");
+
+    port1.close();
+    assert.strictEqual(doubled, 42);
+  });
+
+  it("should transfer objects posted from Rust", async () => {
+    const [port, len] = addon.message_port_transfer(8);
+    const [buf] = await once(port, "message");
+
+    port.close();
+    assert.ok(port instanceof MessagePort);
+    assert.strictEqual(len, 0);
+    assert.ok(buf instanceof ArrayBuffer);
+    assert.strictEqual(buf.byteLength, 8);
+  });
+
+  it("should only downcast message ports", () => {
+    assert.throws(() => addon.message_port_double({}), /JsMessagePort/);
+  });
+
+  it("should not leave an exception pending when checking for a port", () => {
+    Object.defineProperty(MessagePort, Symbol.hasInstance, {
+      configurable: true,
+      value: () => {
+        throw new Error("Symbol.hasInstance");
+      },
+    });
+
+    try {
+      assert.throws(() => addon.message_port_double({}), /JsMessagePort/);
+    } finally {
+      delete MessagePort[Symbol.hasInstance];
+    }
+  });
+});
+
+describe("Worker", () => {
+  it("should exchange messages with a worker started from Rust", async () => {
+    assert.deepStrictEqual(await addon.double_in_worker(), [2, 4, 6]);
+  });
+
+  it("should start a worker from a data: URL", async () => {
+    const code = `
+      import { parentPort } from "node:worker_threads";
+
+      parentPort.on("message", (n) => {
+        parentPort.postMessage(n * 2);
+
+        if (n === 3) {
+          parentPort.close();
+        }
+      });
+    `;
+    const url = `data:text/javascript,${encodeURIComponent(code)}`;
+
+    assert.deepStrictEqual(await addon.double_in_worker_file(url), [2, 4, 6]);
   });
 });
 
@@ -213,3 +391,70 @@ describe("Instance-local storage", () => {
     });
   });
 });
+
+describe("Instance store", () => {
+  it("should persist values between calls", () => {
+    const first = addon.increment_store_count();
+
+    assert.strictEqual(addon.increment_store_count(), first + 1);
+    assert.strictEqual(addon.instanceStore.get("count"), first + 1);
+  });
+
+  it("should share values with JavaScript", () => {
+    addon.instanceStore.set("count", 41);
+
+    assert.strictEqual(addon.increment_store_count(), 42);
+  });
+
+  it("should throw when a value has the wrong type", () => {
+    addon.instanceStore.set("count", "not a number");
+
+    assert.throws(() => addon.increment_store_count(), TypeError);
+    assert.strictEqual(addon.delete_from_store("count"), true);
+    assert.strictEqual(addon.delete_from_store("count"), false);
+    assert.strictEqual(addon.increment_store_count(), 1);
+  });
+
+  it("should ignore methods replaced by JavaScript", () => {
+    const store = addon.instanceStore;
+
+    store.set("count", 1);
+    store.get = () => "not a number";
+    store.set = () => {
+      throw new Error("replaced");
+    };
+
+    try {
+      assert.strictEqual(addon.increment_store_count(), 2);
+    } finally {
+      delete store.get;
+      delete store.set;
+    }
+
+    assert.strictEqual(store.get("count"), 2);
+  });
+
+  it("should store boxed Rust data", () => {
+    assert.strictEqual(addon.load_name(), null);
+
+    addon.store_name("Neon");
+
+    assert.strictEqual(addon.load_name(), "Neon");
+    assert.strictEqual(addon.delete_from_store("name"), true);
+    assert.strictEqual(addon.load_name(), null);
+  });
+
+  it("should allocate a separate store for each addon instance", (cb) => {
+    addon.instanceStore.set("count", 100);
+
+    const worker = new Worker(__filename);
+
+    worker.once("message", (message) => {
+      assert.strictEqual(message, 1);
+      assert.strictEqual(addon.instanceStore.get("count"), 100);
+      cb();
+    });
+
+    worker.postMessage("get_store_count");
+  });
+});
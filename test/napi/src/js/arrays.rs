@@ -24,3 +24,85 @@ pub fn read_js_array(mut cx: FunctionContext) -> JsResult<JsValue> {
 
     Ok(first_element)
 }
+
+pub fn return_js_array_from_iter(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let values = (0..len).map(|i| cx.number(i)).collect::<Vec<_>>();
+
+    cx.array_from_iter(values)
+}
+
+pub fn return_js_array_set_each(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let array = cx.empty_array();
+
+    for i in 0..len {
+        let n = cx.number(i);
+        array.set(&mut cx, i, n)?;
+    }
+
+    Ok(array)
+}
+
+pub fn return_js_array_from_slice(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let a = cx.number(1).upcast::<JsValue>();
+    let b = cx.string("two").upcast();
+    let c = cx.boolean(true).upcast();
+
+    JsArray::from_slice(&mut cx, &[a, b, c])
+}
+
+pub fn push_js_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let value = cx.argument::<JsValue>(1)?;
+    let len = array.push(&mut cx, value)?;
+
+    Ok(cx.number(len))
+}
+
+pub fn copy_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let mut values = Vec::new();
+
+    array.try_for_each(&mut cx, |_cx, i, v| {
+        assert_eq!(i as usize, values.len());
+        values.push(v);
+        Ok(())
+    })?;
+
+    cx.array_from_iter(values)
+}
+
+pub fn sum_js_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let mut sum = 0.0;
+
+    array.try_for_each(&mut cx, |cx, _i, v| {
+        sum += v.downcast_or_throw::<JsNumber, _>(cx)?.value(cx);
+        Ok(())
+    })?;
+
+    Ok(cx.number(sum))
+}
+
+pub fn double_f64_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let values = array
+        .to_f64_vec(&mut cx)?
+        .into_iter()
+        .map(|n| n * 2.0)
+        .collect::<Vec<_>>();
+
+    cx.array_from_f64s(&values)
+}
+
+pub fn double_i32_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let values = array
+        .to_i32_vec(&mut cx)?
+        .into_iter()
+        .map(|n| n.wrapping_mul(2))
+        .collect::<Vec<_>>();
+
+    cx.array_from_i32s(&values)
+}
@@ -1,6 +1,9 @@
 use std::cell::RefCell;
 
-use neon::{prelude::*, types::extract::Boxed};
+use neon::{
+    prelude::*,
+    types::{extract::Boxed, ExternalType, JsExternal},
+};
 
 pub struct Person {
     name: String,
@@ -38,6 +41,18 @@ pub fn person_greet(mut cx: FunctionContext) -> JsResult<JsString> {
     Ok(greeting)
 }
 
+// Downcasting a handle to its own type does not need to check with the engine
+pub fn person_greet_downcast(mut cx: FunctionContext) -> JsResult<JsString> {
+    let person = cx.argument::<JsBox<Person>>(0)?;
+
+    assert!(person.is_a::<JsBox<Person>, _>(&mut cx));
+
+    let person = person.downcast_or_throw::<JsBox<Person>, _>(&mut cx)?;
+    let greeting = cx.string(person.greet());
+
+    Ok(greeting)
+}
+
 pub fn ref_person_new(mut cx: FunctionContext) -> JsResult<JsValue> {
     let name = cx.argument::<JsString>(0)?.value(&mut cx);
     let person = RefCell::new(Person::new(name));
@@ -73,6 +88,41 @@ pub fn external_unit(mut cx: FunctionContext) -> JsResult<JsBox<()>> {
     Ok(cx.boxed(()))
 }
 
+#[repr(C)]
+pub struct Counter(f64);
+
+impl Finalize for Counter {}
+
+unsafe impl ExternalType for Counter {
+    const TYPE_TAG: u128 = 0x8f4c_2b1d_6e3a_4f70_b5d9_1a2c_3e4f_5061;
+}
+
+// Same layout as `Counter`, but a different tag
+#[repr(C)]
+pub struct OtherCounter(f64);
+
+unsafe impl ExternalType for OtherCounter {
+    const TYPE_TAG: u128 = 0x2d7e_9c40_a1b3_4c5d_8e6f_7a8b_9c0d_1e2f;
+}
+
+pub fn external_counter_new(mut cx: FunctionContext) -> JsResult<JsExternal<Counter>> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+    Ok(JsExternal::new(&mut cx, Counter(n)))
+}
+
+pub fn external_counter_get(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let counter = cx.argument::<JsExternal<Counter>>(0)?;
+
+    Ok(cx.number(counter.0))
+}
+
+pub fn external_other_counter_get(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let counter = cx.argument::<JsExternal<OtherCounter>>(0)?;
+
+    Ok(cx.number(counter.0))
+}
+
 #[neon::export]
 fn create_boxed_string(s: String) -> Boxed<String> {
     Boxed(s)
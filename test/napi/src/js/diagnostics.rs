@@ -0,0 +1,69 @@
+use std::cell::RefCell;
+
+use neon::{
+    diagnostics::{self, Kind},
+    prelude::*,
+    types::buffer::{BorrowError, TypedArray},
+};
+
+thread_local! {
+    static HELD: RefCell<Vec<Root<JsObject>>> = const { RefCell::new(Vec::new()) };
+}
+
+#[neon::export]
+fn diagnostics_report<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsObject> {
+    cx.diagnostics_report()
+}
+
+#[neon::export]
+fn capture_backtraces(enabled: bool) {
+    diagnostics::capture_backtraces(enabled);
+}
+
+#[neon::export]
+fn hold_roots<'cx>(cx: &mut Cx<'cx>, object: Handle<'cx, JsObject>, n: f64) {
+    let roots = (0..n as usize).map(|_| object.root(cx)).collect::<Vec<_>>();
+
+    HELD.with(|held| held.borrow_mut().extend(roots));
+}
+
+#[neon::export]
+fn release_roots(cx: &mut Cx) {
+    for root in HELD.with(|held| held.take()) {
+        root.drop(cx);
+    }
+}
+
+// Returns the number of deferreds before, while, and after settling a promise
+#[neon::export]
+fn count_deferreds(cx: &mut Cx) -> Vec<f64> {
+    let count = || diagnostics::report().count(Kind::Deferred) as f64;
+    let before = count();
+    let (deferred, _) = cx.promise();
+    let during = count();
+    let value = cx.undefined();
+
+    deferred.resolve(cx, value);
+
+    vec![before, during, count()]
+}
+
+// Returns the number of borrows while borrowing two arrays
+#[neon::export]
+fn count_borrows<'cx>(
+    cx: &mut Cx<'cx>,
+    a: Handle<'cx, JsUint8Array>,
+    b: Handle<'cx, JsUint8Array>,
+) -> NeonResult<f64> {
+    let mut run = || -> Result<_, BorrowError> {
+        let lock = cx.lock();
+        let _a = a.try_borrow(&lock)?;
+        let _b = b.try_borrow(&lock)?;
+
+        Ok(diagnostics::report().count(Kind::Borrow) as f64)
+    };
+
+    let count = run();
+
+    count.or_throw(cx)
+}
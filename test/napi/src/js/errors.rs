@@ -1,4 +1,36 @@
-use neon::prelude::*;
+use std::{cell::Cell, fmt};
+
+use neon::{prelude::*, result::ThrowError};
+
+thread_local! {
+    // The decorator is registered for the whole module, so tests enable it only
+    // while they run
+    static DECORATE_ERRORS: Cell<bool> = const { Cell::new(false) };
+}
+
+pub fn decorate<'cx>(cx: &mut Cx<'cx>, err: Handle<'cx, JsError>) -> NeonResult<()> {
+    if !DECORATE_ERRORS.with(Cell::get) {
+        return Ok(());
+    }
+
+    let msg: String = err.prop(cx, "message").get()?;
+
+    if msg == "throw from decorator" {
+        return cx.throw_error("thrown by decorator");
+    }
+
+    err.prop(cx, "decorated").set(true)?;
+
+    Ok(())
+}
+
+pub fn set_decorate_errors(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let enabled = cx.argument::<JsBoolean>(0)?.value(&mut cx);
+
+    DECORATE_ERRORS.with(|decorate| decorate.set(enabled));
+
+    Ok(cx.undefined())
+}
 
 pub fn new_error(mut cx: FunctionContext) -> JsResult<JsError> {
     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
@@ -18,6 +50,113 @@ pub fn new_range_error(mut cx: FunctionContext) -> JsResult<JsError> {
     cx.range_error(msg)
 }
 
+pub fn new_syntax_error(mut cx: FunctionContext) -> JsResult<JsError> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsError::syntax_error(&mut cx, msg)
+}
+
+pub fn new_eval_error(mut cx: FunctionContext) -> JsResult<JsError> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsError::eval_error(&mut cx, msg)
+}
+
+pub fn new_uri_error(mut cx: FunctionContext) -> JsResult<JsError> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsError::uri_error(&mut cx, msg)
+}
+
+pub fn new_error_with_type(mut cx: FunctionContext) -> JsResult<JsError> {
+    let constructor = cx.argument::<JsFunction>(0)?;
+    let msg = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    JsError::with_type(&mut cx, constructor, msg)
+}
+
+pub fn throw_error_with_code(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let path = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.error_builder(format!("ENOENT: no such file or directory, open '{path}'"))
+        .code("ENOENT")
+        .prop("errno", -2)?
+        .prop("syscall", "open")?
+        .prop("path", path)?
+        .throw()
+}
+
+#[derive(Debug)]
+struct InvalidPort(u16);
+
+impl fmt::Display for InvalidPort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "port {} is reserved", self.0)
+    }
+}
+
+impl ThrowError for InvalidPort {
+    fn code(&self) -> Option<&str> {
+        Some("ERR_INVALID_PORT")
+    }
+}
+
+pub fn parse_port(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let port = cx.argument::<JsString>(0)?.value(&mut cx);
+    let port: u16 = cx.with_throw_guard(|_| port.parse())?;
+
+    if port < 1024 {
+        return Err(InvalidPort(port)).or_throw(&mut cx);
+    }
+
+    Ok(cx.number(port))
+}
+
+pub fn new_error_from_builder(mut cx: FunctionContext) -> JsResult<JsError> {
+    let constructor = cx.argument::<JsFunction>(0)?;
+    let code = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.error_builder("Oh, no!")
+        .constructor(constructor)
+        .code(code)
+        .build()
+}
+
+pub fn capture_stack_trace(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let frames = cx
+        .capture_stack_trace()?
+        .into_iter()
+        .map(|frame| {
+            cx.object_builder()
+                .prop("functionName", frame.function_name)?
+                .prop("fileName", frame.file_name)?
+                .prop("line", frame.line.map(f64::from))?
+                .prop("column", frame.column.map(f64::from))?
+                .build()
+        })
+        .collect::<NeonResult<Vec<_>>>()?;
+
+    cx.array_from_iter(frames)
+}
+
+pub fn get_error_stack(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let err = cx.argument::<JsError>(0)?;
+
+    match err.stack(&mut cx)? {
+        Some(stack) => Ok(cx.string(stack).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn new_error_with_stack(mut cx: FunctionContext) -> JsResult<JsError> {
+    let stack = cx.argument::<JsString>(0)?.value(&mut cx);
+    let err = cx.error("Oh, no!")?;
+
+    err.set_stack(&mut cx, stack)?;
+
+    Ok(err)
+}
+
 pub fn throw_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
 
@@ -31,6 +31,19 @@ fn rs_renamed_add(a: f64, b: f64) -> f64 {
     simple_add(a, b)
 }
 
+#[neon::export(strict)]
+fn strict_add(a: f64, b: f64) -> f64 {
+    a + b
+}
+
+#[neon::export(strict)]
+fn strict_no_args() {}
+
+#[neon::export(strict)]
+fn strict_add_with_cx<'cx>(cx: &mut Cx<'cx>, a: f64, b: f64) -> Handle<'cx, JsNumber> {
+    cx.number(a + b)
+}
+
 #[neon::export(task)]
 fn add_task(a: f64, b: f64) -> f64 {
     simple_add(a, b)
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use either::Either;
 use neon::{prelude::*, types::extract::*};
 
@@ -132,6 +134,11 @@ pub fn extract_json_sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(nums.into_iter().sum::<f64>()))
 }
 
+#[neon::export]
+pub fn extract_json_dict(Json(keys): Json<Vec<String>>) -> JsonDict<HashMap<String, usize>> {
+    JsonDict(keys.into_iter().enumerate().map(|(i, k)| (k, i)).collect())
+}
+
 pub fn extract_single_add_one(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let n: f64 = cx.args()?;
 
@@ -158,3 +165,46 @@ pub fn buffer_concat(mut a: Vec<u8>, Uint8Array(b): Uint8Array<Vec<u8>>) -> Arra
 pub fn string_to_buf(s: String) -> Uint8Array<String> {
     Uint8Array(s)
 }
+
+#[neon::export]
+pub fn extract_trace_context(cx: TraceContext) -> TraceContext {
+    cx
+}
+
+#[neon::export]
+pub fn trace_in_span(
+    cx: &mut Cx,
+    TraceContext(parent): TraceContext,
+    should_throw: bool,
+) -> NeonResult<TraceContext> {
+    trace::in_span(cx, "trace_in_span", &parent, |cx| {
+        if should_throw {
+            return cx.throw_error("span failed");
+        }
+
+        Ok(TraceContext(opentelemetry::Context::current()))
+    })
+}
+
+#[neon::export]
+// Paths are returned as a string, or as a `Buffer` if they are not valid Unicode
+pub fn extract_path(path: std::path::PathBuf) -> std::path::PathBuf {
+    path
+}
+
+#[neon::export]
+pub fn extract_array(Array(values): Array<Option<f64>>) -> Array<String> {
+    Array(values.into_iter().map(|v| format!("{v:?}")).collect())
+}
+
+#[neon::export]
+pub fn extract_hash_map(map: HashMap<String, Array<String>>) -> HashMap<String, f64> {
+    map.into_iter()
+        .map(|(k, Array(v))| (k, v.len() as f64))
+        .collect()
+}
+
+#[neon::export]
+pub fn extract_hash_map_bool_keys(map: HashMap<bool, f64>) -> f64 {
+    map.len() as f64
+}
@@ -1,4 +1,10 @@
-use neon::{prelude::*, types::extract::With};
+use std::ffi::c_void;
+
+use neon::{
+    context::{Absent, CallInfo, Next},
+    prelude::*,
+    types::extract::With,
+};
 
 fn add1(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let x = cx.argument::<JsNumber>(0)?.value(&mut cx);
@@ -232,6 +238,26 @@ pub fn is_argument_zero_some(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     Ok(cx.boolean(b))
 }
 
+pub fn optional_argument_zero_string(mut cx: FunctionContext) -> JsResult<JsValue> {
+    match cx.argument_opt_typed::<JsString>(0)? {
+        Some(s) => Ok(s.upcast()),
+        None => Ok(cx.null().upcast()),
+    }
+}
+
+pub fn argument_zero_or_default(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    cx.argument_or::<JsNumber>(0, 42.0)
+}
+
+pub fn argument_zero_or_missing(mut cx: FunctionContext) -> JsResult<JsValue> {
+    cx.treat_as_absent(Absent::Missing);
+
+    match cx.argument_opt_typed::<JsValue>(0)? {
+        Some(v) => Ok(v),
+        None => Ok(cx.string("missing").upcast()),
+    }
+}
+
 pub fn require_argument_zero_string(mut cx: FunctionContext) -> JsResult<JsString> {
     let s = cx.argument(0)?;
     Ok(s)
@@ -286,6 +312,21 @@ pub fn call_and_catch(mut cx: FunctionContext) -> JsResult<JsValue> {
         .unwrap_or_else(|err| err))
 }
 
+pub fn call_catching(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let f: Handle<JsFunction> = cx.argument(0)?;
+    let this = cx.undefined();
+    let (threw, value) = match f.call_catching(&mut cx, this, []) {
+        Ok(v) => (false, v),
+        Err(err) => (true, err),
+    };
+    let result = cx.empty_object();
+
+    result.prop(&mut cx, "threw").set(threw)?;
+    result.prop(&mut cx, "value").set(value)?;
+
+    Ok(result)
+}
+
 pub fn get_number_or_default(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let n = cx
         .try_catch(|cx| Ok(cx.argument::<JsNumber>(0)?.value(cx)))
@@ -324,6 +365,37 @@ pub fn is_construct(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(this)
 }
 
+pub fn get_new_target(mut cx: FunctionContext) -> JsResult<JsValue> {
+    match cx.new_target() {
+        Some(new_target) => Ok(new_target.upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+// Constructs `Error` with the `new.target` of the call, so that it can be
+// extended by a JavaScript class
+pub fn construct_error_with_new_target(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let Some(new_target) = cx.new_target() else {
+        return cx.throw_type_error("Constructor must be called with `new`");
+    };
+
+    let message = cx.argument::<JsValue>(0)?;
+    let error: Handle<JsFunction> = cx.global("Error")?;
+
+    error
+        .bind(&mut cx)
+        .new_target(new_target)?
+        .arg(message)?
+        .construct()
+}
+
+pub fn construct_with_new_target(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let f: Handle<JsFunction> = cx.argument(0)?;
+    let new_target = cx.argument::<JsValue>(1)?;
+
+    f.bind(&mut cx).new_target(new_target)?.arg(42)?.construct()
+}
+
 // `function caller_with_drop_callback(wrappedCallback, dropCallback)`
 //
 // `wrappedCallback` will be called each time the returned function is
@@ -373,3 +445,219 @@ pub fn caller_with_drop_callback(mut cx: FunctionContext) -> JsResult<JsFunction
         callback.f.to_inner(&mut cx).call(&mut cx, this, args)
     })
 }
+
+// Registered in `main`; only acts on functions named `intercepted*`
+pub fn intercept<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    info: &CallInfo,
+    next: Next<'_, 'cx>,
+) -> JsResult<'cx, JsValue> {
+    match info.name() {
+        "interceptedDouble" | "interceptedExportFunction" => {
+            let n = next.call(cx)?.downcast_or_throw::<JsNumber, _>(cx)?;
+            let n = n.value(cx);
+
+            Ok(cx.number(n * 2.0).upcast())
+        }
+        "interceptedGuarded" => {
+            let token = cx.argument::<JsString>(0)?.value(cx);
+
+            if token != "secret" {
+                return cx.throw_error("Access denied");
+            }
+
+            next.call(cx)
+        }
+        "interceptedThrow" => match cx.try_catch(|cx| next.call(cx)) {
+            Ok(v) => Ok(v),
+            Err(err) => {
+                let msg = err.to_string(cx)?.value(cx);
+
+                cx.throw_type_error(format!("Intercepted {msg}"))
+            }
+        },
+        "interceptedOrder" => {
+            let log = cx.argument::<JsArray>(0)?;
+            let len = log.len(cx);
+
+            log.prop(cx, len).set("outer")?;
+            next.call(cx)
+        }
+        _ => next.call(cx),
+    }
+}
+
+// Registered after `intercept`, so it runs closer to the function
+pub fn intercept_inner<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    info: &CallInfo,
+    next: Next<'_, 'cx>,
+) -> JsResult<'cx, JsValue> {
+    if info.name() == "interceptedOrder" {
+        let log = cx.argument::<JsArray>(0)?;
+        let len = log.len(cx);
+
+        log.prop(cx, len).set("inner")?;
+    }
+
+    next.call(cx)
+}
+
+#[neon::export]
+fn intercepted_double(n: f64) -> f64 {
+    n + 1.0
+}
+
+// Exported with `ModuleContext::export_function` instead of `neon::export`
+pub fn intercepted_export_function(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+    Ok(cx.number(n + 1.0))
+}
+
+#[neon::export]
+fn intercepted_guarded(_token: String, n: f64) -> f64 {
+    n
+}
+
+#[neon::export]
+fn intercepted_throw<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsUndefined> {
+    cx.throw_error("failure")
+}
+
+#[neon::export]
+fn intercepted_order<'cx>(cx: &mut Cx<'cx>, log: Handle<'cx, JsArray>) -> NeonResult<()> {
+    let len = log.len(cx);
+
+    log.prop(cx, len).set("function")?;
+
+    Ok(())
+}
+
+struct CallbackData {
+    f: Root<JsFunction>,
+    sum: f64,
+}
+
+// Simulates a C library that synchronously invokes a callback for each item
+extern "C" fn for_each_item(len: u32, cb: extern "C" fn(u32, *mut c_void), data: *mut c_void) {
+    for i in 0..len {
+        cb(i, data);
+    }
+}
+
+extern "C" fn add_item(i: u32, data: *mut c_void) {
+    let data = unsafe { &mut *data.cast::<CallbackData>() };
+
+    // `None` after a previous item threw
+    // Safety: `sum_with_c_callbacks` holds no borrows while calling `for_each_item`
+    let result = unsafe {
+        Cx::with_current(|cx| {
+            data.f
+                .to_inner(cx)
+                .call_with(cx)
+                .arg(cx.number(i))
+                .apply::<JsNumber, _>(cx)
+                .map(|n| n.value(cx))
+        })
+    };
+
+    if let Some(Ok(n)) = result {
+        data.sum += n;
+    }
+}
+
+pub fn sum_with_c_callbacks(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let f = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let len = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let mut data = CallbackData { f, sum: 0.0 };
+
+    // Catches an exception left pending by a callback
+    let result = cx.try_catch(|_| {
+        for_each_item(len, add_item, (&mut data as *mut CallbackData).cast());
+        Ok(())
+    });
+
+    data.f.drop(&mut cx);
+
+    if let Err(err) = result {
+        return cx.throw(err);
+    }
+
+    Ok(cx.number(data.sum))
+}
+
+pub fn has_current_context_on_thread(mut cx: FunctionContext) -> JsResult<JsArray> {
+    // Safety: The callbacks do not run JavaScript
+    let here = unsafe { Cx::with_current(|_| ()) }.is_some();
+    let background = std::thread::spawn(|| unsafe { Cx::with_current(|_| ()) }.is_some())
+        .join()
+        .unwrap();
+    let here = cx.boolean(here);
+    let background = cx.boolean(background);
+
+    cx.array_from_iter([here, background])
+}
+
+// Returns a function that counts its calls and calls `f` with the count
+pub fn counter_with_callback(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let f = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let mut count = 0;
+
+    JsFunction::new_mut(&mut cx, move |mut cx| {
+        count += 1;
+
+        f.to_inner(&mut cx)
+            .call_with(&cx)
+            .arg(cx.number(count))
+            .exec(&mut cx)?;
+
+        Ok(cx.number(count))
+    })
+}
+
+pub fn call_spread(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let this = cx.argument::<JsValue>(1)?;
+    let args = cx.argument::<JsArray>(2)?;
+
+    f.call_spread(&mut cx, this, args)
+}
+
+// Calls `f` with the arguments after it, like `(f, ...args) => f(...args)`
+pub fn call_with_rest(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let args = cx.rest_arguments(1);
+    let this = cx.undefined();
+
+    f.call(&mut cx, this, args)
+}
+
+// Calls `f(depth - 1)`, which is expected to call back into `reenter`, alternating
+// between a scoped and an unscoped context. Returns the depth reached.
+pub fn reenter(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let depth = cx.argument::<JsNumber>(1)?.value(&mut cx);
+
+    if depth <= 0.0 {
+        return Ok(cx.number(0));
+    }
+
+    let n: f64 = if depth % 2.0 == 0.0 {
+        cx.compute_scoped(|mut cx| f.bind(&mut cx).arg(depth - 1.0)?.call::<Handle<JsNumber>>())?
+            .value(&mut cx)
+    } else {
+        f.bind(&mut cx).arg(depth - 1.0)?.call()?
+    };
+
+    Ok(cx.number(n + 1.0))
+}
+
+// Calls `f`, catching an exception thrown by a nested call
+pub fn reenter_catching(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+
+    Ok(cx
+        .try_catch(|cx| f.bind(cx).call())
+        .unwrap_or_else(|err| err))
+}
@@ -3,7 +3,7 @@ use std::future::Future;
 use neon::{
     prelude::*,
     types::{
-        buffer::TypedArray,
+        buffer::{BytesGuard, TypedArray},
         extract::{Error, Json, TryIntoJs, With},
     },
 };
@@ -79,6 +79,21 @@ pub fn lazy_async_sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+// Spawns a future that multiplies a number by two and resolves with the result.
+// Rejects with a `RangeError` if the result is not finite.
+// Purpose: Test `neon::futures::spawn`.
+pub fn spawn_double(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+    neon::futures::spawn(&mut cx, async move { n * 2.0 }, |mut cx, n| {
+        if !n.is_finite() {
+            return cx.throw_range_error("Result is not finite");
+        }
+
+        Ok(cx.number(n))
+    })
+}
+
 #[neon::export]
 async fn async_fn_add(a: f64, b: f64) -> f64 {
     a + b
@@ -151,3 +166,16 @@ async fn await_callback(ch: Channel, cb: Root<JsFunction>) -> Result<Root<JsObje
 
     Ok(res)
 }
+
+// Copies the bytes of a buffer and sums them after yielding to the runtime.
+// Purpose: Test that `BytesGuard` remains valid across `.await`.
+#[neon::export(async)]
+fn async_sum_bytes(cx: &mut FunctionContext) -> NeonResult<impl Future<Output = f64>> {
+    let buf: Handle<JsBuffer> = cx.argument(0)?;
+    let bytes = BytesGuard::new(cx, &*buf);
+
+    Ok(async move {
+        tokio::task::yield_now().await;
+        bytes.iter().map(|&b| b as f64).sum()
+    })
+}
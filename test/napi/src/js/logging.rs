@@ -0,0 +1,45 @@
+use std::thread;
+
+use log::{Level, Log, Record};
+use neon::{log::Logger, prelude::*};
+
+fn parse_level(cx: &mut Cx, level: &str) -> NeonResult<Level> {
+    level
+        .parse()
+        .or_else(|_| cx.throw_range_error(format!("Unknown level: {level}")))
+}
+
+#[neon::export]
+fn init_logger<'cx>(cx: &mut Cx<'cx>, channel: Handle<'cx, JsObject>) -> NeonResult<()> {
+    Logger::diagnostics_channel(cx, channel)
+        .level(log::LevelFilter::Debug)
+        .init()
+        .or_else(|err| cx.throw_error(err.to_string()))
+}
+
+#[neon::export]
+fn log_from_thread(cx: &mut Cx, level: String, message: String) -> NeonResult<()> {
+    let level = parse_level(cx, &level)?;
+
+    thread::spawn(move || log::log!(level, "{message}"))
+        .join()
+        .unwrap();
+
+    Ok(())
+}
+
+#[neon::export]
+fn log_to_console(cx: &mut Cx, level: String, message: String) -> NeonResult<()> {
+    let level = parse_level(cx, &level)?;
+    let logger = Logger::console(cx).level(log::LevelFilter::Debug);
+
+    logger.log(
+        &Record::builder()
+            .level(level)
+            .target("napi_tests")
+            .args(format_args!("{message}"))
+            .build(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use neon::prelude::*;
+
+#[neon::export]
+fn heap_statistics<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsObject> {
+    let stats = cx.heap_statistics()?;
+
+    cx.object_builder()
+        .prop("rss", stats.rss as f64)?
+        .prop("heapTotal", stats.heap_total as f64)?
+        .prop("heapUsed", stats.heap_used as f64)?
+        .prop("external", stats.external as f64)?
+        .prop("arrayBuffers", stats.array_buffers as f64)?
+        .build()
+}
+
+#[neon::export]
+fn adjust_external_memory(cx: &mut Cx, change_in_bytes: f64) -> f64 {
+    cx.adjust_external_memory(change_in_bytes as i64) as f64
+}
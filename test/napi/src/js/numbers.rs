@@ -39,3 +39,52 @@ pub fn accept_and_return_negative_js_number(mut cx: FunctionContext) -> JsResult
     let number: Handle<JsNumber> = cx.argument(0)?;
     Ok(number)
 }
+
+fn parse_argument<T: std::str::FromStr>(cx: &mut FunctionContext) -> NeonResult<T> {
+    let s = cx.argument::<JsString>(0)?.value(cx);
+
+    s.parse()
+        .or_else(|_| cx.throw_type_error(format!("invalid integer: {s}")))
+}
+
+pub fn number_from_i64(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = parse_argument::<i64>(&mut cx)?;
+
+    cx.number_from_i64(n).or_throw(&mut cx)
+}
+
+pub fn number_from_u64(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = parse_argument::<u64>(&mut cx)?;
+
+    cx.number_from_u64(n).or_throw(&mut cx)
+}
+
+pub fn number_or_bigint_from_i64(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let n = parse_argument::<i64>(&mut cx)?;
+
+    Ok(cx.number_or_bigint_from_i64(n))
+}
+
+pub fn number_or_bigint_from_u64(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let n = parse_argument::<u64>(&mut cx)?;
+
+    Ok(cx.number_or_bigint_from_u64(n))
+}
+
+pub fn number_to_i64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx
+        .argument::<JsNumber>(0)?
+        .to_i64(&mut cx)
+        .or_throw(&mut cx)?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn number_to_u64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx
+        .argument::<JsNumber>(0)?
+        .to_u64(&mut cx)
+        .or_throw(&mut cx)?;
+
+    Ok(cx.string(n.to_string()))
+}
@@ -1,6 +1,11 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cell::RefCell};
 
-use neon::{prelude::*, types::buffer::TypedArray};
+use neon::{
+    object::{PropertyNamesOptions, Schema},
+    prelude::*,
+    types::{buffer::TypedArray, extract::TryIntoJs},
+    util::{self, DiffOptions},
+};
 
 pub fn return_js_global_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.global_object())
@@ -10,6 +15,12 @@ pub fn return_js_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.empty_object())
 }
 
+pub fn return_js_dict(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let dict = cx.empty_dict()?;
+    dict.prop(&mut cx, "__proto__").set("value")?;
+    Ok(dict)
+}
+
 pub fn return_js_object_with_mixed_content(mut cx: FunctionContext) -> JsResult<JsObject> {
     let js_object: Handle<JsObject> = cx.empty_object();
     let n = cx.number(9000.0);
@@ -122,3 +133,306 @@ pub fn call_non_method_with_prop(mut cx: FunctionContext) -> JsResult<JsUndefine
     obj.prop(&mut cx, "number").bind()?.exec()?;
     Ok(cx.undefined())
 }
+
+pub fn return_object_builder(mut cx: FunctionContext) -> JsResult<JsObject> {
+    cx.object_builder()
+        .prop("name", "neon")?
+        .prop("version", 1)?
+        .prop("version", 2)?
+        .prop("nul\0key", true)?
+        .prop("", ())?
+        .build()
+}
+
+pub fn return_object_builder_with_len(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let mut builder = cx.object_builder();
+
+    for i in 0..len {
+        builder.prop(&format!("k{i}"), i)?;
+    }
+
+    builder.build()
+}
+
+pub fn get_many_props(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let values = obj.get_many(&mut cx, &["a", "b", "nul\0key", "missing"])?;
+    let result = cx.empty_array();
+
+    for (i, v) in values.into_iter().enumerate() {
+        result.prop(&mut cx, i as u32).set(v)?;
+    }
+
+    Ok(result)
+}
+
+pub fn define_many_props(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let a = cx.number(1).upcast::<JsValue>();
+    let b = cx.string("b").upcast();
+    let nul = cx.boolean(true).upcast();
+
+    obj.define_many(&mut cx, &[("a", a), ("b", b), ("nul\0key", nul)])?;
+
+    Ok(cx.undefined())
+}
+
+pub fn return_object_set_each_with_len(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let obj = cx.empty_object();
+
+    for i in 0..len {
+        obj.prop(&mut cx, format!("k{i}").as_str()).set(i)?;
+    }
+
+    Ok(obj)
+}
+
+pub fn diff_objects(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let before = cx.argument::<JsObject>(0)?;
+    let after = cx.argument::<JsObject>(1)?;
+    let max_depth = cx.argument_opt(2);
+    let mut options = DiffOptions::new();
+
+    if let Some(max_depth) = max_depth {
+        let max_depth = max_depth.downcast_or_throw::<JsNumber, _>(&mut cx)?;
+
+        options = options.max_depth(max_depth.value(&mut cx) as usize);
+    }
+
+    let changes = util::diff(&mut cx, before, after, options)?
+        .into_iter()
+        .map(|change| change.try_into_js(&mut cx))
+        .collect::<NeonResult<Vec<_>>>()?;
+
+    cx.array_from_iter(changes)
+}
+
+struct Tag {
+    name: String,
+    finalized: Option<Root<JsFunction>>,
+}
+
+impl Finalize for Tag {
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+        if let Some(finalized) = self.finalized {
+            let name = cx.string(self.name);
+            let _ = finalized.into_inner(cx).call_with(cx).arg(name).exec(cx);
+        }
+    }
+}
+
+pub fn wrap_object(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let name = cx.argument::<JsString>(1)?.value(&mut cx);
+    let finalized = cx
+        .argument_opt(2)
+        .map(|f| f.downcast_or_throw::<JsFunction, _>(&mut cx))
+        .transpose()?
+        .map(|f| f.root(&mut cx));
+
+    neon::object::wrap(&mut cx, obj, Tag { name, finalized })?;
+
+    Ok(cx.undefined())
+}
+
+pub fn unwrap_object(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    match neon::object::unwrap::<Tag, _>(&mut cx, obj)? {
+        Some(tag) => Ok(cx.string(&tag.name).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn unwrap_object_as_string(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    match neon::object::unwrap::<String, _>(&mut cx, obj)? {
+        Some(s) => Ok(cx.string(s).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+struct Counter(u32);
+
+impl Finalize for Counter {}
+
+pub fn wrap_counter(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let this = cx.this::<JsObject>()?;
+
+    neon::object::wrap(&mut cx, this, RefCell::new(Counter(0)))?;
+
+    Ok(cx.undefined())
+}
+
+pub fn counter_count(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let counter = cx.this_internals::<RefCell<Counter>>()?;
+    let count = match counter.try_borrow() {
+        Ok(counter) => counter.0,
+        Err(_) => return cx.throw_error("RefCell is already mutably borrowed"),
+    };
+
+    Ok(cx.number(count))
+}
+
+// Increments the counter, calling `f` while the counter is borrowed
+pub fn counter_increment(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let f = cx.argument_opt(0);
+    let mut counter = cx.this_internals_mut::<Counter>()?;
+
+    counter.0 += 1;
+
+    if let Some(f) = f {
+        f.downcast_or_throw::<JsFunction, _>(&mut cx)?
+            .bind(&mut cx)
+            .exec()?;
+    }
+
+    let count = counter.0;
+
+    Ok(cx.number(count))
+}
+
+pub fn get_property_names(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let options = cx.argument::<JsObject>(1)?;
+    let symbols = options.prop(&mut cx, "symbols").get()?;
+    let non_enumerable = options.prop(&mut cx, "nonEnumerable").get()?;
+    let prototypes = options.prop(&mut cx, "prototypes").get()?;
+    let options = PropertyNamesOptions::new()
+        .symbols(symbols)
+        .non_enumerable(non_enumerable)
+        .prototypes(prototypes);
+
+    obj.get_property_names(&mut cx, options)
+}
+
+pub fn has_own_property(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let has = obj.has_own_property(&mut cx, key)?;
+
+    Ok(cx.boolean(has))
+}
+
+pub fn has_own_property_str(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsString>(1)?.value(&mut cx);
+    let has = obj.has_own_property(&mut cx, key.as_str())?;
+
+    Ok(cx.boolean(has))
+}
+
+pub fn has_own_property_index(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let index = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let has = obj.has_own_property(&mut cx, index)?;
+
+    Ok(cx.boolean(has))
+}
+
+pub fn delete_property(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let deleted = obj.delete(&mut cx, key)?;
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn delete_property_str(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsString>(1)?.value(&mut cx);
+    let deleted = obj.delete(&mut cx, key.as_str())?;
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn delete_property_index(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let index = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let deleted = obj.delete(&mut cx, index)?;
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn set_global(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let value = cx.argument::<JsValue>(1)?;
+
+    cx.global_set(&name, value)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn get_intrinsics(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array = neon::intrinsics::array(&mut cx)?.upcast::<JsValue>();
+    let object = neon::intrinsics::object(&mut cx)?.upcast();
+    let promise = neon::intrinsics::promise(&mut cx)?.upcast();
+    let error = neon::intrinsics::error(&mut cx)?.upcast();
+    let json = neon::intrinsics::json(&mut cx)?.upcast();
+    let symbol = neon::intrinsics::symbol(&mut cx)?.upcast();
+    let process = neon::intrinsics::process(&mut cx)?.upcast();
+
+    JsArray::from_slice(
+        &mut cx,
+        &[array, object, promise, error, json, symbol, process],
+    )
+}
+
+pub fn get_process_env(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    match cx.process_env(&name)? {
+        Some(value) => Ok(cx.string(value).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn require_module(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let specifier = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.require(&specifier)
+}
+
+pub fn require_module_from(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let base = cx.argument::<JsString>(0)?.value(&mut cx);
+    let specifier = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.require_from(&base, &specifier)
+}
+
+pub fn get_process_argv(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let argv = cx.process_argv()?;
+    let arr = cx.empty_array();
+
+    for (i, arg) in argv.into_iter().enumerate() {
+        let arg = cx.string(arg);
+
+        arr.set(&mut cx, i as u32, arg)?;
+    }
+
+    Ok(arr)
+}
+
+pub fn validate_options(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let options = cx.argument::<JsValue>(0)?;
+    let fields = Schema::new()
+        .field::<JsNumber>("id")
+        .optional::<JsString>("name")
+        .optional_object("retry", Schema::new().field::<JsNumber>("attempts"))
+        .validate(&mut cx, options)?;
+
+    let id = fields.get::<JsNumber>(&mut cx, "id")?.upcast::<JsValue>();
+    let name = match fields.get_opt::<JsString>(&mut cx, "name")? {
+        Some(name) => name.upcast(),
+        None => cx.undefined().upcast(),
+    };
+    let attempts = match fields.get_opt::<JsNumber>(&mut cx, "retry.attempts")? {
+        Some(attempts) => attempts.upcast(),
+        None => cx.undefined().upcast(),
+    };
+
+    cx.array_from_iter([id, name, attempts])
+}
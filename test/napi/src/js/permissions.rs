@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use neon::prelude::*;
+
+#[neon::export]
+fn permissions_enabled(cx: &mut Cx) -> NeonResult<bool> {
+    Ok(cx.permissions()?.is_enabled())
+}
+
+#[neon::export]
+fn has_fs_read(cx: &mut Cx, path: String) -> NeonResult<bool> {
+    cx.permissions()?.has_fs_read(path)
+}
+
+#[neon::export]
+fn has_fs_write(cx: &mut Cx, path: PathBuf) -> NeonResult<bool> {
+    cx.permissions()?.has_fs_write(path)
+}
+
+#[neon::export]
+fn has_child_process(cx: &mut Cx) -> NeonResult<bool> {
+    cx.permissions()?.has_child_process()
+}
+
+#[neon::export]
+fn require_fs_write(cx: &mut Cx, path: String) -> NeonResult<()> {
+    cx.permissions()?.require_fs_write(path)
+}
@@ -0,0 +1,69 @@
+use neon::{
+    prelude::*,
+    types::{JsProxy, ProxyHandler},
+};
+
+// Reads fall back to the prefixed property key, writes to `readonly` fail, and keys
+// starting with an underscore are hidden from `in`
+#[neon::export]
+fn virtual_object<'cx>(cx: &mut Cx<'cx>, prefix: String) -> JsResult<'cx, JsProxy> {
+    let target = cx.empty_object();
+    let handler = ProxyHandler::new(cx)
+        .get(move |cx, target, key| {
+            if target.has_own_property(cx, key)? {
+                return target.prop(cx, key).get();
+            }
+
+            match key.downcast::<JsString, _>(cx) {
+                Ok(key) => {
+                    let key = key.value(cx);
+
+                    Ok(cx.string(format!("{prefix}{key}")).upcast())
+                }
+                Err(_) => Ok(cx.undefined().upcast()),
+            }
+        })?
+        .set(|cx, target, key, value| {
+            if key.to_string(cx)?.value(cx) == "readonly" {
+                return Ok(false);
+            }
+
+            target.prop(cx, key).set(value)?;
+
+            Ok(true)
+        })?
+        .has(|cx, _target, key| match key.downcast::<JsString, _>(cx) {
+            Ok(key) => Ok(!key.value(cx).starts_with('_')),
+            Err(_) => Ok(false),
+        })?
+        .build();
+
+    JsProxy::new(cx, target, handler)
+}
+
+#[neon::export]
+fn new_proxy<'cx>(
+    cx: &mut Cx<'cx>,
+    target: Handle<'cx, JsObject>,
+    handler: Handle<'cx, JsObject>,
+) -> JsResult<'cx, JsProxy> {
+    JsProxy::new(cx, target, handler)
+}
+
+#[neon::export]
+fn is_proxy<'cx>(cx: &mut Cx<'cx>, value: Handle<'cx, JsValue>) -> bool {
+    value.is_a::<JsProxy, _>(cx)
+}
+
+// Reads every property of the target function through a `get` trap
+#[neon::export]
+fn function_proxy<'cx>(
+    cx: &mut Cx<'cx>,
+    target: Handle<'cx, JsFunction>,
+) -> JsResult<'cx, JsProxy> {
+    let handler = ProxyHandler::new(cx)
+        .get(|cx, target, key| target.prop(cx, key).get())?
+        .build();
+
+    JsProxy::new(cx, target, handler)
+}
@@ -1,4 +1,8 @@
-use neon::{prelude::*, reflect::eval};
+use neon::{
+    prelude::*,
+    reflect::eval,
+    types::{atom::AtomTable, buffer::TypedArray},
+};
 
 pub fn return_js_string(mut cx: FunctionContext) -> JsResult<JsString> {
     Ok(cx.string("hello node"))
@@ -19,6 +23,90 @@ pub fn return_length_utf16(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(value.len() as f64))
 }
 
+pub fn string_from_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    let units = cx.argument::<JsTypedArray<u16>>(0)?;
+    let units = units.as_slice(&cx).to_vec();
+
+    Ok(JsString::from_utf16(&mut cx, &units))
+}
+
+pub fn string_to_utf16(mut cx: FunctionContext) -> JsResult<JsTypedArray<u16>> {
+    let units = cx.argument::<JsString>(0)?.to_utf16(&mut cx);
+    JsTypedArray::from_slice(&mut cx, &units)
+}
+
+pub fn string_from_latin1(mut cx: FunctionContext) -> JsResult<JsString> {
+    let bytes = cx.argument::<JsBuffer>(0)?;
+    let bytes = bytes.as_slice(&cx).to_vec();
+
+    Ok(JsString::from_latin1(&mut cx, &bytes))
+}
+
+pub fn string_to_latin1(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let bytes = cx.argument::<JsString>(0)?.to_latin1(&mut cx);
+    JsBuffer::from_slice(&mut cx, &bytes)
+}
+
+pub fn external_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    let s = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsString::external(&mut cx, s).or_throw(&mut cx)
+}
+
+pub fn external_string_latin1(mut cx: FunctionContext) -> JsResult<JsString> {
+    let bytes = cx.argument::<JsBuffer>(0)?;
+    let bytes = bytes.as_slice(&cx).to_vec();
+
+    JsString::external_latin1(&mut cx, bytes).or_throw(&mut cx)
+}
+
+pub fn external_string_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    let units = cx.argument::<JsTypedArray<u16>>(0)?;
+    let units = units.as_slice(&cx).to_vec();
+
+    JsString::external_utf16(&mut cx, units).or_throw(&mut cx)
+}
+
+pub fn concat_strings(mut cx: FunctionContext) -> JsResult<JsString> {
+    let parts = cx
+        .argument::<JsArray>(0)?
+        .to_vec(&mut cx)?
+        .into_iter()
+        .map(|v| v.downcast_or_throw(&mut cx))
+        .collect::<NeonResult<Vec<Handle<JsString>>>>()?;
+
+    JsString::concat(&mut cx, &parts)
+}
+
+pub fn substring(mut cx: FunctionContext) -> JsResult<JsString> {
+    let s = cx.argument::<JsString>(0)?;
+    let start = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let end = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+    s.substring(&mut cx, start, end)
+}
+
+// Interns each string and returns `[atom, text]` pairs
+pub fn intern_strings(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let strings = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    let mut atoms = AtomTable::new();
+    let result = cx.empty_array();
+
+    for (i, s) in strings.into_iter().enumerate() {
+        let s = s.downcast_or_throw::<JsString, _>(&mut cx)?;
+        let atom = atoms.intern(&mut cx, s);
+        let text = cx.string(atoms.resolve(atom).unwrap());
+        let atom = cx.number(atom);
+        let pair = cx.empty_array();
+
+        pair.set(&mut cx, 0, atom)?;
+        pair.set(&mut cx, 1, text)?;
+        result.set(&mut cx, i as u32, pair)?;
+    }
+
+    Ok(result)
+}
+
 pub fn run_string_as_script(mut cx: FunctionContext) -> JsResult<JsValue> {
     let string_script = cx.argument::<JsString>(0)?;
     eval(&mut cx, string_script)
@@ -0,0 +1,49 @@
+use neon::{prelude::*, testing};
+
+#[testing::test]
+fn passes_assert(cx: &mut Cx) -> NeonResult<()> {
+    let len = cx.empty_array().len(cx);
+
+    testing::assert(cx, len == 0, "expected an empty array")
+}
+
+#[testing::test]
+fn passes_assert_strict_eq(cx: &mut Cx) -> NeonResult<()> {
+    let s = cx.string("hello");
+
+    testing::assert_strict_eq(cx, s, "hello")
+}
+
+#[testing::test]
+fn passes_assert_throws(cx: &mut Cx) -> NeonResult<()> {
+    let err = testing::assert_throws(cx, |cx| cx.throw_type_error::<_, ()>("oops"))?;
+    let is_error = err.is_a::<JsError, _>(cx);
+
+    testing::assert(cx, is_error, "expected an Error")
+}
+
+#[neon::export]
+fn assert_strict_eq_failure<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsValue> {
+    testing::assert_throws(cx, |cx| {
+        let n = cx.number(1);
+
+        testing::assert_strict_eq(cx, n, "1")
+    })
+}
+
+#[neon::export]
+fn assert_throws_failure<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsValue> {
+    testing::assert_throws(cx, |cx| testing::assert_throws(cx, |_| Ok(())))
+}
+
+#[neon::export]
+fn write_test_driver(path: String, addon: String) -> Result<(), neon::types::extract::Error> {
+    testing::write_driver(path, &addon)?;
+
+    Ok(())
+}
+
+#[neon::export]
+fn bench_driver(addon: String) -> String {
+    testing::bench::driver(&addon)
+}
@@ -1,6 +1,10 @@
-use std::{cell::RefCell, sync::Arc, time::Duration};
+use std::{cell::RefCell, num::NonZeroUsize, sync::Arc, time::Duration};
+
+use once_cell::sync::Lazy;
 
 use neon::{
+    event::{self, CancellationToken, Emitter, ProgressReporter, ThreadPool},
+    handle::WeakRoot,
     prelude::*,
     types::{buffer::TypedArray, extract::Error},
 };
@@ -13,6 +17,36 @@ pub fn useless_root(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(object)
 }
 
+pub fn weak_root(mut cx: FunctionContext) -> JsResult<JsBox<WeakRoot<JsObject>>> {
+    let object = cx.argument::<JsObject>(0)?;
+    let weak = object.weak_root(&mut cx);
+
+    Ok(cx.boxed(weak))
+}
+
+pub fn upgrade_weak_root(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let weak = cx.argument::<JsBox<WeakRoot<JsObject>>>(0)?;
+
+    match weak.upgrade(&mut cx) {
+        Some(object) => Ok(object.upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+// Drops a weak root on another thread and calls back once it has been dropped
+pub fn drop_weak_root_from_thread(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let weak = cx.argument::<JsObject>(0)?.weak_root(&mut cx);
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let channel = cx.channel();
+
+    std::thread::spawn(move || {
+        drop(weak);
+        channel.send(move |mut cx| callback.into_inner(&mut cx).bind(&mut cx).exec());
+    });
+
+    Ok(cx.undefined())
+}
+
 pub fn thread_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
     let channel = cx.channel();
@@ -24,6 +58,41 @@ pub fn thread_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+// Calls back from another thread with a channel and a task with named async resources
+pub fn named_resources_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let channel = Channel::with_name(&mut cx, "neon-test:channel");
+
+    cx.task(|| ()).name("neon-test:task").and_then(move |_, _| {
+        std::thread::spawn(move || {
+            channel.send(move |mut cx| callback.into_inner(&mut cx).bind(&mut cx).exec())
+        });
+
+        Ok(())
+    });
+
+    Ok(cx.undefined())
+}
+
+// Calls back `n` times, each from a thread with its own channel
+pub fn channels_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+
+    for _ in 0..(n as usize) {
+        let callback = callback.clone(&mut cx);
+        let channel = cx.channel();
+
+        std::thread::spawn(move || {
+            channel.send(move |mut cx| callback.into_inner(&mut cx).bind(&mut cx).exec())
+        });
+    }
+
+    callback.drop(&mut cx);
+
+    Ok(cx.undefined())
+}
+
 pub fn multi_threaded_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
     let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
@@ -224,6 +293,264 @@ pub fn sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+pub fn task_with_progress(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let count = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let on_progress = cx.argument::<JsFunction>(1)?;
+    let progress = ProgressReporter::new(&mut cx, on_progress);
+
+    let promise = cx
+        .task(move || {
+            for i in 1..=count {
+                progress.emit(f64::from(i)).unwrap();
+            }
+
+            count
+        })
+        .promise(|mut cx, count| Ok(cx.number(count)));
+
+    Ok(promise)
+}
+
+// Returns `[promise, cancel]`. The promise resolves with `true` when cancelled.
+pub fn cancellable_task(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let token = CancellationToken::new();
+    let cancel = token.to_function(&mut cx)?;
+
+    let promise = cx
+        .task(move || {
+            for _ in 0..10_000 {
+                if token.is_cancelled() {
+                    return true;
+                }
+
+                std::thread::sleep(Duration::from_millis(1));
+            }
+
+            false
+        })
+        .promise(|mut cx, cancelled| Ok(cx.boolean(cancelled)));
+
+    let result = cx.empty_array();
+
+    result.prop(&mut cx, 0).set(promise)?;
+    result.prop(&mut cx, 1).set(cancel)?;
+
+    Ok(result)
+}
+
+// Emits `data` events with the numbers `0..count` from another thread, followed by `end`
+pub fn emit_from_thread(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let emitter = cx.argument::<JsObject>(0)?;
+    let count = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let emitter = Emitter::<Option<f64>>::new(&mut cx, emitter);
+
+    std::thread::spawn(move || {
+        for i in 0..count {
+            emitter.emit("data", Some(f64::from(i))).unwrap();
+        }
+
+        emitter.emit("end", None).unwrap();
+    });
+
+    Ok(cx.undefined())
+}
+
+// Sends the numbers `0..count` from two threads, one half each
+pub fn stream_from_threads(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let count = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let (tx, rx) = event::channel(&mut cx, |cx, n: u32| Ok(cx.number(n)))?;
+    let half = count / 2;
+
+    for range in [0..half, half..count] {
+        let tx = tx.clone();
+
+        std::thread::spawn(move || {
+            for i in range {
+                tx.send(i).unwrap();
+            }
+        });
+    }
+
+    Ok(rx)
+}
+
+// Converting odd numbers throws
+pub fn stream_convert_throw(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let (tx, rx) = event::channel(&mut cx, |cx, n: u32| {
+        if n % 2 == 1 {
+            return cx.throw_error(format!("Odd: {n}"));
+        }
+
+        Ok(cx.number(n))
+    })?;
+
+    for i in 0..4 {
+        tx.send(i).unwrap();
+    }
+
+    Ok(rx)
+}
+
+// Sends until the iterator is closed by JavaScript and resolves with the count
+pub fn stream_until_closed(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let (tx, rx) = event::channel(&mut cx, |cx, n: u32| Ok(cx.number(n)))?;
+    let (deferred, promise) = cx.promise();
+    let channel = cx.channel();
+
+    std::thread::spawn(move || {
+        let mut sent = 0u32;
+
+        while tx.send(sent).is_ok() {
+            sent += 1;
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        deferred.settle_with(&channel, move |mut cx| Ok(cx.number(sent)));
+    });
+
+    let result = cx.empty_array();
+
+    result.prop(&mut cx, 0).set(rx)?;
+    result.prop(&mut cx, 1).set(promise)?;
+
+    Ok(result)
+}
+
+// Pushes the name of each scheduling method onto an array as its closure executes
+pub fn channel_ordering(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let log = cx.argument::<JsArray>(0)?;
+    let channel = cx.channel();
+
+    for method in ["send", "microtask", "next_tick"] {
+        let log = log.root(&mut cx);
+        let push = move |mut cx: Cx| {
+            let log = log.into_inner(&mut cx);
+            let len = log.len(&mut cx);
+
+            log.prop(&mut cx, len).set(method)?;
+
+            Ok(())
+        };
+
+        match method {
+            "send" => {
+                channel.send(push);
+            }
+            "microtask" => cx.queue_microtask(push)?,
+            _ => cx.next_tick(push)?,
+        }
+    }
+
+    Ok(cx.undefined())
+}
+
+// Fills a channel with room for one closure, returning whether each send succeeded,
+// whether the second one failed because the queue was full and whether joining a
+// closure sent without checking failed
+pub fn bounded_channel_try_send(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let channel = Channel::with_capacity(&mut cx, 1);
+    let first = channel.try_send(|_| Ok(())).is_ok();
+    let second = channel.try_send(|_| Ok(()));
+    let is_queue_full = second.as_ref().is_err_and(|err| err.is_queue_full());
+    let third = channel.send(|_| Ok(())).join().is_err();
+    let result = cx.empty_array();
+
+    // Must not wait for room in the queue on the JavaScript thread
+    drop(channel.clone());
+
+    result.prop(&mut cx, 0).set(first)?;
+    result.prop(&mut cx, 1).set(second.is_ok())?;
+    result.prop(&mut cx, 2).set(is_queue_full)?;
+    result.prop(&mut cx, 3).set(third)?;
+
+    Ok(result)
+}
+
+// Calls `callback` with each number from `0` to `n` from another thread, waiting for
+// room in a channel that holds one closure at a time
+pub fn bounded_channel_send_blocking(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let callback = Arc::new(cx.argument::<JsFunction>(1)?.root(&mut cx));
+    let channel = Channel::with_capacity(&mut cx, 1);
+
+    std::thread::spawn(move || {
+        for i in 0..n {
+            let callback = callback.clone();
+
+            channel
+                .send_blocking(move |mut cx| {
+                    callback.to_inner(&mut cx).bind(&mut cx).arg(i)?.exec()
+                })
+                .unwrap();
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+// Checks whether this thread and a newly spawned thread are JavaScript threads
+pub fn is_js_thread(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let this_thread = neon::thread::is_js_thread();
+    let other_thread = std::thread::spawn(neon::thread::is_js_thread)
+        .join()
+        .unwrap();
+    let result = cx.empty_array();
+
+    result.prop(&mut cx, 0).set(this_thread)?;
+    result.prop(&mut cx, 1).set(other_thread)?;
+
+    Ok(result)
+}
+
+static POOL: Lazy<ThreadPool> = Lazy::new(|| ThreadPool::new(NonZeroUsize::new(2).unwrap()));
+
+// Resolves with the name of the thread that executed the task
+pub fn task_on_pool(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx
+        .task_on(&POOL, || std::thread::current().name().map(str::to_owned))
+        .promise(|mut cx, name| Ok(cx.string(name.unwrap_or_default())));
+
+    Ok(promise)
+}
+
+pub fn task_on_pool_panic(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let promise = cx
+        .task_on(&POOL, move || -> () { panic!("{}", msg) })
+        .promise(|mut cx, _| Ok(cx.undefined()));
+
+    Ok(promise)
+}
+
+pub fn task_on_pool_and_then(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+
+    cx.task_on(&POOL, move || n * 2.0)
+        .and_then(move |mut cx, n| callback.into_inner(&mut cx).bind(&mut cx).arg(n)?.exec());
+
+    Ok(cx.undefined())
+}
+
+// Resolves with the square root of a number or rejects if it is negative
+pub fn channel_settle_with(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    std::thread::spawn(move || {
+        let result = if n < 0.0 {
+            Err(Error::range_error("Expected a non-negative number"))
+        } else {
+            Ok(n.sqrt())
+        };
+
+        channel.settle_with(deferred, move |_| result);
+    });
+
+    Ok(promise)
+}
+
 pub fn sum_manual_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let nums = cx.argument::<JsTypedArray<f64>>(0)?.as_slice(&cx).to_vec();
 
@@ -482,3 +809,43 @@ fn block_task_callback(ch: Channel, cb: Root<JsFunction>) -> Result<Root<JsObjec
 
     Ok(res)
 }
+
+pub fn deferred_response_resolve(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let responder = cx.deferred_response()?;
+
+    std::thread::spawn(move || {
+        responder.resolve(n * 2.0);
+    });
+
+    // Ignored in favor of the deferred response
+    Ok(cx.number(-1))
+}
+
+pub fn deferred_response_reject(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let responder = cx.deferred_response()?;
+
+    std::thread::spawn(move || {
+        responder.respond(move |mut cx| cx.throw_error::<_, Handle<JsValue>>(msg));
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn deferred_response_leak(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let responder = cx.deferred_response()?;
+
+    std::thread::spawn(move || drop(responder));
+
+    Ok(cx.undefined())
+}
+
+pub fn deferred_response_twice(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let first = cx.deferred_response()?;
+
+    first.resolve(());
+    cx.deferred_response()?;
+
+    Ok(cx.undefined())
+}
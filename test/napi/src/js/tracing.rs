@@ -0,0 +1,78 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex,
+};
+
+use neon::{prelude::*, types::extract::Json};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+static SPANS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// Records the name of each span created by Neon, followed by its `name` field
+struct Recorder {
+    next_id: AtomicU64,
+}
+
+struct NameField(Option<String>);
+
+impl Visit for NameField {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "name" {
+            self.0 = Some(value.to_owned());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "name" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target() == "neon"
+    }
+
+    fn new_span(&self, attrs: &span::Attributes) -> span::Id {
+        let mut name = NameField(None);
+
+        attrs.record(&mut name);
+
+        let span = match name.0 {
+            Some(name) => format!("{}:{name}", attrs.metadata().name()),
+            None => attrs.metadata().name().to_owned(),
+        };
+
+        SPANS.lock().unwrap().push(span);
+
+        span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, _: &Event) {}
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+#[neon::export]
+fn start_tracing(cx: &mut Cx) -> NeonResult<()> {
+    let recorder = Recorder {
+        next_id: AtomicU64::new(1),
+    };
+
+    tracing::subscriber::set_global_default(recorder).or_else(|err| cx.throw_error(err.to_string()))
+}
+
+#[neon::export]
+fn take_spans() -> Json<Vec<String>> {
+    Json(std::mem::take(&mut SPANS.lock().unwrap()))
+}
@@ -1,6 +1,13 @@
+use std::sync::{atomic::Ordering, Arc};
+
+use once_cell::sync::Lazy;
+
 use neon::{
     prelude::*,
-    types::buffer::{Binary, BorrowError, TypedArray},
+    types::{
+        buffer::{Binary, BorrowError, BufferPool, Ref, RefMut, TypedArray},
+        JsSharedArrayBuffer,
+    },
 };
 
 pub fn return_array_buffer(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
@@ -15,6 +22,37 @@ pub fn return_array_buffer_from_slice(mut cx: FunctionContext) -> JsResult<JsArr
     JsArrayBuffer::from_slice(&mut cx, &v)
 }
 
+pub fn return_buffer_from_slice(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let v = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+
+    JsBuffer::from_slice(&mut cx, &v)
+}
+
+pub fn copy_into_uint32_array(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut arr = cx.argument::<JsTypedArray<u32>>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let values = cx.argument::<JsArray>(2)?.to_f64_vec(&mut cx)?;
+    let values = values.into_iter().map(|n| n as u32).collect::<Vec<_>>();
+
+    arr.copy_from(&mut cx, offset, &values)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn copy_out_of_uint32_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let arr = cx.argument::<JsTypedArray<u32>>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let len = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+    let mut values = vec![0; len];
+
+    arr.copy_to(&mut cx, offset, &mut values)?;
+
+    let values = values.into_iter().map(f64::from).collect::<Vec<_>>();
+
+    JsArray::from_f64s(&mut cx, &values)
+}
+
 pub fn read_array_buffer_with_lock(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let buf = cx.argument::<JsTypedArray<u32>>(0)?;
     let i = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
@@ -83,6 +121,70 @@ pub fn read_u8_typed_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(n as f64))
 }
 
+pub fn mirror_typed_array(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut arr = cx.argument::<JsTypedArray<u32>>(0)?;
+    let mut run = || -> Result<_, BorrowError> {
+        let lock = cx.lock();
+        let data = arr.try_borrow_mut(&lock)?;
+        let mid = data.len() / 2;
+        let (front, mut back) = RefMut::map_split(data, |data| data.split_at_mut(mid));
+
+        back[..mid].copy_from_slice(&front);
+
+        Ok(())
+    };
+
+    run().or_throw(&mut cx)?;
+
+    Ok(cx.undefined())
+}
+
+// Borrows the second half of `arr` and then tries to borrow `other`, which may be a
+// view of the same buffer
+pub fn borrow_second_half(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let arr = cx.argument::<JsTypedArray<u32>>(0)?;
+    let mut other = cx.argument::<JsTypedArray<u32>>(1)?;
+    let mut run = || -> Result<_, BorrowError> {
+        let lock = cx.lock();
+        let data = arr.try_borrow(&lock)?;
+        let mid = data.len() / 2;
+        let _back = Ref::map(data, |data| &data[mid..]);
+
+        let borrowed = other.try_borrow_mut(&lock).is_ok();
+
+        Ok(borrowed)
+    };
+
+    let borrowed = run().or_throw(&mut cx)?;
+
+    Ok(cx.boolean(borrowed))
+}
+
+// Borrows `arr` and `other` immutably and then tries to upgrade the borrow of `arr`,
+// zeroing it on success
+pub fn upgrade_borrow(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let arr = cx.argument::<JsTypedArray<u32>>(0)?;
+    let other = cx.argument::<JsTypedArray<u32>>(1)?;
+    let mut run = || -> Result<_, BorrowError> {
+        let lock = cx.lock();
+        let data = arr.try_borrow(&lock)?;
+        let _other = other.try_borrow(&lock)?;
+        let upgraded = match Ref::try_upgrade(data, &lock) {
+            Ok(mut data) => {
+                data.fill(0);
+                true
+            }
+            Err(_) => false,
+        };
+
+        Ok(upgraded)
+    };
+
+    let upgraded = run().or_throw(&mut cx)?;
+
+    Ok(cx.boolean(upgraded))
+}
+
 pub fn copy_typed_array(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let source = cx.argument::<JsTypedArray<u32>>(0)?;
     let mut dest = cx.argument::<JsTypedArray<u32>>(1)?;
@@ -106,6 +208,44 @@ pub fn return_uninitialized_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer
     Ok(b)
 }
 
+pub fn concat_uninit_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let a = cx.argument::<JsBuffer>(0)?;
+    let b = cx.argument::<JsBuffer>(1)?;
+    let len = a.as_slice(&cx).len() + b.as_slice(&cx).len();
+    let mut buf = JsBuffer::uninit(&mut cx, len)?;
+
+    buf.write(a.as_slice(&cx));
+    buf.write(b.as_slice(&cx));
+
+    buf.finish(&mut cx)
+}
+
+pub fn return_partial_uninit_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let s = cx.argument::<JsString>(0)?.value(&mut cx);
+    let capacity = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let mut buf = JsBuffer::uninit(&mut cx, capacity)?;
+
+    buf.write(s.as_bytes());
+
+    buf.finish(&mut cx)
+}
+
+pub fn fill_uninit_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let byte = cx.argument::<JsNumber>(0)?.value(&mut cx) as u8;
+    let len = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let mut buf = JsBuffer::uninit(&mut cx, len * 2)?;
+
+    // Safety: Nothing has been initialized yet
+    for b in unsafe { &mut buf.as_mut_uninit()[..len] } {
+        b.write(byte);
+    }
+
+    // Safety: The first `len` bytes were written above
+    unsafe { buf.assume_init_to(len) };
+
+    buf.finish(&mut cx)
+}
+
 pub fn return_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer> {
     let b: Handle<JsBuffer> = cx.buffer(16)?;
     Ok(b)
@@ -125,6 +265,72 @@ pub fn return_external_array_buffer(mut cx: FunctionContext) -> JsResult<JsArray
     Ok(buf)
 }
 
+pub fn return_shared_external_array_buffer(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
+    let data = cx.argument::<JsString>(0)?.value(&mut cx);
+    // Safety: The test only reads the buffer
+    let buf = unsafe { JsArrayBuffer::external_shared(&mut cx, Arc::<str>::from(data)) };
+
+    Ok(buf)
+}
+
+pub fn return_shared_array_buffer(mut cx: FunctionContext) -> JsResult<JsSharedArrayBuffer> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+
+    JsSharedArrayBuffer::new(&mut cx, len)
+}
+
+pub fn get_shared_array_buffer_size(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let buf = cx.argument::<JsSharedArrayBuffer>(0)?;
+    let size = buf.size(&mut cx)?;
+
+    Ok(cx.number(size as f64))
+}
+
+// Checks the type of the argument while an exception is pending, which should leave
+// the exception for the caller
+pub fn check_shared_array_buffer_while_throwing(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let value = cx.argument::<JsValue>(0)?;
+    let err = cx.error("pending")?;
+    let result = cx.throw(err);
+
+    value.is_a::<JsSharedArrayBuffer, _>(&mut cx);
+
+    result
+}
+
+// Writes the index of each byte from another thread
+pub fn fill_shared_array_buffer_in_thread(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let memory = cx.argument::<JsSharedArrayBuffer>(0)?.share(&mut cx)?;
+
+    std::thread::spawn(move || {
+        for (i, byte) in memory.as_atomic_slice().iter().enumerate() {
+            byte.store(i as u8, Ordering::SeqCst);
+        }
+    })
+    .join()
+    .unwrap();
+
+    Ok(cx.undefined())
+}
+
+static BUFFER_POOL: Lazy<BufferPool> = Lazy::new(|| BufferPool::new(16, 2));
+
+pub fn return_pooled_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+
+    BUFFER_POOL.buffer(&mut cx, len)
+}
+
+pub fn return_pooled_buffer_from_string(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let data = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    BUFFER_POOL.from_slice(&mut cx, data.as_bytes())
+}
+
+pub fn get_buffer_pool_idle(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(BUFFER_POOL.idle() as f64))
+}
+
 pub fn return_int8array_from_arraybuffer(mut cx: FunctionContext) -> JsResult<JsInt8Array> {
     let buf = cx.argument::<JsArrayBuffer>(0)?;
     JsInt8Array::from_buffer(&mut cx, buf)
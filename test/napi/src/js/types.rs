@@ -72,3 +72,24 @@ pub fn strict_equals(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let eq = v1.strict_equals(&mut cx, v2);
     Ok(cx.boolean(eq))
 }
+
+pub fn same_value(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let v1: Handle<JsValue> = cx.argument(0)?;
+    let v2: Handle<JsValue> = cx.argument(1)?;
+    let eq = v1.same_value(&mut cx, v2);
+    Ok(cx.boolean(eq))
+}
+
+pub fn same_value_zero(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let v1: Handle<JsValue> = cx.argument(0)?;
+    let v2: Handle<JsValue> = cx.argument(1)?;
+    let eq = v1.same_value_zero(&mut cx, v2);
+    Ok(cx.boolean(eq))
+}
+
+pub fn instance_of(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let constructor: Handle<JsValue> = cx.argument(1)?;
+    let result = val.instance_of(&mut cx, constructor)?;
+    Ok(cx.boolean(result))
+}
@@ -0,0 +1,16 @@
+use neon::prelude::*;
+
+#[neon::export]
+fn node_version<'cx>(cx: &mut Cx<'cx>) -> JsResult<'cx, JsObject> {
+    let version = cx.node_version();
+    let obj = cx.empty_object();
+
+    obj.prop(cx, "major").set(version.major())?;
+    obj.prop(cx, "minor").set(version.minor())?;
+    obj.prop(cx, "patch").set(version.patch())?;
+    obj.prop(cx, "release").set(version.release())?;
+    obj.prop(cx, "napi").set(version.napi_version())?;
+    obj.prop(cx, "version").set(version.to_string())?;
+
+    Ok(obj)
+}
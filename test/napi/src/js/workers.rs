@@ -1,9 +1,24 @@
-use std::{convert::TryFrom, sync::Mutex, thread, time::Duration};
+use std::{
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
 use once_cell::sync::{Lazy, OnceCell};
 
 use neon::prelude::*;
-use neon::thread::LocalKey;
+use neon::{
+    thread::{LocalKey, Worker},
+    types::{
+        buffer::TypedArray,
+        extract::{Array, Boxed},
+        Deferred, JsMessagePort,
+    },
+};
 
 pub fn get_and_replace(mut cx: FunctionContext) -> JsResult<JsValue> {
     static OBJECT: Lazy<Mutex<Option<Root<JsObject>>>> = Lazy::new(Default::default);
@@ -46,6 +61,197 @@ pub fn get_or_init_clone(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(o.clone(&mut cx).into_inner(&mut cx))
 }
 
+static DEFERRED: Mutex<Option<Deferred>> = Mutex::new(None);
+
+pub fn stash_deferred(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let (deferred, promise) = cx.promise();
+
+    *DEFERRED.lock().unwrap_or_else(|err| err.into_inner()) = Some(deferred);
+
+    Ok(promise)
+}
+
+pub fn settle_deferred(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let deferred = DEFERRED
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .take();
+    let Some(deferred) = deferred else {
+        return cx.throw_error("Expected a stashed `Deferred`");
+    };
+
+    let value = cx.argument::<JsValue>(0)?;
+
+    deferred.resolve(&mut cx, value);
+
+    Ok(cx.undefined())
+}
+
+static EXIT_HOOK_CALLS: AtomicU32 = AtomicU32::new(0);
+
+pub fn register_exit_hook(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    cx.on_exit(|| {
+        EXIT_HOOK_CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+
+    Ok(cx.undefined())
+}
+
+// Registers a counting hook followed by one that panics, which runs first
+pub fn register_panicking_exit_hook(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    cx.on_exit(|| {
+        EXIT_HOOK_CALLS.fetch_add(1, Ordering::SeqCst);
+    });
+
+    cx.on_exit(|| panic!("exit hook"));
+
+    Ok(cx.undefined())
+}
+
+pub fn exit_hook_calls(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(EXIT_HOOK_CALLS.load(Ordering::SeqCst)))
+}
+
+static STOPPED_THREADS: AtomicU32 = AtomicU32::new(0);
+
+// Spawns a thread that runs until the environment is torn down
+pub fn spawn_managed_thread(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    neon::thread::spawn(&mut cx, |token| {
+        while !token.is_cancelled() {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        STOPPED_THREADS.fetch_add(1, Ordering::SeqCst);
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn stopped_threads(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(STOPPED_THREADS.load(Ordering::SeqCst)))
+}
+
+pub fn stop_and_join_thread(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let thread = neon::thread::spawn(&mut cx, |token| {
+        while !token.is_cancelled() {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        42
+    });
+
+    thread.stop();
+
+    let n = thread
+        .join()
+        .or_else(|_| cx.throw_error("thread panicked"))?;
+
+    Ok(cx.number(n))
+}
+
+static DROPPED_THREAD_RESULTS: AtomicU32 = AtomicU32::new(0);
+
+struct ThreadResult;
+
+impl Drop for ThreadResult {
+    fn drop(&mut self) {
+        DROPPED_THREAD_RESULTS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+// Spawns a thread that returns immediately. Its result is dropped once the thread is
+// no longer tracked.
+pub fn spawn_finished_thread(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    neon::thread::spawn(&mut cx, |_| ThreadResult);
+
+    Ok(cx.undefined())
+}
+
+pub fn dropped_thread_results(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(DROPPED_THREAD_RESULTS.load(Ordering::SeqCst)))
+}
+
+const DOUBLE: &str = r#"
+    const { parentPort } = require("node:worker_threads");
+
+    parentPort.on("message", (n) => {
+        parentPort.postMessage(n * 2);
+
+        if (n === 3) {
+            parentPort.close();
+        }
+    });
+"#;
+
+// Doubles 1, 2 and 3 in a worker, exchanging messages from a Rust thread until it exits
+pub fn double_in_worker(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let worker = Worker::eval(&mut cx, DOUBLE)?;
+
+    double_with(cx, worker)
+}
+
+// Same as `double_in_worker`, but runs the worker script at a path or URL
+pub fn double_in_worker_file(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let filename = cx.argument::<JsString>(0)?.value(&mut cx);
+    let worker = Worker::new(&mut cx, &filename)?;
+
+    double_with(cx, worker)
+}
+
+fn double_with(mut cx: FunctionContext, worker: Worker) -> JsResult<JsPromise> {
+    let messages = worker.messages::<f64>(&mut cx)?;
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    worker.post_message(&mut cx, 1.0)?;
+
+    thread::spawn(move || {
+        worker.send(2.0);
+        worker.send(3.0);
+
+        let doubled = messages.into_iter().collect::<Vec<_>>();
+
+        channel.settle_with(deferred, move |_| Array(doubled));
+    });
+
+    Ok(promise)
+}
+
+// Replies to each number received by `port` with its double
+pub fn message_port_double(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let port = cx.argument::<JsMessagePort>(0)?;
+    let reply = port.root(&mut cx);
+
+    port.on_message(&mut cx, move |cx, message| {
+        let n = message.downcast_or_throw::<JsNumber, _>(cx)?.value(cx);
+        let doubled = cx.number(n * 2.0);
+
+        reply.to_inner(cx).post_message(cx, doubled)
+    })?;
+
+    Ok(cx.undefined())
+}
+
+// Creates a pair of ports and posts a buffer of `len` bytes on the first, transferring it.
+// Returns the ports and the length of the buffer after transferring.
+pub fn message_port_transfer(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let (port1, port2) = JsMessagePort::channel(&mut cx)?;
+    let buf = JsArrayBuffer::new(&mut cx, len)?;
+
+    port1.post_message_with_transfer(&mut cx, buf, &[buf.upcast()])?;
+    port1.close(&mut cx)?;
+
+    let len = buf.size(&mut cx);
+    let len = cx.number(len as f64);
+    let result = cx.empty_array();
+
+    result.prop(&mut cx, 0).set(port2)?;
+    result.prop(&mut cx, 1).set(len)?;
+
+    Ok(result)
+}
+
 static THREAD_ID: LocalKey<u32> = LocalKey::new();
 
 pub fn get_or_init_thread_id(mut cx: FunctionContext) -> JsResult<JsNumber> {
@@ -123,3 +329,41 @@ pub fn box_channels(mut cx: FunctionContext) -> JsResult<JsBox<Channels>> {
 
     Ok(cx.boxed(channels))
 }
+
+pub fn increment_store_count(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let mut store = cx.instance_store()?;
+    let n = store.get::<f64>("count")?.unwrap_or(0.0) + 1.0;
+
+    store.set("count", n)?;
+
+    Ok(cx.number(n))
+}
+
+#[derive(Clone)]
+pub struct StoredName(String);
+
+impl Finalize for StoredName {}
+
+pub fn store_name(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.instance_store()?.set("name", Boxed(StoredName(name)))?;
+
+    Ok(cx.undefined())
+}
+
+pub fn load_name(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let name = cx.instance_store()?.get::<Boxed<StoredName>>("name");
+
+    match name? {
+        Some(Boxed(name)) => Ok(cx.string(&name.0).upcast()),
+        None => Ok(cx.null().upcast()),
+    }
+}
+
+pub fn delete_from_store(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let key = cx.argument::<JsString>(0)?.value(&mut cx);
+    let deleted = cx.instance_store()?.delete(&key)?;
+
+    Ok(cx.boolean(deleted))
+}
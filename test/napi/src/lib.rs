@@ -1,5 +1,6 @@
 use neon::prelude::*;
 use once_cell::sync::OnceCell;
+use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::TracerProvider};
 use tokio::runtime::Runtime;
 
 use crate::js::{
@@ -14,17 +15,25 @@ mod js {
     pub mod coercions;
     pub mod container;
     pub mod date;
+    pub mod diagnostics;
     pub mod errors;
     pub mod export;
     pub mod extract;
     pub mod functions;
     pub mod futures;
+    pub mod logging;
+    pub mod memory;
     pub mod numbers;
     pub mod objects;
+    pub mod permissions;
+    pub mod proxy;
     pub mod strings;
+    pub mod testing;
     pub mod threads;
+    pub mod tracing;
     pub mod typedarrays;
     pub mod types;
+    pub mod version;
     pub mod workers;
 }
 
@@ -34,6 +43,20 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     neon::set_global_executor(&mut cx, rt).or_else(|_| cx.throw_error("executor already set"))?;
     neon::registered().export(&mut cx)?;
+    neon::testing::export(&mut cx)?;
+    neon::testing::bench::export(&mut cx)?;
+    cx.export_metadata(neon::metadata!())?;
+
+    // Records spans and propagates W3C trace context; see `lib/extract.js`
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+    opentelemetry::global::set_tracer_provider(TracerProvider::builder().build());
+
+    // Annotates errors thrown by the addon while enabled; see `lib/errors.js`
+    cx.set_error_decorator(js::errors::decorate);
+
+    // Wraps exports named `intercepted*`; see `lib/functions.js`
+    cx.add_interceptor(js::functions::intercept);
+    cx.add_interceptor(js::functions::intercept_inner);
 
     assert!(neon::registered().into_iter().next().is_some());
 
@@ -65,6 +88,22 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_value("one", one)?;
     cx.export_value("two", two)?;
 
+    // Created on first access; see `lib/hello.js`
+    cx.export_lazy("lazyGreeting", |cx| Ok(cx.string("Hello, Lazy!")))?;
+    cx.export_lazy("lazyIntrinsic", |cx| Ok(cx.string("Hello, Intrinsic!")))?;
+
+    let attempts = std::cell::Cell::new(0);
+
+    cx.export_lazy("lazyRetry", move |cx| {
+        attempts.set(attempts.get() + 1);
+
+        if attempts.get() == 1 {
+            return cx.throw_error("first attempt fails");
+        }
+
+        Ok(cx.number(attempts.get()))
+    })?;
+
     // Plain objects.
     let rust_created = cx.empty_object();
     {
@@ -124,6 +163,16 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("return_js_string_utf16", return_js_string_utf16)?;
     cx.export_function("return_length_utf8", return_length_utf8)?;
     cx.export_function("return_length_utf16", return_length_utf16)?;
+    cx.export_function("string_from_utf16", string_from_utf16)?;
+    cx.export_function("string_to_utf16", string_to_utf16)?;
+    cx.export_function("string_from_latin1", string_from_latin1)?;
+    cx.export_function("string_to_latin1", string_to_latin1)?;
+    cx.export_function("external_string", external_string)?;
+    cx.export_function("external_string_latin1", external_string_latin1)?;
+    cx.export_function("external_string_utf16", external_string_utf16)?;
+    cx.export_function("intern_strings", intern_strings)?;
+    cx.export_function("concat_strings", concat_strings)?;
+    cx.export_function("substring", substring)?;
     cx.export_function("run_string_as_script", run_string_as_script)?;
 
     cx.export_function("return_js_number", return_js_number)?;
@@ -147,8 +196,21 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "accept_and_return_negative_js_number",
         accept_and_return_negative_js_number,
     )?;
+    cx.export_function("number_from_i64", number_from_i64)?;
+    cx.export_function("number_from_u64", number_from_u64)?;
+    cx.export_function("number_or_bigint_from_i64", number_or_bigint_from_i64)?;
+    cx.export_function("number_or_bigint_from_u64", number_or_bigint_from_u64)?;
+    cx.export_function("number_to_i64", number_to_i64)?;
+    cx.export_function("number_to_u64", number_to_u64)?;
 
     cx.export_function("return_js_function", return_js_function)?;
+    cx.export_function("interceptedExportFunction", intercepted_export_function)?;
+    cx.export_function("sum_with_c_callbacks", sum_with_c_callbacks)?;
+    cx.export_function("counter_with_callback", counter_with_callback)?;
+    cx.export_function(
+        "has_current_context_on_thread",
+        has_current_context_on_thread,
+    )?;
     cx.export_function("call_js_function", call_js_function)?;
     cx.export_function(
         "call_js_function_idiomatically",
@@ -223,6 +285,12 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("return_this", return_this)?;
     cx.export_function("require_object_this", require_object_this)?;
     cx.export_function("is_argument_zero_some", is_argument_zero_some)?;
+    cx.export_function(
+        "optional_argument_zero_string",
+        optional_argument_zero_string,
+    )?;
+    cx.export_function("argument_zero_or_default", argument_zero_or_default)?;
+    cx.export_function("argument_zero_or_missing", argument_zero_or_missing)?;
     cx.export_function("require_argument_zero_string", require_argument_zero_string)?;
     cx.export_function("check_string_and_number", check_string_and_number)?;
     cx.export_function("execute_scoped", execute_scoped)?;
@@ -233,13 +301,54 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("return_js_array_with_number", return_js_array_with_number)?;
     cx.export_function("return_js_array_with_string", return_js_array_with_string)?;
     cx.export_function("read_js_array", read_js_array)?;
+    cx.export_function("return_js_array_from_iter", return_js_array_from_iter)?;
+    cx.export_function("return_js_array_set_each", return_js_array_set_each)?;
+    cx.export_function("return_js_array_from_slice", return_js_array_from_slice)?;
+    cx.export_function("push_js_array", push_js_array)?;
+    cx.export_function("copy_js_array", copy_js_array)?;
+    cx.export_function("sum_js_array", sum_js_array)?;
+    cx.export_function("double_f64_array", double_f64_array)?;
+    cx.export_function("double_i32_array", double_i32_array)?;
 
     cx.export_function("to_string", to_string)?;
 
     cx.export_function("return_js_global_object", return_js_global_object)?;
     cx.export_function("return_js_object", return_js_object)?;
+    cx.export_function("return_js_dict", return_js_dict)?;
     cx.export_function("return_js_object_with_number", return_js_object_with_number)?;
     cx.export_function("return_js_object_with_string", return_js_object_with_string)?;
+    cx.export_function("return_object_builder", return_object_builder)?;
+    cx.export_function("get_many_props", get_many_props)?;
+    cx.export_function("define_many_props", define_many_props)?;
+    cx.export_function(
+        "return_object_builder_with_len",
+        return_object_builder_with_len,
+    )?;
+    cx.export_function(
+        "return_object_set_each_with_len",
+        return_object_set_each_with_len,
+    )?;
+    cx.export_function("diff_objects", diff_objects)?;
+    cx.export_function("wrap_object", wrap_object)?;
+    cx.export_function("unwrap_object", unwrap_object)?;
+    cx.export_function("wrap_counter", wrap_counter)?;
+    cx.export_function("counter_count", counter_count)?;
+    cx.export_function("counter_increment", counter_increment)?;
+    cx.export_function("unwrap_object_as_string", unwrap_object_as_string)?;
+    cx.export_function("get_property_names", get_property_names)?;
+    cx.export_function("has_own_property", has_own_property)?;
+    cx.export_function("has_own_property_str", has_own_property_str)?;
+    cx.export_function("has_own_property_index", has_own_property_index)?;
+    cx.export_function("delete_property", delete_property)?;
+    cx.export_function("delete_property_str", delete_property_str)?;
+    cx.export_function("delete_property_index", delete_property_index)?;
+    cx.export_function("set_global", set_global)?;
+    cx.export_function("get_intrinsics", get_intrinsics)?;
+    cx.export_function("get_process_env", get_process_env)?;
+    cx.export_function("get_process_argv", get_process_argv)?;
+    cx.export_function("require_module", require_module)?;
+    cx.export_function("require_module_from", require_module_from)?;
+    cx.export_function("validate_options", validate_options)?;
     cx.export_function(
         "return_js_object_with_mixed_content",
         return_js_object_with_mixed_content,
@@ -252,6 +361,9 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "return_array_buffer_from_slice",
         return_array_buffer_from_slice,
     )?;
+    cx.export_function("return_buffer_from_slice", return_buffer_from_slice)?;
+    cx.export_function("copy_into_uint32_array", copy_into_uint32_array)?;
+    cx.export_function("copy_out_of_uint32_array", copy_out_of_uint32_array)?;
     cx.export_function("read_array_buffer_with_lock", read_array_buffer_with_lock)?;
     cx.export_function(
         "read_array_buffer_with_borrow",
@@ -269,10 +381,36 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     )?;
     cx.export_function("read_u8_typed_array", read_u8_typed_array)?;
     cx.export_function("copy_typed_array", copy_typed_array)?;
+    cx.export_function("mirror_typed_array", mirror_typed_array)?;
+    cx.export_function("borrow_second_half", borrow_second_half)?;
+    cx.export_function("upgrade_borrow", upgrade_borrow)?;
     cx.export_function("return_uninitialized_buffer", return_uninitialized_buffer)?;
+    cx.export_function("concat_uninit_buffer", concat_uninit_buffer)?;
+    cx.export_function("return_partial_uninit_buffer", return_partial_uninit_buffer)?;
+    cx.export_function("fill_uninit_buffer", fill_uninit_buffer)?;
     cx.export_function("return_buffer", return_buffer)?;
     cx.export_function("return_external_buffer", return_external_buffer)?;
     cx.export_function("return_external_array_buffer", return_external_array_buffer)?;
+    cx.export_function(
+        "return_shared_external_array_buffer",
+        return_shared_external_array_buffer,
+    )?;
+    cx.export_function("return_shared_array_buffer", return_shared_array_buffer)?;
+    cx.export_function("return_pooled_buffer", return_pooled_buffer)?;
+    cx.export_function(
+        "return_pooled_buffer_from_string",
+        return_pooled_buffer_from_string,
+    )?;
+    cx.export_function("get_buffer_pool_idle", get_buffer_pool_idle)?;
+    cx.export_function("get_shared_array_buffer_size", get_shared_array_buffer_size)?;
+    cx.export_function(
+        "check_shared_array_buffer_while_throwing",
+        check_shared_array_buffer_while_throwing,
+    )?;
+    cx.export_function(
+        "fill_shared_array_buffer_in_thread",
+        fill_shared_array_buffer_in_thread,
+    )?;
     cx.export_function(
         "return_int8array_from_arraybuffer",
         return_int8array_from_arraybuffer,
@@ -344,11 +482,25 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("is_string", is_string)?;
     cx.export_function("is_undefined", is_undefined)?;
     cx.export_function("strict_equals", strict_equals)?;
+    cx.export_function("same_value", same_value)?;
+    cx.export_function("same_value_zero", same_value_zero)?;
+    cx.export_function("instance_of", instance_of)?;
 
     cx.export_function("new_error", new_error)?;
     cx.export_function("new_type_error", new_type_error)?;
     cx.export_function("new_range_error", new_range_error)?;
+    cx.export_function("new_syntax_error", new_syntax_error)?;
+    cx.export_function("new_eval_error", new_eval_error)?;
+    cx.export_function("new_uri_error", new_uri_error)?;
+    cx.export_function("new_error_with_type", new_error_with_type)?;
+    cx.export_function("new_error_from_builder", new_error_from_builder)?;
+    cx.export_function("parse_port", parse_port)?;
+    cx.export_function("capture_stack_trace", capture_stack_trace)?;
+    cx.export_function("get_error_stack", get_error_stack)?;
+    cx.export_function("new_error_with_stack", new_error_with_stack)?;
     cx.export_function("throw_error", throw_error)?;
+    cx.export_function("set_decorate_errors", set_decorate_errors)?;
+    cx.export_function("throw_error_with_code", throw_error_with_code)?;
     cx.export_function("downcast_error", downcast_error)?;
 
     cx.export_function("panic", panic)?;
@@ -356,9 +508,20 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("throw_and_catch", throw_and_catch)?;
     cx.export_function("call_and_catch", call_and_catch)?;
+    cx.export_function("call_spread", call_spread)?;
+    cx.export_function("call_with_rest", call_with_rest)?;
+    cx.export_function("reenter", reenter)?;
+    cx.export_function("reenter_catching", reenter_catching)?;
+    cx.export_function("call_catching", call_catching)?;
     cx.export_function("get_number_or_default", get_number_or_default)?;
     cx.export_function("assume_this_is_an_object", assume_this_is_an_object)?;
     cx.export_function("is_construct", is_construct)?;
+    cx.export_function("get_new_target", get_new_target)?;
+    cx.export_function(
+        "construct_error_with_new_target",
+        construct_error_with_new_target,
+    )?;
+    cx.export_function("construct_with_new_target", construct_with_new_target)?;
     cx.export_function("caller_with_drop_callback", caller_with_drop_callback)?;
 
     cx.export_function("count_called", {
@@ -380,14 +543,23 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("person_new", person_new)?;
     cx.export_function("person_greet", person_greet)?;
+    cx.export_function("person_greet_downcast", person_greet_downcast)?;
     cx.export_function("ref_person_new", ref_person_new)?;
     cx.export_function("ref_person_greet", ref_person_greet)?;
     cx.export_function("ref_person_set_name", ref_person_set_name)?;
     cx.export_function("ref_person_fail", ref_person_fail)?;
     cx.export_function("external_unit", external_unit)?;
+    cx.export_function("external_counter_new", external_counter_new)?;
+    cx.export_function("external_counter_get", external_counter_get)?;
+    cx.export_function("external_other_counter_get", external_other_counter_get)?;
 
     cx.export_function("useless_root", useless_root)?;
+    cx.export_function("weak_root", weak_root)?;
+    cx.export_function("upgrade_weak_root", upgrade_weak_root)?;
+    cx.export_function("drop_weak_root_from_thread", drop_weak_root_from_thread)?;
     cx.export_function("thread_callback", thread_callback)?;
+    cx.export_function("channels_callback", channels_callback)?;
+    cx.export_function("named_resources_callback", named_resources_callback)?;
     cx.export_function("multi_threaded_callback", multi_threaded_callback)?;
     cx.export_function("greeter_new", greeter_new)?;
     cx.export_function("greeter_greet", greeter_greet)?;
@@ -396,6 +568,23 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("channel_join", channel_join)?;
     cx.export_function("sum", sum)?;
     cx.export_function("sum_manual_promise", sum_manual_promise)?;
+    cx.export_function("task_with_progress", task_with_progress)?;
+    cx.export_function("cancellable_task", cancellable_task)?;
+    cx.export_function("task_on_pool", task_on_pool)?;
+    cx.export_function("emit_from_thread", emit_from_thread)?;
+    cx.export_function("stream_from_threads", stream_from_threads)?;
+    cx.export_function("stream_convert_throw", stream_convert_throw)?;
+    cx.export_function("stream_until_closed", stream_until_closed)?;
+    cx.export_function("channel_ordering", channel_ordering)?;
+    cx.export_function("bounded_channel_try_send", bounded_channel_try_send)?;
+    cx.export_function(
+        "bounded_channel_send_blocking",
+        bounded_channel_send_blocking,
+    )?;
+    cx.export_function("is_js_thread", is_js_thread)?;
+    cx.export_function("channel_settle_with", channel_settle_with)?;
+    cx.export_function("task_on_pool_panic", task_on_pool_panic)?;
+    cx.export_function("task_on_pool_and_then", task_on_pool_and_then)?;
     cx.export_function("sum_rust_thread", sum_rust_thread)?;
     cx.export_function("leak_promise", leak_promise)?;
     cx.export_function("channel_panic", channel_panic)?;
@@ -418,9 +607,33 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "deferred_settle_with_panic_throw",
         deferred_settle_with_panic_throw,
     )?;
+    cx.export_function("deferred_response_resolve", deferred_response_resolve)?;
+    cx.export_function("deferred_response_reject", deferred_response_reject)?;
+    cx.export_function("deferred_response_leak", deferred_response_leak)?;
+    cx.export_function("deferred_response_twice", deferred_response_twice)?;
     cx.export_function("get_and_replace", js::workers::get_and_replace)?;
     cx.export_function("get_or_init", js::workers::get_or_init)?;
     cx.export_function("get_or_init_clone", js::workers::get_or_init_clone)?;
+    cx.export_function("stash_deferred", js::workers::stash_deferred)?;
+    cx.export_function("settle_deferred", js::workers::settle_deferred)?;
+    cx.export_function("register_exit_hook", js::workers::register_exit_hook)?;
+    cx.export_function(
+        "register_panicking_exit_hook",
+        js::workers::register_panicking_exit_hook,
+    )?;
+    cx.export_function("exit_hook_calls", js::workers::exit_hook_calls)?;
+    cx.export_function("spawn_managed_thread", js::workers::spawn_managed_thread)?;
+    cx.export_function("stopped_threads", js::workers::stopped_threads)?;
+    cx.export_function("stop_and_join_thread", js::workers::stop_and_join_thread)?;
+    cx.export_function("spawn_finished_thread", js::workers::spawn_finished_thread)?;
+    cx.export_function(
+        "dropped_thread_results",
+        js::workers::dropped_thread_results,
+    )?;
+    cx.export_function("double_in_worker", js::workers::double_in_worker)?;
+    cx.export_function("double_in_worker_file", js::workers::double_in_worker_file)?;
+    cx.export_function("message_port_double", js::workers::message_port_double)?;
+    cx.export_function("message_port_transfer", js::workers::message_port_transfer)?;
     cx.export_function("get_or_init_thread_id", js::workers::get_or_init_thread_id)?;
     cx.export_function("reentrant_try_init", js::workers::reentrant_try_init)?;
     cx.export_function("get_reentrant_value", js::workers::get_reentrant_value)?;
@@ -428,10 +641,16 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("unstash_global_object", js::workers::unstash_global_object)?;
     cx.export_function("reject_after", js::workers::reject_after)?;
     cx.export_function("box_channels", js::workers::box_channels)?;
+    cx.export_function("increment_store_count", js::workers::increment_store_count)?;
+    cx.export_function("store_name", js::workers::store_name)?;
+    cx.export_function("load_name", js::workers::load_name)?;
+    cx.export_function("delete_from_store", js::workers::delete_from_store)?;
+    cx.export_instance_store("instanceStore")?;
 
     // Futures
     cx.export_function("lazy_async_add", js::futures::lazy_async_add)?;
     cx.export_function("lazy_async_sum", js::futures::lazy_async_sum)?;
+    cx.export_function("spawn_double", js::futures::spawn_double)?;
 
     // JsBigInt test suite
     cx.export_function("bigint_suite", js::bigint::bigint_suite)?;